@@ -15,6 +15,14 @@ else
   CARGO_TARGET="--target ${TARGET}"
 fi
 
+# Targets without a prebuilt `core` build it from source, and do not have `std`.
+if [ ! -z $BUILD_STD ]; then
+  rustup component add rust-src
+  cargo check -Z build-std=core --target ${TARGET} \
+    --no-default-features --features atomic
+  exit 0
+fi
+
 if [ ! -z $CI ]; then
   $CARGO clean
 fi
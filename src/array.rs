@@ -251,8 +251,15 @@ where
 mod iter;
 mod ops;
 mod traits;
+mod vec;
 
-pub use self::iter::IntoIter;
+pub use self::{
+	iter::IntoIter,
+	vec::{
+		BitArrayVec,
+		CapacityError,
+	},
+};
 
 #[cfg(test)]
 mod tests;
@@ -2,8 +2,14 @@
 
 use crate::{
 	array::BitArray,
+	index::{
+		BitIdx,
+		BitMask,
+	},
+	mem::BitMemory,
 	order::BitOrder,
 	slice::BitSlice,
+	store::BitStore,
 	view::BitView,
 };
 
@@ -19,6 +25,10 @@ use core::{
 
 /** A by-value [array] iterator.
 
+The iterator keeps a copy of the element under each of its two cursors, so
+that walking through the array reads each element from the array only once,
+rather than once per bit.
+
 # Original
 
 [`array::IntoIter`](core::array::IntoIter)
@@ -48,8 +58,19 @@ where
 	/// - `alive.start <= alive.end`
 	/// - `alive.end <= V::const_bits()`
 	alive: Range<usize>,
+
+	/// The index and value of the element most recently read by the front
+	/// cursor.
+	front: Option<(usize, Mem<V>)>,
+
+	/// The index and value of the element most recently read by the back
+	/// cursor.
+	back: Option<(usize, Mem<V>)>,
 }
 
+/// The register type of an array’s storage elements.
+type Mem<V> = <<V as BitView>::Store as BitStore>::Mem;
+
 impl<O, V> IntoIter<O, V>
 where
 	O: BitOrder,
@@ -64,6 +85,8 @@ where
 		Self {
 			array,
 			alive: 0 .. V::const_bits(),
+			front: None,
+			back: None,
 		}
 	}
 
@@ -88,6 +111,9 @@ where
 	///
 	/// [`IntoIter::as_mut_slice`](core::array::IntoIter::as_mut_slice)
 	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<O, V::Store> {
+		//  The caller may change bits in the buffered elements.
+		self.front = None;
+		self.back = None;
 		unsafe {
 			self.array
 				.as_mut_bitslice()
@@ -102,8 +128,37 @@ where
 	}
 
 	/// Extracts a bit from the array.
-	fn get(&self, index: usize) -> bool {
-		*unsafe { self.array.as_bitslice().get_unchecked(index) }
+	///
+	/// # Parameters
+	///
+	/// - `array`: The array being iterated.
+	/// - `slot`: The element buffer of the cursor that is reading. It is
+	///   refilled from `array` when `index` lies in a different element than
+	///   the one it holds.
+	/// - `index`: The index of the bit to read. This must be less than
+	///   `V::const_bits()`.
+	///
+	/// # Returns
+	///
+	/// The value of the bit at `index`.
+	fn get(
+		array: &BitArray<O, V>,
+		slot: &mut Option<(usize, Mem<V>)>,
+		index: usize,
+	) -> bool {
+		let width = <Mem<V> as BitMemory>::BITS as usize;
+		let (elt, bit) = (index / width, (index % width) as u8);
+		let mem = match *slot {
+			Some((idx, mem)) if idx == elt => mem,
+			_ => {
+				let mem =
+					unsafe { array.as_slice().get_unchecked(elt) }.load_value();
+				*slot = Some((elt, mem));
+				mem
+			},
+		};
+		BitMask::new(mem)
+			.test(unsafe { BitIdx::new_unchecked(bit) }.select::<O>())
 	}
 }
 
@@ -128,7 +183,9 @@ where
 	type Item = bool;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		self.alive.next().map(|idx| self.get(idx))
+		let array = &self.array;
+		let slot = &mut self.front;
+		self.alive.next().map(|idx| Self::get(array, slot, idx))
 	}
 
 	fn size_hint(&self) -> (usize, Option<usize>) {
@@ -141,7 +198,9 @@ where
 	}
 
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		self.alive.nth(n).map(|idx| self.get(idx))
+		let array = &self.array;
+		let slot = &mut self.front;
+		self.alive.nth(n).map(|idx| Self::get(array, slot, idx))
 	}
 
 	fn last(mut self) -> Option<Self::Item> {
@@ -155,11 +214,19 @@ where
 	V: BitView,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		self.alive.next_back().map(|idx| self.get(idx))
+		let array = &self.array;
+		let slot = &mut self.back;
+		self.alive
+			.next_back()
+			.map(|idx| Self::get(array, slot, idx))
 	}
 
 	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-		self.alive.nth_back(n).map(|idx| self.get(idx))
+		let array = &self.array;
+		let slot = &mut self.back;
+		self.alive
+			.nth_back(n)
+			.map(|idx| Self::get(array, slot, idx))
 	}
 }
 
@@ -20,6 +20,10 @@ use core::ops::{
 	Index,
 	IndexMut,
 	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
 impl<O, V, Rhs> BitAnd<Rhs> for BitArray<O, V>
@@ -157,3 +161,49 @@ where
 		self
 	}
 }
+
+impl<O, V> Shl<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Output = Self;
+
+	fn shl(mut self, by: usize) -> Self::Output {
+		self.as_mut_bitslice().shift_numeric(by, true);
+		self
+	}
+}
+
+impl<O, V> ShlAssign<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn shl_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_numeric(by, true);
+	}
+}
+
+impl<O, V> Shr<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Output = Self;
+
+	fn shr(mut self, by: usize) -> Self::Output {
+		self.as_mut_bitslice().shift_numeric(by, false);
+		self
+	}
+}
+
+impl<O, V> ShrAssign<usize> for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn shr_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_numeric(by, false);
+	}
+}
@@ -2,7 +2,10 @@
 
 #![cfg(test)]
 
-use crate::prelude::*;
+use crate::{
+	array::BitArrayVec,
+	prelude::*,
+};
 
 use core::convert::TryInto;
 
@@ -114,6 +117,38 @@ fn iter() {
 	assert!(arr.all(), "{:?}", arr);
 }
 
+#[test]
+fn into_iter_by_value() {
+	fn ones<I>(iter: I) -> usize
+	where I: IntoIterator<Item = bool> {
+		iter.into_iter().filter(|&b| b).count()
+	}
+
+	let mut arr = BitArray::<Msb0, [u8; 3]>::zeroed();
+	arr.set(2, true);
+	arr.set(9, true);
+	arr.set(23, true);
+	assert_eq!(ones(arr), 3);
+
+	let mut seen = 0;
+	for (idx, bit) in arr.into_iter().enumerate() {
+		assert_eq!(bit, [2, 9, 23].contains(&idx));
+		seen += 1;
+	}
+	assert_eq!(seen, 24);
+	assert!(arr.into_iter().rev().eq(arr.iter().rev().copied()));
+
+	//  Writes through the iterator are visible to later reads, even in an
+	//  element that has already been buffered.
+	let mut iter = arr.into_iter();
+	assert!(!iter.next().unwrap());
+	assert!(iter.next_back().unwrap());
+	iter.as_mut_bitslice().set(0, true);
+	iter.as_mut_bitslice().set(20, true);
+	assert!(iter.next().unwrap());
+	assert!(iter.nth_back(1).unwrap());
+}
+
 #[test]
 fn ops() {
 	let a = bitarr![0, 0, 1, 1];
@@ -169,6 +204,31 @@ fn traits() {
 	assert!(a.not_any());
 }
 
+#[test]
+fn cross_type_cmp() {
+	let arr = bitarr![Lsb0, u8; 1, 0, 1, 1, 0, 0, 1, 0];
+	let bits = bits![Msb0, u16; 1, 0, 1, 1, 0, 0, 1, 0];
+
+	assert_eq!(arr, bits);
+	assert_eq!(bits, arr);
+	assert_eq!(*bits, arr);
+
+	let mut data = 0u32;
+	let bits_mut = &mut data.view_bits_mut::<Msb0>()[.. 8];
+	bits_mut.store(0b1011_0011u8);
+	assert!(bits_mut > arr);
+	assert!(arr < bits_mut);
+	assert!(&*bits_mut > arr);
+
+	#[cfg(feature = "alloc")]
+	{
+		let bv = bits.to_bitvec();
+		assert_eq!(arr, bv);
+		assert_eq!(bv, arr);
+		assert_eq!(arr.partial_cmp(&bv), Some(core::cmp::Ordering::Equal));
+	}
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn format() {
@@ -194,4 +254,47 @@ fn format() {
 		"{}",
 		text
 	);
+
+	assert_eq!(
+		format!("{:p}", a),
+		format!("{:p}[0]", a.as_slice().as_ptr())
+	);
+}
+
+#[test]
+fn array_vec() {
+	let mut bits = BitArrayVec::<Lsb0, [u16; 2]>::new();
+	assert!(bits.is_empty());
+	assert_eq!(bits.capacity(), 32);
+	assert_eq!(bits.pop(), None);
+
+	bits.extend_from_bitslice(bits![Msb0, u8; 1; 30]).unwrap();
+	assert_eq!(bits.len(), 30);
+	let err = bits.extend_from_bitslice(bits![1; 3]).unwrap_err();
+	assert_eq!((err.needed(), err.capacity()), (33, 32));
+	assert_eq!(bits.len(), 30);
+
+	bits.push(false).unwrap();
+	bits.push(true).unwrap();
+	assert!(bits.is_full());
+	assert_eq!(
+		crate::error::Error::from(bits.push(true).unwrap_err()),
+		crate::error::Error::Capacity {
+			needed: 33,
+			capacity: 32,
+		},
+	);
+
+	assert_eq!(bits.pop(), Some(true));
+	assert_eq!(bits.pop(), Some(false));
+	bits.truncate(4);
+	assert_eq!(bits, bits![1; 4]);
+
+	let copy = bits.clone();
+	bits.clear();
+	assert!(bits.is_empty());
+	assert_eq!(bits.into_bitarray().value(), [0; 2]);
+	assert_eq!(copy.into_bitarray().value(), [0b1111, 0]);
+
+	assert!(BitArrayVec::<Lsb0, u8>::from_bitslice(bits![0; 9]).is_err());
 }
@@ -27,6 +27,7 @@ use core::{
 		Formatter,
 		LowerHex,
 		Octal,
+		Pointer,
 		UpperHex,
 	},
 	hash::{
@@ -86,17 +87,42 @@ where
 	}
 }
 
-impl<O, V, T> PartialEq<BitArray<O, V>> for BitSlice<O, T>
+impl<O1, O2, V, T> PartialEq<BitArray<O2, V>> for BitSlice<O1, T>
 where
-	O: BitOrder,
+	O1: BitOrder,
+	O2: BitOrder,
 	V: BitView,
 	T: BitStore,
 {
-	fn eq(&self, other: &BitArray<O, V>) -> bool {
+	fn eq(&self, other: &BitArray<O2, V>) -> bool {
 		self == other.as_bitslice()
 	}
 }
 
+impl<O1, O2, V, T> PartialEq<BitArray<O2, V>> for &BitSlice<O1, T>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	V: BitView,
+	T: BitStore,
+{
+	fn eq(&self, other: &BitArray<O2, V>) -> bool {
+		*self == other.as_bitslice()
+	}
+}
+
+impl<O1, O2, V, T> PartialEq<BitArray<O2, V>> for &mut BitSlice<O1, T>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	V: BitView,
+	T: BitStore,
+{
+	fn eq(&self, other: &BitArray<O2, V>) -> bool {
+		**self == other.as_bitslice()
+	}
+}
+
 impl<O, V, Rhs> PartialEq<Rhs> for BitArray<O, V>
 where
 	O: BitOrder,
@@ -109,13 +135,38 @@ where
 	}
 }
 
-impl<O, V, T> PartialOrd<BitArray<O, V>> for BitSlice<O, T>
+impl<O1, O2, V, T> PartialOrd<BitArray<O2, V>> for BitSlice<O1, T>
 where
-	O: BitOrder,
+	O1: BitOrder,
+	O2: BitOrder,
+	V: BitView,
+	T: BitStore,
+{
+	fn partial_cmp(&self, other: &BitArray<O2, V>) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+impl<O1, O2, V, T> PartialOrd<BitArray<O2, V>> for &BitSlice<O1, T>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	V: BitView,
+	T: BitStore,
+{
+	fn partial_cmp(&self, other: &BitArray<O2, V>) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+impl<O1, O2, V, T> PartialOrd<BitArray<O2, V>> for &mut BitSlice<O1, T>
+where
+	O1: BitOrder,
+	O2: BitOrder,
 	V: BitView,
 	T: BitStore,
 {
-	fn partial_cmp(&self, other: &BitArray<O, V>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitArray<O2, V>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
@@ -287,6 +338,16 @@ where
 	}
 }
 
+impl<O, V> Pointer for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Pointer::fmt(self.as_bitslice(), fmt)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<O, V> Hash for BitArray<O, V>
 where
@@ -397,8 +458,8 @@ where
 	}
 }
 
-#[cfg(feature = "std")]
-impl<'a, O, T> std::error::Error for TryFromBitSliceError<'a, O, T>
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<'a, O, T> crate::error::StdError for TryFromBitSliceError<'a, O, T>
 where
 	O: BitOrder,
 	T: BitStore,
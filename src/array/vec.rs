@@ -0,0 +1,382 @@
+//! A fixed-capacity bit-vector stored in a `BitArray`.
+
+use crate::{
+	array::BitArray,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitView,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+/** A bit-vector with a runtime length and a fixed, inline, capacity.
+
+This holds a [`BitArray`] buffer and a count of how many of its bits are live.
+It grows and shrinks like a [`BitVec`], but never allocates: operations that
+would need more bits than the buffer holds fail with [`CapacityError`], and
+leave the vector unchanged. This makes it usable in `#![no_std]` programs that
+have no allocator, but still need a buffer whose length changes at runtime.
+
+The bits after the live region are always kept cleared, so the buffer given
+back by [`.into_bitarray()`] contains only the live bits.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+- `V`: The buffer type, as in [`BitArray`]. Its bit count is the capacity.
+
+# Examples
+
+```rust
+use bitvec::{
+  array::BitArrayVec,
+  prelude::*,
+};
+
+let mut bits = BitArrayVec::<Msb0, [u8; 1]>::new();
+assert_eq!(bits.capacity(), 8);
+
+for _ in 0 .. 5 {
+  bits.push(true).unwrap();
+}
+bits.extend_from_bitslice(bits![0, 1, 0]).unwrap();
+assert!(bits.is_full());
+assert!(bits.push(false).is_err());
+
+assert_eq!(bits.pop(), Some(false));
+assert_eq!(bits.as_bitslice(), bits![1, 1, 1, 1, 1, 0, 1]);
+assert_eq!(bits.into_bitarray().value(), [0b1111_1010]);
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitVec`]: crate::vec::BitVec
+[`CapacityError`]: crate::array::CapacityError
+[`.into_bitarray()`]: Self::into_bitarray
+**/
+pub struct BitArrayVec<O = Lsb0, V = [usize; 1]>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// The buffer. All bits at and after `len` are cleared.
+	data: BitArray<O, V>,
+	/// The number of live bits in `data`.
+	len: usize,
+}
+
+impl<O, V> BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	/// Constructs an empty vector.
+	pub fn new() -> Self {
+		Self {
+			data: BitArray::zeroed(),
+			len: 0,
+		}
+	}
+
+	/// Copies a bit-slice into a new vector.
+	///
+	/// # Errors
+	///
+	/// This fails if `bits` is longer than the capacity.
+	pub fn from_bitslice<O2, T2>(
+		bits: &BitSlice<O2, T2>,
+	) -> Result<Self, CapacityError>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut out = Self::new();
+		out.extend_from_bitslice(bits)?;
+		Ok(out)
+	}
+
+	/// Counts the live bits in the vector.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the vector has no live bits.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Counts the bits the vector can hold.
+	///
+	/// This is the number of bits in `V`.
+	pub fn capacity(&self) -> usize {
+		V::const_bits()
+	}
+
+	/// Tests if the vector has no room for another bit.
+	pub fn is_full(&self) -> bool {
+		self.len == self.capacity()
+	}
+
+	/// Views the live bits.
+	pub fn as_bitslice(&self) -> &BitSlice<O, V::Store> {
+		&self.data[.. self.len]
+	}
+
+	/// Views the live bits mutably.
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<O, V::Store> {
+		&mut self.data[.. self.len]
+	}
+
+	/// Unwraps the buffer. Every bit after the live region is cleared.
+	pub fn into_bitarray(self) -> BitArray<O, V> {
+		self.data
+	}
+
+	/// Appends a bit to the end of the vector.
+	///
+	/// # Errors
+	///
+	/// This fails, and leaves the vector unchanged, if it is already full.
+	///
+	/// # Original
+	///
+	/// [`Vec::push`](alloc::vec::Vec::push)
+	pub fn push(&mut self, value: bool) -> Result<(), CapacityError> {
+		self.reserve(1)?;
+		self.data.set(self.len, value);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Removes the last bit from the vector.
+	///
+	/// # Returns
+	///
+	/// The removed bit, or `None` if the vector was empty.
+	///
+	/// # Original
+	///
+	/// [`Vec::pop`](alloc::vec::Vec::pop)
+	pub fn pop(&mut self) -> Option<bool> {
+		if self.is_empty() {
+			return None;
+		}
+		self.len -= 1;
+		let out = self.data[self.len];
+		self.data.set(self.len, false);
+		Some(out)
+	}
+
+	/// Appends a bit-slice to the end of the vector.
+	///
+	/// # Errors
+	///
+	/// This fails, and leaves the vector unchanged, if the vector does not have
+	/// room for all of `bits`.
+	pub fn extend_from_bitslice<O2, T2>(
+		&mut self,
+		bits: &BitSlice<O2, T2>,
+	) -> Result<(), CapacityError>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let add = bits.len();
+		self.reserve(add)?;
+		let len = self.len;
+		self.data[len .. len + add].clone_from_bitslice(bits);
+		self.len += add;
+		Ok(())
+	}
+
+	/// Shortens the vector, clearing the removed bits.
+	///
+	/// This has no effect if `len` is not less than [`self.len()`].
+	///
+	/// # Original
+	///
+	/// [`Vec::truncate`](alloc::vec::Vec::truncate)
+	///
+	/// [`self.len()`]: Self::len
+	pub fn truncate(&mut self, len: usize) {
+		if len < self.len {
+			self.data[len .. self.len].set_all(false);
+			self.len = len;
+		}
+	}
+
+	/// Removes all bits from the vector.
+	///
+	/// # Original
+	///
+	/// [`Vec::clear`](alloc::vec::Vec::clear)
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Checks that `additional` more bits fit in the vector.
+	fn reserve(&self, additional: usize) -> Result<(), CapacityError> {
+		let capacity = self.capacity();
+		if capacity - self.len < additional {
+			return Err(CapacityError {
+				needed: self.len.saturating_add(additional),
+				capacity,
+			});
+		}
+		Ok(())
+	}
+}
+
+impl<O, V> Clone for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn clone(&self) -> Self {
+		Self {
+			data: self.data.clone(),
+			len: self.len,
+		}
+	}
+}
+
+impl<O, V> Debug for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().bitptr().render(fmt, "ArrayVec", None)?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+impl<O, V> Default for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<O, V> Deref for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	type Target = BitSlice<O, V::Store>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<O, V> DerefMut for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O, V> Display for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, V> Eq for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+}
+
+impl<O, V> Hash for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(state)
+	}
+}
+
+impl<O, V, Rhs> PartialEq<Rhs> for BitArrayVec<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+	Rhs: ?Sized + PartialEq<BitSlice<O, V::Store>>,
+{
+	fn eq(&self, other: &Rhs) -> bool {
+		other == self.as_bitslice()
+	}
+}
+
+/** The error type returned when a [`BitArrayVec`] has no room for more bits.
+
+[`BitArrayVec`]: crate::array::BitArrayVec
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CapacityError {
+	/// The length that the rejected operation required.
+	needed: usize,
+	/// The capacity of the vector.
+	capacity: usize,
+}
+
+impl CapacityError {
+	/// Gets the length that the rejected operation required.
+	pub fn needed(self) -> usize {
+		self.needed
+	}
+
+	/// Gets the capacity of the vector that rejected the operation.
+	pub fn capacity(self) -> usize {
+		self.capacity
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for CapacityError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"{} bits exceeds the capacity of {} bits",
+			self.needed, self.capacity,
+		)
+	}
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl crate::error::StdError for CapacityError {
+}
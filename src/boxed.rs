@@ -30,6 +30,7 @@ before freezing it.
 #![cfg(feature = "alloc")]
 
 use crate::{
+	error::Error,
 	index::BitIdx,
 	mem::BitMemory,
 	order::{
@@ -141,8 +142,9 @@ where
 	///
 	/// # Effects
 	///
-	/// This delegates to [`BitVec::from_bitslice`], then discards the excess
-	/// capacity.
+	/// This allocates exactly the memory elements that `slice` touches, and
+	/// copies them, as [`BitVec::from_bitslice`] does. It does not construct a
+	/// `BitVec` on the way, so no excess capacity is ever allocated.
 	///
 	/// # Examples
 	///
@@ -157,7 +159,49 @@ where
 	///
 	/// [`BitVec::from_bitslice`]: crate::vec::BitVec::from_bitslice
 	pub fn from_bitslice(slice: &BitSlice<O, T>) -> Self {
-		BitVec::from_bitslice(slice).into_boxed_bitslice()
+		let mut bitptr = slice.bitptr();
+		let boxed = slice
+			.domain()
+			.collect::<Box<[T::Mem]>>()
+			.pipe(ManuallyDrop::new);
+		unsafe {
+			bitptr.set_pointer(boxed.as_ptr() as *const T);
+			Self::from_raw(bitptr.to_bitslice_ptr_mut())
+		}
+	}
+
+	/// Copies a slice of memory elements into a new bit-box.
+	///
+	/// This checks the length of `slice` before allocating, so buffers of
+	/// untrusted size can be rejected without a panic.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: Some memory elements to copy into a bit-box.
+	///
+	/// # Returns
+	///
+	/// A `BitBox` over a copy of `slice`.
+	///
+	/// # Errors
+	///
+	/// This returns [`Error::TooLong`] if `slice` has too many elements to be
+	/// viewed as bits. See [`BitSlice::MAX_ELTS`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = BitBox::<Lsb0, u16>::try_from_slice(&[1, 2]).unwrap();
+	/// assert_eq!(bb.len(), 32);
+	/// assert_eq!(bb.iter_ones().collect::<Vec<_>>(), [0, 17]);
+	/// ```
+	///
+	/// [`BitSlice::MAX_ELTS`]: crate::slice::BitSlice::MAX_ELTS
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	pub fn try_from_slice(slice: &[T]) -> Result<Self, Error> {
+		BitSlice::from_slice(slice).map(Self::from_bitslice)
 	}
 
 	/// Converts a `Box<[T]>` into a `BitBox`<O, T>` without copying its buffer.
@@ -206,9 +250,13 @@ where
 	///
 	/// # Returns
 	///
-	/// If `boxed` is short enough to be viewed as a `BitBox`, then this returns
-	/// a `BitBox` over the `boxed` buffer. If `boxed` is too long, then this
-	/// returns `boxed` unmodified.
+	/// A `BitBox` over the `boxed` buffer.
+	///
+	/// # Errors
+	///
+	/// If `boxed` is too long to be viewed as bits, this returns
+	/// [`Error::TooLong`] alongside `boxed`, unmodified. See
+	/// [`BitSlice::MAX_ELTS`].
 	///
 	/// # Examples
 	///
@@ -221,10 +269,15 @@ where
 	/// assert_eq!(bb[..], bits![0; 32]);
 	/// assert_eq!(addr, bb.as_slice().as_ptr());
 	/// ```
-	pub fn try_from_boxed_slice(boxed: Box<[T]>) -> Result<Self, Box<[T]>> {
+	///
+	/// [`BitSlice::MAX_ELTS`]: crate::slice::BitSlice::MAX_ELTS
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	pub fn try_from_boxed_slice(
+		boxed: Box<[T]>,
+	) -> Result<Self, (Error, Box<[T]>)> {
 		let len = boxed.len();
-		if len > BitSlice::<O, T>::MAX_ELTS {
-			return Err(boxed);
+		if let Err(err) = BitSlice::<O, T>::check_slice_len(len) {
+			return Err((err, boxed));
 		}
 
 		let base = boxed.as_ptr();
@@ -421,7 +474,7 @@ where
 		unsafe { slice::from_raw_parts_mut(base, elts) }
 	}
 
-	/// Sets the uninitialized bits of the vector to a fixed value.
+	/// Sets the uninitialized bits of the box to a fixed value.
 	///
 	/// This method modifies all bits in the allocated buffer that are outside
 	/// the `self.as_bitslice()` view so that they have a consistent value. This
@@ -437,13 +490,13 @@ where
 	/// assert_eq!(bb.count_ones(), 2);
 	/// assert_eq!(bb.as_slice(), &[220u8]);
 	///
-	/// bb.set_uninitialized(false);
+	/// bb.fill_uninitialized(false);
 	/// assert_eq!(bb.as_slice(), &[12u8]);
 	///
-	/// bb.set_uninitialized(true);
+	/// bb.fill_uninitialized(true);
 	/// assert_eq!(bb.as_slice(), &[!3u8]);
 	/// ```
-	pub fn set_uninitialized(&mut self, value: bool) {
+	pub fn fill_uninitialized(&mut self, value: bool) {
 		let head = self.bitptr().head().value() as usize;
 		let tail = head + self.len();
 		let elts = self.bitptr().elements() * T::Mem::BITS as usize;
@@ -457,6 +510,12 @@ where
 		}
 	}
 
+	/// Sets the uninitialized bits of the box to a fixed value.
+	#[deprecated = "Use `.fill_uninitialized()` to set the dead bits"]
+	pub fn set_uninitialized(&mut self, value: bool) {
+		self.fill_uninitialized(value);
+	}
+
 	/// Views the handle’s encoded pointer.
 	pub(crate) fn bitptr(&self) -> BitPtr<O, T> {
 		self.pointer.as_ptr().pipe(BitPtr::from_bitslice_ptr_mut)
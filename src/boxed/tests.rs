@@ -8,6 +8,7 @@ use core::convert::TryInto;
 use alloc::{
 	boxed::Box,
 	format,
+	vec::Vec,
 };
 
 #[test]
@@ -37,9 +38,9 @@ fn api() {
 	let mut bb = 0b1001_0110u8.view_bits::<Msb0>()[2 .. 6]
 		.to_bitvec()
 		.into_boxed_bitslice();
-	bb.set_uninitialized(false);
+	bb.fill_uninitialized(false);
 	assert_eq!(bb.as_slice(), &[0b0001_0100]);
-	bb.set_uninitialized(true);
+	bb.fill_uninitialized(true);
 	assert_eq!(bb.as_slice(), &[0b1101_0111]);
 	assert_eq!(bb, bits![0, 1, 0, 1]);
 }
@@ -81,6 +82,33 @@ fn convert() {
 	assert_eq!(&boxed[..], &[0; 2]);
 
 	let _: BitBox<Lsb0, u32> = boxed.try_into().unwrap();
+
+	let boxed = BitBox::<Msb0, u8>::try_from_slice(&[0x80, 1]).unwrap();
+	assert_eq!(boxed.iter_ones().collect::<Vec<_>>(), [0, 15]);
+
+	#[cfg(not(miri))]
+	assert!(matches!(
+		BitBox::<LocalBits, u8>::try_from_slice(unsafe {
+			core::slice::from_raw_parts(
+				1usize as *const _,
+				BitSlice::<LocalBits, u8>::MAX_ELTS,
+			)
+		}),
+		Err(crate::error::Error::TooLong { .. })
+	));
+
+	let mut data = [0b1011_0110u8, 0b0100_1101];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 .. 13];
+	let boxed = BitBox::from_bitslice(bits);
+	assert_eq!(boxed.as_slice().len(), 2);
+	assert_eq!(boxed, bits);
+	let boxed: BitBox<Msb0, u8> = bits.into();
+	assert_eq!(boxed.clone(), boxed);
+	assert_eq!(boxed.clone().as_slice(), boxed.as_slice());
+
+	let boxed: BitBox<Msb0, u8> = [true, false, true].iter().copied().collect();
+	assert_eq!(boxed, bits![1, 0, 1]);
+	assert_eq!(boxed.into_bitvec().capacity(), 8);
 }
 
 #[test]
@@ -138,4 +166,9 @@ fn format() {
 		text
 	);
 	assert!(text.ends_with(", head: 000, bits: 4 } [0100]"), "{}", text);
+
+	assert_eq!(
+		format!("{:p}", b),
+		format!("{:p}[0]", b.as_slice().as_ptr())
+	);
 }
@@ -2,6 +2,7 @@
 
 use crate::{
 	boxed::BitBox,
+	error::Error,
 	order::BitOrder,
 	ptr::BitPtr,
 	slice::BitSlice,
@@ -33,6 +34,7 @@ use core::{
 		Hash,
 		Hasher,
 	},
+	iter::FromIterator,
 };
 
 use tap::pipe::Pipe;
@@ -124,10 +126,11 @@ impl<O, T, Rhs> PartialEq<Rhs> for BitBox<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
-	Rhs: ?Sized + PartialEq<BitSlice<O, T>>,
+	Rhs: ?Sized,
+	BitSlice<O, T>: PartialEq<Rhs>,
 {
 	fn eq(&self, other: &Rhs) -> bool {
-		other == self.as_bitslice()
+		self.as_bitslice() == other
 	}
 }
 
@@ -147,10 +150,11 @@ impl<O, T, Rhs> PartialOrd<Rhs> for BitBox<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
-	Rhs: ?Sized + PartialOrd<BitSlice<O, T>>,
+	Rhs: ?Sized,
+	BitSlice<O, T>: PartialOrd<Rhs>,
 {
 	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
-		other.partial_cmp(self.as_bitslice())
+		self.as_bitslice().partial_cmp(other)
 	}
 }
 
@@ -208,6 +212,16 @@ where
 	}
 }
 
+impl<O, T> From<&mut BitSlice<O, T>> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(slice: &mut BitSlice<O, T>) -> Self {
+		Self::from_bitslice(slice)
+	}
+}
+
 impl<O, T> From<BitVec<O, T>> for BitBox<O, T>
 where
 	O: BitOrder,
@@ -233,7 +247,7 @@ where
 	O: BitOrder,
 	T: BitStore,
 {
-	type Error = Box<[T]>;
+	type Error = (Error, Box<[T]>);
 
 	fn try_from(boxed: Box<[T]>) -> Result<Self, Self::Error> {
 		Self::try_from_boxed_slice(boxed)
@@ -258,7 +272,7 @@ where
 	T: BitStore,
 {
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		Pointer::fmt(self, fmt)?;
+		self.bitptr().render(fmt, "Box", None)?;
 		fmt.write_str(" ")?;
 		Display::fmt(self, fmt)
 	}
@@ -310,7 +324,7 @@ where
 	T: BitStore,
 {
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-		self.bitptr().render(fmt, "Box", None)
+		Pointer::fmt(self.as_bitslice(), fmt)
 	}
 }
 
@@ -336,6 +350,17 @@ where
 	}
 }
 
+impl<O, T> FromIterator<bool> for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = bool> {
+		BitVec::from_iter(iter).into_boxed_bitslice()
+	}
+}
+
 /// This is not present on `Box<[T]>`, but is needed to fit into the general
 /// operator implementations.
 impl<O, T> IntoIterator for BitBox<O, T>
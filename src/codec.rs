@@ -0,0 +1,1113 @@
+/*! Bit-stream codes.
+
+Compressed media formats pack their headers with integer codes whose widths
+depend on their values, rather than with fixed-width fields, and serial links
+transform their frames so that the receiver can find their boundaries. This
+module reads these codes out of a [`BitSlice`], and appends them to a
+[`BitVec`].
+
+Codes are laid out in the index order of the bit-slice, and the bits of each
+integer inside a code are written most significant first, as the bit-stream
+formats that use them specify. This is independent of the [`BitOrder`] type
+parameter, which only governs where each index lives in memory; use [`Msb0`]
+over bytes to match the bit-streams in files.
+
+The reading methods take the position of the code as a cursor, which they
+advance past the code when they succeed, and leave unchanged when they fail, so
+that successive calls read successive codes.
+
+- [`.read_unary()`] and [`.write_unary()`] handle the unary code, in which `n`
+  is written as `n` `0` bits followed by a `1` bit.
+- [`.read_exp_golomb()`] and [`.write_exp_golomb()`] handle the exponential
+  Golomb codes of order `k`. Order `0` is the `ue(v)` code of H.264 and HEVC.
+- [`.read_varint()`] and [`.write_varint()`] handle unsigned LEB128, the
+  variable-length integers of Protocol Buffers, DWARF, and WebAssembly. The
+  `_zigzag` variants handle the zig-zag encoding of signed integers used by
+  Protocol Buffers. The bytes of these codes need not start on byte boundaries,
+  so they can be mixed freely with fields of other widths.
+- [`.find_value()`] finds a fixed-width field, such as a sync marker, at any
+  index.
+- [`.stuff_bits_into()`] and [`.destuff_bits_into()`] insert and remove the `0`
+  bit that HDLC, CAN, and USB place after every five consecutive `1` bits, so
+  that a frame never contains their flag or idle patterns.
+- [`.manchester_encode_into()`] and [`.manchester_decode_into()`] convert
+  between data and its Manchester line code, which sends each bit as a
+  transition in the middle of its period.
+- [`.nrzi_encode_into()`] and [`.nrzi_decode_into()`] convert between data and
+  its NRZI line code, which sends each `0` bit as a change of level and each `1`
+  bit as no change.
+
+[`BitOrder`]: crate::order::BitOrder
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`Msb0`]: crate::order::Msb0
+[`.destuff_bits_into()`]: crate::slice::BitSlice::destuff_bits_into
+[`.find_value()`]: crate::slice::BitSlice::find_value
+[`.manchester_decode_into()`]: crate::slice::BitSlice::manchester_decode_into
+[`.manchester_encode_into()`]: crate::slice::BitSlice::manchester_encode_into
+[`.nrzi_decode_into()`]: crate::slice::BitSlice::nrzi_decode_into
+[`.nrzi_encode_into()`]: crate::slice::BitSlice::nrzi_encode_into
+[`.read_exp_golomb()`]: crate::slice::BitSlice::read_exp_golomb
+[`.read_unary()`]: crate::slice::BitSlice::read_unary
+[`.read_varint()`]: crate::slice::BitSlice::read_varint
+[`.stuff_bits_into()`]: crate::slice::BitSlice::stuff_bits_into
+[`.write_exp_golomb()`]: crate::vec::BitVec::write_exp_golomb
+[`.write_unary()`]: crate::vec::BitVec::write_unary
+[`.write_varint()`]: crate::vec::BitVec::write_varint
+!*/
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+use core::{
+	any::TypeId,
+	cmp,
+	convert::{
+		TryFrom,
+		TryInto,
+	},
+};
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Reads a unary code.
+	///
+	/// The code for `n` is `n` `0` bits followed by one `1` bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pos`: The index at which the code begins. On success, this is moved
+	///   to the index after the code.
+	///
+	/// # Returns
+	///
+	/// The number of `0` bits before the first `1` bit at or after `*pos`, or
+	/// `None` if there is no `1` bit there.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 1, 0];
+	/// let mut pos = 0;
+	/// assert_eq!(bits.read_unary(&mut pos), Some(0));
+	/// assert_eq!(bits.read_unary(&mut pos), Some(2));
+	/// assert_eq!(pos, 4);
+	/// assert!(bits.read_unary(&mut pos).is_none());
+	/// assert_eq!(pos, 4);
+	/// ```
+	pub fn read_unary(&self, pos: &mut usize) -> Option<usize> {
+		let zeros = self.get(*pos ..)?.first_one()?;
+		*pos += zeros + 1;
+		Some(zeros)
+	}
+
+	/// Reads an exponential Golomb code of order `k`.
+	///
+	/// The code for `n` writes `n + 2^k` in binary, with as many `0` bits
+	/// before it as it has bits after its leading `1`, less `k`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pos`: The index at which the code begins. On success, this is moved
+	///   to the index after the code.
+	/// - `k`: The order of the code. This must be less than 64.
+	///
+	/// # Returns
+	///
+	/// The decoded value, or `None` if the code runs past the end of the slice
+	/// or encodes a value that does not fit in a `u64`.
+	///
+	/// # Panics
+	///
+	/// This panics if `k` is not less than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// //  ue(v) codes for 0, 1, 2, and 7.
+	/// let bits = bits![Msb0, u8; 1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0];
+	/// let mut pos = 0;
+	/// assert_eq!(bits.read_exp_golomb(&mut pos, 0), Some(0));
+	/// assert_eq!(bits.read_exp_golomb(&mut pos, 0), Some(1));
+	/// assert_eq!(bits.read_exp_golomb(&mut pos, 0), Some(2));
+	/// assert_eq!(bits.read_exp_golomb(&mut pos, 0), Some(7));
+	/// assert_eq!(pos, bits.len());
+	/// ```
+	pub fn read_exp_golomb(&self, pos: &mut usize, k: u32) -> Option<u64> {
+		assert!(k < 64, "Exp-Golomb order {} must be less than 64", k);
+		let mut at = *pos;
+		let width = self.read_unary(&mut at)? + k as usize;
+		//  Past this width, even the smallest code exceeds `u64::MAX`.
+		if width > 64 {
+			return None;
+		}
+		let rest = self.get(at .. at.checked_add(width)?)?;
+		let code = rest.iter().fold(1u128, |acc, bit| acc << 1 | *bit as u128);
+		let value = u64::try_from(code - (1 << k)).ok()?;
+		*pos = at + width;
+		Some(value)
+	}
+
+	/// Reads an unsigned LEB128 variable-length integer.
+	///
+	/// The code is a sequence of bytes, each of which holds seven bits of the
+	/// value, least significant group first, below a high bit that is set in
+	/// every byte except the last. Each byte is eight consecutive bits of the
+	/// slice, starting at any index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `pos`: The index at which the code begins. On success, this is moved
+	///   to the index after the code.
+	///
+	/// # Returns
+	///
+	/// The decoded value, or `None` if the code runs past the end of the slice
+	/// or encodes a value that does not fit in a `u64`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0b101_10101u8, 0b100_00000, 0b010_00000];
+	/// let bits = data.view_bits::<Msb0>();
+	///
+	/// //  A three-bit field, then the varint `0xAC 0x02`.
+	/// let mut pos = 3;
+	/// assert_eq!(bits.read_varint(&mut pos), Some(300));
+	/// assert_eq!(pos, 19);
+	/// ```
+	pub fn read_varint(&self, pos: &mut usize) -> Option<u64> {
+		let mut at = *pos;
+		let mut value = 0u64;
+		for shift in (0 .. 64).step_by(7) {
+			let byte = self
+				.get(at .. at.checked_add(8)?)?
+				.iter()
+				.fold(0u8, |acc, bit| acc << 1 | *bit as u8);
+			at += 8;
+			let group = (byte & 0x7F) as u64;
+			//  The tenth byte has room for only the top bit of the value.
+			if group << shift >> shift != group {
+				return None;
+			}
+			value |= group << shift;
+			if byte & 0x80 == 0 {
+				*pos = at;
+				return Some(value);
+			}
+		}
+		None
+	}
+
+	/// Reads a zig-zag encoded signed integer in an LEB128 code.
+	///
+	/// Zig-zag encoding maps `0, -1, 1, -2, 2, …` to `0, 1, 2, 3, 4, …`, so
+	/// that values near zero have short codes whatever their sign. See
+	/// [`.read_varint()`] for the rest of the format.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![Msb0, u8; 0, 0, 0, 0, 0, 0, 1, 1];
+	/// assert_eq!(bits.read_varint_zigzag(&mut 0), Some(-2));
+	/// ```
+	///
+	/// [`.read_varint()`]: Self::read_varint
+	pub fn read_varint_zigzag(&self, pos: &mut usize) -> Option<i64> {
+		let value = self.read_varint(pos)?;
+		Some((value >> 1) as i64 ^ -((value & 1) as i64))
+	}
+
+	/// Finds the first `width`-bit field that holds `value`.
+	///
+	/// The field may start at any index. Under [`Lsb0`] and [`Msb0`], the
+	/// slice is loaded up to 64 bits at a time through [`BitField`], each load
+	/// is appended to the `width - 1` bits that preceded it, and the field
+	/// ending at every bit of the load is compared against `value`. Other
+	/// orderings shift the slice into the register a bit at a time.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: The integer type of the value to find.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `value`: The value to find. Only its `width` least significant bits
+	///   are compared.
+	/// - `width`: The width of the field, which must not exceed the width of
+	///   `U`.
+	///
+	/// # Returns
+	///
+	/// The index at which the first matching field begins, or `None` if no
+	/// field matches. A zero-width field matches at index `0`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` exceeds `U::BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// //  A 16-bit sync marker, three bits into the capture.
+	/// let data = [0b101_11100u8, 0b0111_0111, 0b000_00110];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits.find_value(0xE3B8u16, 16), Some(3));
+	/// assert_eq!(bits.find_value(0b0110u8, 4), Some(20));
+	/// assert!(bits.find_value(!0u32, 32).is_none());
+	/// ```
+	///
+	/// [`BitField`]: crate::field::BitField
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	pub fn find_value<U>(&self, value: U, width: usize) -> Option<usize>
+	where U: BitMemory {
+		assert!(
+			width <= U::BITS as usize,
+			"Cannot find a {}-bit field in a {}-bit value",
+			width,
+			U::BITS,
+		);
+		if width == 0 {
+			return Some(0);
+		}
+		let mask = !0u128 >> (128 - width);
+		let value = match TryInto::<u128>::try_into(value) {
+			Ok(value) => mask & value,
+			Err(_) => unreachable!("Every `BitMemory` type fits in `u128`"),
+		};
+		if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+			let this: &BitSlice<Lsb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			return find_in_words(this, value, width, |chunk| {
+				chunk.load_le::<u64>().reverse_bits() >> (64 - chunk.len())
+			});
+		}
+		if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+			let this: &BitSlice<Msb0, T> =
+				unsafe { &*(self as *const _ as *const _) };
+			return find_in_words(this, value, width, |chunk| {
+				chunk.load_be::<u64>()
+			});
+		}
+		let mut window = 0u128;
+		for (idx, bit) in self.iter().copied().enumerate() {
+			window = (window << 1 | bit as u128) & mask;
+			if idx + 1 >= width && window == value {
+				return Some(idx + 1 - width);
+			}
+		}
+		None
+	}
+}
+
+/// Finds the first `width`-bit field that holds `value`, a word at a time.
+///
+/// `load` reads a chunk of at most 64 bits into a word with its first bit
+/// most significant. Each word is appended to the `width - 1` bits before it,
+/// so that every field ending within the chunk is in the register, and the
+/// chunks are narrowed for wide fields so that the register fits in `u128`.
+fn find_in_words<O, T, F>(
+	bits: &BitSlice<O, T>,
+	value: u128,
+	width: usize,
+	load: F,
+) -> Option<usize>
+where
+	O: BitOrder,
+	T: BitStore,
+	F: Fn(&BitSlice<O, T>) -> u64,
+{
+	let mask = !0u128 >> (128 - width);
+	let step = cmp::min(64, 129 - width);
+	let mut window = 0u128;
+	for (num, chunk) in bits.chunks(step).enumerate() {
+		let len = chunk.len();
+		let base = num * step;
+		let word = window << len | load(chunk) as u128;
+		for idx in 0 .. len {
+			let end = base + idx + 1;
+			if end >= width && (word >> (len - 1 - idx)) & mask == value {
+				return Some(end - width);
+			}
+		}
+		window = word & mask >> 1;
+	}
+	None
+}
+
+/// The number of consecutive `1` bits after which a `0` bit is stuffed.
+#[cfg(feature = "alloc")]
+const STUFF_RUN: usize = 5;
+
+#[cfg(feature = "alloc")]
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Appends the slice to a bit-vector, with a `0` bit inserted after every
+	/// run of five `1` bits.
+	///
+	/// Runs of `1` bits are found a memory element at a time, and the bits
+	/// between them are copied in bulk, so sparse or dense frames do not cost a
+	/// test of every bit. As in HDLC, a frame that ends in five `1` bits has a
+	/// `0` bit appended.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let frame = bits![0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1];
+	/// let mut line = bitvec![];
+	/// frame.stuff_bits_into(&mut line);
+	/// assert_eq!(line, bits![0, 1, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 1, 1, 1, 0]);
+	/// ```
+	pub fn stuff_bits_into<O2, T2>(&self, out: &mut BitVec<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut from = 0;
+		for run in self.iter_one_ranges() {
+			out.extend_from_bitslice(&self[from .. run.start]);
+			for _ in 0 .. run.len() / STUFF_RUN {
+				out.resize(out.len() + STUFF_RUN, true);
+				out.push(false);
+			}
+			out.resize(out.len() + run.len() % STUFF_RUN, true);
+			from = run.end;
+		}
+		out.extend_from_bitslice(&self[from ..]);
+	}
+
+	/// Appends the slice to a bit-vector, with the `0` bit after every run of
+	/// five `1` bits removed.
+	///
+	/// This reverses [`.stuff_bits_into()`]. If the slice ends immediately
+	/// after five `1` bits, then the stuffed `0` bit is taken to have been cut
+	/// off with the end of the frame.
+	///
+	/// # Errors
+	///
+	/// A stuffed stream never has six consecutive `1` bits; in HDLC they mark
+	/// a flag or an abort. If the slice has one, then this stops, having
+	/// appended the bits before the run, and returns the index of its sixth
+	/// bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let line = bits![0, 1, 1, 1, 1, 1, 0, 1, 1, 0, 1, 1, 1, 1, 1, 0];
+	/// let mut frame = bitvec![];
+	/// line.destuff_bits_into(&mut frame).unwrap();
+	/// assert_eq!(frame, bits![0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1]);
+	///
+	/// let flag = bits![0, 1, 1, 1, 1, 1, 1, 0];
+	/// assert_eq!(flag.destuff_bits_into(&mut frame), Err(6));
+	/// ```
+	///
+	/// [`.stuff_bits_into()`]: Self::stuff_bits_into
+	pub fn destuff_bits_into<O2, T2>(
+		&self,
+		out: &mut BitVec<O2, T2>,
+	) -> Result<(), usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut from = 0;
+		for run in self.iter_one_ranges() {
+			if run.len() > STUFF_RUN {
+				out.extend_from_bitslice(&self[from .. run.start]);
+				return Err(run.start + STUFF_RUN);
+			}
+			out.extend_from_bitslice(&self[from .. run.end]);
+			from = run.end;
+			if run.len() == STUFF_RUN {
+				from += 1;
+			}
+		}
+		if let Some(rest) = self.get(from ..) {
+			out.extend_from_bitslice(rest);
+		}
+		Ok(())
+	}
+
+	/// Appends the Manchester line code of the slice to a bit-vector.
+	///
+	/// Each bit becomes two: a `0` bit is sent as `1, 0` and a `1` bit as
+	/// `0, 1`, as in IEEE 802.3. The opposite convention, used by some RFID
+	/// tags, is the inverse of this code.
+	///
+	/// The slice is encoded 32 bits at a time, with integer arithmetic rather
+	/// than a test of every bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut line = bitvec![];
+	/// bits![0, 1, 1].manchester_encode_into(&mut line);
+	/// assert_eq!(line, bits![1, 0, 0, 1, 0, 1]);
+	/// ```
+	pub fn manchester_encode_into<O2, T2>(&self, out: &mut BitVec<O2, T2>)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		out.reserve(self.len() * 2);
+		for chunk in self.chunks(32) {
+			let data = spread(load_word(chunk) as u32);
+			let line = data << 1 | !data & EVEN_BITS;
+			store_word(out, line, chunk.len() * 2);
+		}
+	}
+
+	/// Appends the data carried by a Manchester line code to a bit-vector.
+	///
+	/// This reverses [`.manchester_encode_into()`]. The slice is decoded 64
+	/// bits at a time, with integer arithmetic rather than a test of every
+	/// bit.
+	///
+	/// # Errors
+	///
+	/// Each pair of bits in the code must have two different values. If a pair
+	/// does not, or the slice has an odd length, then this stops, having
+	/// appended the data decoded before it, and returns the index of the first
+	/// bit of the invalid pair.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = bitvec![];
+	/// bits![1, 0, 0, 1, 0, 1].manchester_decode_into(&mut data).unwrap();
+	/// assert_eq!(data, bits![0, 1, 1]);
+	///
+	/// let bad = bits![0, 1, 1, 1];
+	/// assert_eq!(bad.manchester_decode_into(&mut data), Err(2));
+	/// ```
+	///
+	/// [`.manchester_encode_into()`]: Self::manchester_encode_into
+	pub fn manchester_decode_into<O2, T2>(
+		&self,
+		out: &mut BitVec<O2, T2>,
+	) -> Result<(), usize>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		out.reserve(self.len() / 2);
+		let mut start = 0;
+		for chunk in self.chunks(64) {
+			let pairs = chunk.len() / 2;
+			let line = load_word(chunk);
+			let (first, second) = (line & EVEN_BITS, line >> 1 & EVEN_BITS);
+			let bad = !(first ^ second) & EVEN_BITS & low_bits(pairs * 2);
+			if bad != 0 {
+				let idx = bad.trailing_zeros() as usize;
+				store_word(out, gather(second) as u64, idx / 2);
+				return Err(start + idx);
+			}
+			store_word(out, gather(second) as u64, pairs);
+			start += chunk.len();
+		}
+		if self.len() % 2 == 1 {
+			return Err(self.len() - 1);
+		}
+		Ok(())
+	}
+
+	/// Appends the NRZI line code of the slice to a bit-vector.
+	///
+	/// Each `0` bit is sent by inverting the line level, and each `1` bit by
+	/// holding it, as in USB. Paired with [`.stuff_bits_into()`], this
+	/// guarantees a change of level at least once every six bits.
+	///
+	/// The slice is encoded 64 bits at a time, with integer arithmetic rather
+	/// than a test of every bit.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `out`: The bit-vector to which the line levels are appended.
+	/// - `level`: The line level before the first bit is sent.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut line = bitvec![];
+	/// bits![0, 0, 1, 1, 0].nrzi_encode_into(&mut line, true);
+	/// assert_eq!(line, bits![0, 1, 1, 1, 0]);
+	/// ```
+	///
+	/// [`.stuff_bits_into()`]: Self::stuff_bits_into
+	pub fn nrzi_encode_into<O2, T2>(&self, out: &mut BitVec<O2, T2>, level: bool)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		out.reserve(self.len());
+		let mut level = 0u64.wrapping_sub(level as u64);
+		for chunk in self.chunks(64) {
+			let line = prefix_parity(!load_word(chunk)) ^ level;
+			store_word(out, line, chunk.len());
+			//  Broadcast the last level of the chunk across the next.
+			level = 0u64.wrapping_sub(line >> (chunk.len() - 1) & 1);
+		}
+	}
+
+	/// Appends the data carried by an NRZI line code to a bit-vector.
+	///
+	/// This reverses [`.nrzi_encode_into()`]. Every sequence of line levels is
+	/// a valid code, so this cannot fail.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `out`: The bit-vector to which the data bits are appended.
+	/// - `level`: The line level before the first bit was sent.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = bitvec![];
+	/// bits![0, 1, 1, 1, 0].nrzi_decode_into(&mut data, true);
+	/// assert_eq!(data, bits![0, 0, 1, 1, 0]);
+	/// ```
+	///
+	/// [`.nrzi_encode_into()`]: Self::nrzi_encode_into
+	pub fn nrzi_decode_into<O2, T2>(&self, out: &mut BitVec<O2, T2>, level: bool)
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		out.reserve(self.len());
+		let mut level = level as u64;
+		for chunk in self.chunks(64) {
+			let line = load_word(chunk);
+			store_word(out, !(line ^ (line << 1 | level)), chunk.len());
+			level = line >> (chunk.len() - 1) & 1;
+		}
+	}
+}
+
+/// The even-numbered bits of a word.
+#[cfg(feature = "alloc")]
+const EVEN_BITS: u64 = 0x5555_5555_5555_5555;
+
+/// Produces a word with its `n` least significant bits set, for `n <= 64`.
+#[cfg(feature = "alloc")]
+fn low_bits(n: usize) -> u64 {
+	if n == 0 { 0 } else { !0 >> (64 - n) }
+}
+
+/// Reads up to 64 bits into a word, with `bits[n]` at `1 << n`.
+///
+/// The [`Lsb0`] and [`Msb0`] orderings load the word a memory element at a
+/// time through [`BitField`]. [`Lsb0`] already places `bits[n]` at `1 << n`
+/// when loaded little-endian; [`Msb0`] places `bits[0]` at the most significant
+/// end when loaded big-endian, so its word is reversed. Other orderings visit
+/// only the `1` bits, one at a time.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`Lsb0`]: crate::order::Lsb0
+/// [`Msb0`]: crate::order::Msb0
+#[cfg(feature = "alloc")]
+fn load_word<O, T>(bits: &BitSlice<O, T>) -> u64
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let len = bits.len();
+	debug_assert!(len <= 64, "A word holds at most 64 bits");
+	if len == 0 {
+		return 0;
+	}
+	if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+		let bits: &BitSlice<Lsb0, T> =
+			unsafe { &*(bits as *const _ as *const _) };
+		return bits.load_le::<u64>();
+	}
+	if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+		let bits: &BitSlice<Msb0, T> =
+			unsafe { &*(bits as *const _ as *const _) };
+		return bits.load_be::<u64>().reverse_bits() >> (64 - len);
+	}
+	bits.iter_ones().fold(0, |word, idx| word | 1 << idx)
+}
+
+/// Appends the `len` least significant bits of a word to a bit-vector, with
+/// `1 << n` at the `n`th new index.
+///
+/// This is the inverse of [`load_word`], and uses [`BitField`] for the same
+/// orderings.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`load_word`]: self::load_word
+#[cfg(feature = "alloc")]
+fn store_word<O, T>(out: &mut BitVec<O, T>, word: u64, len: usize)
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let start = out.len();
+	out.resize(start + len, false);
+	if len == 0 {
+		return;
+	}
+	let mut word = word & low_bits(len);
+	let dest = unsafe { out.get_unchecked_mut(start ..) };
+	if TypeId::of::<O>() == TypeId::of::<Lsb0>() {
+		let dest: &mut BitSlice<Lsb0, T> =
+			unsafe { &mut *(dest as *mut _ as *mut _) };
+		dest.store_le(word);
+		return;
+	}
+	if TypeId::of::<O>() == TypeId::of::<Msb0>() {
+		let dest: &mut BitSlice<Msb0, T> =
+			unsafe { &mut *(dest as *mut _ as *mut _) };
+		dest.store_be(word.reverse_bits() >> (64 - len));
+		return;
+	}
+	while word != 0 {
+		let idx = word.trailing_zeros() as usize;
+		unsafe {
+			dest.set_unchecked(idx, true);
+		}
+		word &= word - 1;
+	}
+}
+
+/// Moves bit `n` of a 32-bit value to bit `2 * n` of a word.
+#[cfg(feature = "alloc")]
+fn spread(value: u32) -> u64 {
+	let mut word = value as u64;
+	word = (word | word << 16) & 0x0000_FFFF_0000_FFFF;
+	word = (word | word << 8) & 0x00FF_00FF_00FF_00FF;
+	word = (word | word << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+	word = (word | word << 2) & 0x3333_3333_3333_3333;
+	(word | word << 1) & EVEN_BITS
+}
+
+/// Moves bit `2 * n` of a word to bit `n` of a 32-bit value, discarding the
+/// odd-numbered bits. This reverses [`spread`].
+///
+/// [`spread`]: self::spread
+#[cfg(feature = "alloc")]
+fn gather(word: u64) -> u32 {
+	let mut word = word & EVEN_BITS;
+	word = (word | word >> 1) & 0x3333_3333_3333_3333;
+	word = (word | word >> 2) & 0x0F0F_0F0F_0F0F_0F0F;
+	word = (word | word >> 4) & 0x00FF_00FF_00FF_00FF;
+	word = (word | word >> 8) & 0x0000_FFFF_0000_FFFF;
+	(word | word >> 16) as u32
+}
+
+/// Sets each bit of a word to the parity of itself and all lower bits.
+#[cfg(feature = "alloc")]
+fn prefix_parity(mut word: u64) -> u64 {
+	for &shift in &[1, 2, 4, 8, 16, 32] {
+		word ^= word << shift;
+	}
+	word
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Appends a unary code.
+	///
+	/// See [`BitSlice::read_unary`] for the format.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![];
+	/// bv.write_unary(3);
+	/// bv.write_unary(0);
+	/// assert_eq!(bv, bits![0, 0, 0, 1, 1]);
+	/// ```
+	///
+	/// [`BitSlice::read_unary`]: crate::slice::BitSlice::read_unary
+	pub fn write_unary(&mut self, value: usize) {
+		self.resize(self.len() + value, false);
+		self.push(true);
+	}
+
+	/// Appends an exponential Golomb code of order `k`.
+	///
+	/// See [`BitSlice::read_exp_golomb`] for the format. Every `u64` value can
+	/// be written, and read back.
+	///
+	/// # Panics
+	///
+	/// This panics if `k` is not less than 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<Msb0, u8>::new();
+	/// bv.write_exp_golomb(7, 0);
+	/// bv.write_exp_golomb(5, 2);
+	/// assert_eq!(bv, bits![0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1]);
+	///
+	/// let mut pos = 0;
+	/// assert_eq!(bv.read_exp_golomb(&mut pos, 0), Some(7));
+	/// assert_eq!(bv.read_exp_golomb(&mut pos, 2), Some(5));
+	/// ```
+	///
+	/// [`BitSlice::read_exp_golomb`]: crate::slice::BitSlice::read_exp_golomb
+	pub fn write_exp_golomb(&mut self, value: u64, k: u32) {
+		assert!(k < 64, "Exp-Golomb order {} must be less than 64", k);
+		let code = value as u128 + (1 << k);
+		let width = 127 - code.leading_zeros();
+		self.resize(self.len() + (width - k) as usize, false);
+		self.extend((0 ..= width).rev().map(|idx| code >> idx & 1 != 0));
+	}
+
+	/// Appends an unsigned LEB128 variable-length integer.
+	///
+	/// See [`BitSlice::read_varint`] for the format. The code is as short as
+	/// the value permits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1, 0, 1];
+	/// bv.write_varint(300);
+	/// assert_eq!(bv.len(), 19);
+	/// assert_eq!(bv.as_slice(), [0b101_10101, 0b100_00000, 0b010_00000]);
+	/// ```
+	///
+	/// [`BitSlice::read_varint`]: crate::slice::BitSlice::read_varint
+	pub fn write_varint(&mut self, mut value: u64) {
+		loop {
+			let mut byte = (value & 0x7F) as u8;
+			value >>= 7;
+			if value != 0 {
+				byte |= 0x80;
+			}
+			self.extend((0 .. 8).rev().map(|idx| byte >> idx & 1 != 0));
+			if value == 0 {
+				break;
+			}
+		}
+	}
+
+	/// Appends a zig-zag encoded signed integer in an LEB128 code.
+	///
+	/// See [`BitSlice::read_varint_zigzag`] for the format.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<Msb0, u8>::new();
+	/// bv.write_varint_zigzag(-2);
+	/// bv.write_varint_zigzag(i64::MIN);
+	/// assert_eq!(bv.as_slice()[0], 3);
+	///
+	/// let mut pos = 0;
+	/// assert_eq!(bv.read_varint_zigzag(&mut pos), Some(-2));
+	/// assert_eq!(bv.read_varint_zigzag(&mut pos), Some(i64::MIN));
+	/// ```
+	///
+	/// [`BitSlice::read_varint_zigzag`]: crate::slice::BitSlice::read_varint_zigzag
+	pub fn write_varint_zigzag(&mut self, value: i64) {
+		self.write_varint((value << 1 ^ value >> 63) as u64);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	#[cfg(feature = "alloc")]
+	use crate::devel::XorShift;
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn exp_golomb_round_trip() {
+		let values = [0, 1, 2, 3, 100, 1 << 40, u64::MAX - 1, u64::MAX];
+		for k in [0, 1, 5, 63].iter().copied() {
+			let mut bv = BitVec::<Msb0, u8>::new();
+			for &value in &values {
+				bv.write_exp_golomb(value, k);
+				bv.write_unary(k as usize);
+			}
+
+			let mut pos = 0;
+			for &value in &values {
+				assert_eq!(bv.read_exp_golomb(&mut pos, k), Some(value));
+				assert_eq!(bv.read_unary(&mut pos), Some(k as usize));
+			}
+			assert_eq!(pos, bv.len());
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn varint_round_trip() {
+		let values = [0, 1, 127, 128, 300, 1 << 35, u64::MAX];
+		let signed = [0, -1, 1, -64, 64, i64::MIN, i64::MAX];
+		let mut bv = BitVec::<Lsb0, u16>::new();
+		for (&value, &signed) in values.iter().zip(&signed) {
+			bv.push(true);
+			bv.write_varint(value);
+			bv.write_varint_zigzag(signed);
+		}
+		assert_eq!(bv.len(), 7 + (23 + 26) * 8);
+
+		let mut pos = 0;
+		for (&value, &signed) in values.iter().zip(&signed) {
+			assert!(bv[pos]);
+			pos += 1;
+			assert_eq!(bv.read_varint(&mut pos), Some(value));
+			assert_eq!(bv.read_varint_zigzag(&mut pos), Some(signed));
+		}
+		assert_eq!(pos, bv.len());
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn bit_stuffing() {
+		let mut rng = XorShift::default();
+		let mut frame = BitVec::<Msb0, u8>::new();
+		while frame.len() < 2000 {
+			let state = rng.next_u32();
+			let run = state as usize % 13;
+			frame.resize(frame.len() + run, state & 0x100 != 0);
+			frame.push(state & 0x200 != 0);
+		}
+		frame.resize(frame.len() + 10, true);
+
+		let mut line = BitVec::<Lsb0, u32>::new();
+		frame[3 ..].stuff_bits_into(&mut line);
+		assert!(line.windows(6).all(|win| win.not_all()));
+		assert!(!line[line.len() - 1]);
+
+		let mut back = BitVec::<Msb0, u16>::new();
+		line.destuff_bits_into(&mut back).unwrap();
+		assert_eq!(back, frame[3 ..]);
+
+		back.clear();
+		line.pop();
+		line.destuff_bits_into(&mut back).unwrap();
+		assert_eq!(back, frame[3 ..]);
+
+		let bad = bits![1, 0, 1, 1, 1, 1, 1, 1, 1];
+		assert_eq!(bad.destuff_bits_into(&mut back), Err(7));
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn words() {
+		use super::{
+			load_word,
+			low_bits,
+			store_word,
+		};
+
+		fn check<O>()
+		where O: BitOrder {
+			let data = [0x9E37_79B9u32, 0x7F4A_7C15, 0xF39C_C060];
+			let bits = data.view_bits::<O>();
+			for start in 0 .. 32 {
+				for len in 0 ..= 64 {
+					let span = &bits[start .. start + len];
+					let word =
+						span.iter_ones().fold(0u64, |word, idx| word | 1 << idx);
+					assert_eq!(load_word(span), word);
+
+					let mut out = BitVec::<O, u8>::repeat(true, start % 11);
+					store_word(&mut out, word | !low_bits(len), len);
+					assert_eq!(out[start % 11 ..], span);
+				}
+			}
+		}
+		check::<Lsb0>();
+		check::<Msb0>();
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn line_coding() {
+		let mut rng = XorShift::new(0x9E37_79B9);
+		let mut frame = BitVec::<Msb0, u16>::new();
+		while frame.len() < 300 {
+			frame.push(rng.next_u32() & 0x10 != 0);
+		}
+		let data = &frame[5 ..];
+
+		let naive = data
+			.iter()
+			.flat_map(|&bit| vec![!bit, bit])
+			.collect::<BitVec>();
+		let mut line = BitVec::<Lsb0, u8>::new();
+		data.manchester_encode_into(&mut line);
+		assert_eq!(line, naive);
+
+		let mut back = BitVec::<Msb0, u32>::new();
+		line[..].manchester_decode_into(&mut back).unwrap();
+		assert_eq!(back, data);
+
+		back.clear();
+		line.set(201, false);
+		line.set(200, false);
+		assert_eq!(line[..].manchester_decode_into(&mut back), Err(200));
+		assert_eq!(back, data[.. 100]);
+		back.clear();
+		assert_eq!(line[.. 7].manchester_decode_into(&mut back), Err(6));
+		assert_eq!(back, data[.. 3]);
+
+		for &start in &[false, true] {
+			let mut level = start;
+			let naive = data
+				.iter()
+				.map(|&bit| {
+					level ^= !bit;
+					level
+				})
+				.collect::<BitVec>();
+			let mut line = BitVec::<Msb0, u64>::new();
+			data.nrzi_encode_into(&mut line, start);
+			assert_eq!(line, naive);
+
+			let mut back = BitVec::<Lsb0, u16>::new();
+			line[3 ..].nrzi_decode_into(&mut back, line[2]);
+			assert_eq!(back, data[3 ..]);
+		}
+	}
+
+	#[test]
+	fn varint_failures() {
+		let data = [0xFFu8; 11];
+		let bits = data.view_bits::<Msb0>();
+		assert!(bits.read_varint(&mut 0).is_none());
+		assert!(bits[.. 16].read_varint(&mut 0).is_none());
+
+		let mut data = [0xFFu8; 10];
+		data[9] = 0x01;
+		assert_eq!(data.view_bits::<Msb0>().read_varint(&mut 0), Some(!0));
+		data[9] = 0x02;
+		assert!(data.view_bits::<Msb0>().read_varint(&mut 0).is_none());
+	}
+
+	#[test]
+	fn exp_golomb_failures() {
+		let bits = bits![0, 0, 1, 0];
+		let mut pos = 0;
+		assert!(bits.read_exp_golomb(&mut pos, 0).is_none());
+		assert!(bits.read_exp_golomb(&mut pos, 1).is_none());
+		assert_eq!(pos, 0);
+		assert_eq!(bits.read_exp_golomb(&mut 1, 0), Some(1));
+		assert!(bits.read_exp_golomb(&mut 5, 0).is_none());
+
+		//  65 `0` bits prefix a value that cannot fit in `u64`, and 64 prefix
+		//  only `u64::MAX`.
+		let mut data = [0u8; 17];
+		data[8] = 0b0100_0000;
+		let bits = data.view_bits::<Msb0>();
+		assert!(bits.read_exp_golomb(&mut 0, 0).is_none());
+		assert_eq!(bits.read_exp_golomb(&mut 1, 0), Some(u64::MAX));
+	}
+
+	#[test]
+	fn find_value() {
+		let mut data = [0u32; 8];
+		let bits = data.view_bits_mut::<Lsb0>();
+		bits[77 .. 109].clone_from_bitslice(0x1ACF_FC1Du32.view_bits::<Msb0>());
+		bits[200 ..].set_all(true);
+
+		assert_eq!(bits.find_value(0x1ACF_FC1Du32, 32), Some(77));
+		assert_eq!(bits.find_value(0xFC1Du64, 16), Some(93));
+		assert_eq!(bits.find_value(0x3FFu16, 10), Some(89));
+		assert_eq!(bits.find_value(0x7FFu16, 11), Some(200));
+		assert_eq!(bits.find_value(!0u128, 56), Some(200));
+		assert!(bits.find_value(!0u128, 57).is_none());
+		assert_eq!(bits.find_value(0x8001u64, 1), Some(80));
+		assert_eq!(bits[.. 76].find_value(0usize, 0), Some(0));
+		assert!(bits![].find_value(0u8, 1).is_none());
+	}
+
+	#[test]
+	fn find_value_words() {
+		fn check<O>()
+		where O: BitOrder {
+			let data = [0x9E37_79B9u32, 0x7F4A_7C15, 0xF39C_C060, 0x85EB_CA6B];
+			let field = |span: &BitSlice<O, u32>| {
+				span.iter().fold(0u128, |acc, &bit| acc << 1 | bit as u128)
+			};
+			for &skip in &[0, 3] {
+				let bits = &data.view_bits::<O>()[skip ..];
+				for &width in &[1, 5, 31, 64, 65, 100, 124] {
+					for start in (0 ..= bits.len() - width).step_by(7) {
+						let value = field(&bits[start .. start + width]);
+						let naive = bits
+							.windows(width)
+							.position(|win| field(win) == value);
+						assert_eq!(bits.find_value(value, width), naive);
+					}
+				}
+			}
+		}
+		check::<Lsb0>();
+		check::<Msb0>();
+	}
+
+	#[test]
+	#[should_panic]
+	fn find_value_too_wide() {
+		bits![0; 20].find_value(0u16, 17);
+	}
+}
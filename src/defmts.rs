@@ -0,0 +1,177 @@
+/*! [`defmt`]-powered logging.
+
+This module implements [`Format`] for the [`bitvec`] types, so that firmware
+can log them through `defmt` without running `core::fmt` on the target.
+
+The wire representation is compact: the bits are packed eight to a byte, in
+slice order with the first bit of each byte in its most significant position,
+and sent as one byte slice together with the number of live bits. The host
+renders each byte in binary, so the log shows the bits in slice order, with
+the final byte padded by `0` bits after the end of the slice. No type or
+address information is sent.
+
+The bytes are packed into a buffer on the stack rather than an allocation, so
+a slice longer than that buffer continues in further byte slices of the same
+form, each following the one before it.
+
+[`Format`]: defmt::Format
+[`bitvec`]: crate
+[`defmt`]: defmt
+!*/
+
+#![cfg(feature = "defmt")]
+
+use crate::{
+	array::BitArray,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	view::BitView,
+};
+
+use defmt::{
+	Format,
+	Formatter,
+};
+
+#[cfg(feature = "alloc")]
+use crate::{
+	boxed::BitBox,
+	vec::BitVec,
+};
+
+/// The number of bytes packed into each byte slice sent to the host.
+const FRAME: usize = 64;
+
+impl<O, T> Format for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn format(&self, fmt: Formatter) {
+		let mut buf = [0u8; FRAME];
+		let mut frames = self.chunks(FRAME * 8);
+		let first = frames.next().unwrap_or_else(BitSlice::empty);
+		defmt::write!(
+			fmt,
+			"{=usize} bits {=[u8]:08b}",
+			self.len(),
+			pack(first, &mut buf),
+		);
+		for frame in frames {
+			defmt::write!(fmt, " {=[u8]:08b}", pack(frame, &mut buf));
+		}
+	}
+}
+
+impl<O, V> Format for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn format(&self, fmt: Formatter) {
+		self.as_bitslice().format(fmt)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Format for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn format(&self, fmt: Formatter) {
+		self.as_bitslice().format(fmt)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> Format for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn format(&self, fmt: Formatter) {
+		self.as_bitslice().format(fmt)
+	}
+}
+
+/// Packs up to `8 * FRAME` bits into bytes, with the first bit of each byte in
+/// its most significant position, and returns the bytes that were used.
+fn pack<'a, O, T>(bits: &BitSlice<O, T>, buf: &'a mut [u8; FRAME]) -> &'a [u8]
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let mut len = 0;
+	for (slot, byte) in buf.iter_mut().zip(bits.chunks(8)) {
+		//  Left-align a partial byte, so that its padding follows the slice.
+		*slot = byte.iter().fold(0u8, |accum, bit| accum << 1 | *bit as u8)
+			<< (8 - byte.len());
+		len += 1;
+	}
+	&buf[.. len]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use defmt::export::{
+		fetch_bytes,
+		make_formatter,
+	};
+
+	#[cfg(not(feature = "std"))]
+	use alloc::vec::Vec;
+
+	/// Formats a value, and returns the bytes sent after its format string.
+	fn sent<F>(value: &F) -> Vec<u8>
+	where F: ?Sized + Format {
+		fetch_bytes();
+		value.format(make_formatter());
+		//  Skip the format-string index.
+		fetch_bytes().split_off(2)
+	}
+
+	/// Encodes a bit count and byte slice as `defmt` sends them.
+	fn expect(len: usize, bytes: &[u8]) -> Vec<u8> {
+		let mut out = Vec::new();
+		out.extend_from_slice(&(len as u32).to_le_bytes());
+		out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+		out.extend_from_slice(bytes);
+		out
+	}
+
+	#[test]
+	fn wire_form() {
+		//  The bytes hold the bits in slice order, not in memory order.
+		let data = [0x5Au8, 0x01];
+		let bits = data.view_bits::<Lsb0>();
+		assert_eq!(sent(bits), expect(16, &[0x5A, 0x80]));
+		assert_eq!(sent(&bits[.. 12]), expect(12, &[0x5A, 0x80]));
+		assert_eq!(sent(&bits[3 .. 8]), expect(5, &[0xD0]));
+		assert_eq!(sent(&bits[6 .. 11]), expect(5, &[0xA0]));
+		assert_eq!(sent(BitSlice::<Msb0, u8>::empty()), expect(0, &[]));
+
+		let data = [0x5Au8, 0xC3];
+		let arr = BitArray::<Msb0, _>::new(data);
+		assert_eq!(sent(&arr), expect(16, &[0x5A, 0xC3]));
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn frames() {
+		let bv = BitVec::<Msb0, u8>::repeat(true, FRAME * 8 + 3);
+		let mut full = expect(bv.len(), &[0xFF; FRAME]);
+		//  The second frame has its own format string, and no bit count.
+		full.extend_from_slice(&[0, 0]);
+		full.extend_from_slice(&1u32.to_le_bytes());
+		full.push(0xE0);
+		let got = sent(&bv);
+		assert_eq!(got.len(), full.len());
+		assert_eq!(got[.. FRAME + 8], full[.. FRAME + 8]);
+		assert_eq!(got[FRAME + 10 ..], full[FRAME + 10 ..]);
+	}
+}
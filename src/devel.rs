@@ -75,6 +75,41 @@ pub fn assert_range(range: Range<usize>, end: impl Into<Option<usize>>) {
 	}
 }
 
+/** A xorshift generator, for tests that need long, irregular, and repeatable
+inputs.
+
+This is Marsaglia’s 32-bit xorshift, with the shift triple `(13, 17, 5)`. It
+is not suitable for anything but test data.
+**/
+#[cfg(test)]
+pub struct XorShift {
+	state: u32,
+}
+
+#[cfg(test)]
+impl XorShift {
+	/// Seeds a generator. The seed must not be zero.
+	pub fn new(seed: u32) -> Self {
+		debug_assert_ne!(seed, 0, "A zero seed never advances");
+		Self { state: seed }
+	}
+
+	/// Advances the generator, and returns its new state.
+	pub fn next_u32(&mut self) -> u32 {
+		self.state ^= self.state << 13;
+		self.state ^= self.state >> 17;
+		self.state ^= self.state << 5;
+		self.state
+	}
+}
+
+#[cfg(test)]
+impl Default for XorShift {
+	fn default() -> Self {
+		Self::new(0x2545_F491)
+	}
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
 	use super::*;
@@ -21,6 +21,7 @@ direct memory access to the underlying buffer for the duration of its existence.
 use crate::{
 	index::{
 		BitIdx,
+		BitMask,
 		BitTail,
 	},
 	mem::BitMemory,
@@ -39,6 +40,7 @@ use core::{
 		Octal,
 		UpperHex,
 	},
+	marker::PhantomData,
 	slice,
 };
 
@@ -716,6 +718,133 @@ impl<T> Copy for Domain<'_, T> where T: BitStore
 {
 }
 
+macro_rules! elements {
+	($t:ident => $elem:ty $(, $m:ident)?; $($derive:ident),*) => {
+		/// An iterator over the memory elements underlying a [`BitSlice`],
+		/// paired with the mask of live bits in each element.
+		///
+		/// The first and last elements are masked to the region of the
+		/// [`BitSlice`]; all interior elements are yielded with
+		/// [`BitMask::ALL`]. This allows callers to write word-parallel
+		/// algorithms without re-deriving the edge masks themselves.
+		///
+		/// [`BitMask::ALL`]: crate::index::BitMask::ALL
+		/// [`BitSlice`]: crate::slice::BitSlice
+		#[derive($($derive),*)]
+		pub struct $t <'a, O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+			/// The elements not yet yielded.
+			elems: &'a [$elem],
+			/// The live start index in the front element.
+			head: BitIdx<T::Mem>,
+			/// The live end index in the back element.
+			tail: BitTail<T::Mem>,
+			/// The ordering used to build masks.
+			_ord: PhantomData<O>,
+		}
+
+		impl<'a, O, T> $t <'a, O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+			pub(crate) fn new(slice: &'a $($m)? BitSlice<O, T>) -> Self {
+				let bitptr = slice.bitptr();
+				let elems = unsafe {
+					slice::from_raw_parts(
+						bitptr.pointer().to_const() as *const $elem,
+						bitptr.elements(),
+					)
+				};
+				Self {
+					elems,
+					head: bitptr.head(),
+					tail: bitptr.tail(),
+					_ord: PhantomData,
+				}
+			}
+		}
+
+		impl<'a, O, T> Iterator for $t <'a, O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+			type Item = (&'a $elem, BitMask<T::Mem>);
+
+			fn next(&mut self) -> Option<Self::Item> {
+				let (elem, rest) = self.elems.split_first()?;
+				let tail = if rest.is_empty() {
+					self.tail
+				}
+				else {
+					BitTail::LAST
+				};
+				let mask = O::mask(self.head, tail);
+				self.elems = rest;
+				self.head = BitIdx::ZERO;
+				Some((elem, mask))
+			}
+
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				let len = self.len();
+				(len, Some(len))
+			}
+
+			fn count(self) -> usize {
+				self.len()
+			}
+
+			fn last(mut self) -> Option<Self::Item> {
+				self.next_back()
+			}
+		}
+
+		impl<O, T> DoubleEndedIterator for $t <'_, O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+			fn next_back(&mut self) -> Option<Self::Item> {
+				let (elem, rest) = self.elems.split_last()?;
+				let head = if rest.is_empty() {
+					self.head
+				}
+				else {
+					BitIdx::ZERO
+				};
+				let mask = O::mask(head, self.tail);
+				self.elems = rest;
+				self.tail = BitTail::LAST;
+				Some((elem, mask))
+			}
+		}
+
+		impl<O, T> ExactSizeIterator for $t <'_, O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+			fn len(&self) -> usize {
+				self.elems.len()
+			}
+		}
+
+		impl<O, T> core::iter::FusedIterator for $t <'_, O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+		}
+	};
+}
+
+elements!(Elements => T; Clone, Debug);
+elements!(ElementsMut => T::Access, mut; Debug);
+
 macro_rules! fmt {
 	($($f:ty => $fwd:ident),+ $(,)?) => { $(
 		impl<T> $f for Domain<'_, T>
@@ -750,4 +879,37 @@ mod tests {
 			assert_eq!(iter, elem);
 		}
 	}
+
+	#[test]
+	fn elements() {
+		use radium::Radium;
+
+		let data = [0u16; 3];
+		let bits = &data.view_bits::<Msb0>()[4 .. 40];
+		let masks = bits
+			.elements()
+			.map(|(_, mask)| mask.value())
+			.collect::<Vec<_>>();
+		assert_eq!(masks, [0x0FFF, 0xFFFF, 0xFF00]);
+		let masks = bits
+			.elements()
+			.rev()
+			.map(|(_, mask)| mask.value())
+			.collect::<Vec<_>>();
+		assert_eq!(masks, [0xFF00, 0xFFFF, 0x0FFF]);
+
+		let bits = &data.view_bits::<Lsb0>()[3 .. 7];
+		let mut iter = bits.elements();
+		assert_eq!(iter.len(), 1);
+		assert_eq!(iter.next_back().map(|(_, m)| m.value()), Some(0x0078));
+		assert!(iter.next().is_none());
+		assert_eq!(data.view_bits::<Lsb0>()[.. 0].elements().count(), 0);
+
+		let mut data = [0u8; 3];
+		let bits = &mut data.view_bits_mut::<Lsb0>()[6 .. 18];
+		for (elem, mask) in bits.elements_mut() {
+			elem.fetch_or(mask.value(), core::sync::atomic::Ordering::Relaxed);
+		}
+		assert_eq!(data, [0xC0, 0xFF, 0x03]);
+	}
 }
@@ -0,0 +1,350 @@
+/*! The crate-wide error type.
+
+Most of `bitvec`’s API mirrors the standard library, and panics where the
+standard library panics. The fallible constructors and the `try_` methods
+instead report their failures as values. Each of those has a narrow error type
+describing exactly what went wrong, such as [`IndexError`] or
+[`FieldOverflow`]. All of them convert into the single [`Error`] enum in this
+module, so that code which uses several of them can propagate any failure with
+`?` through one return type.
+
+The error types implement the standard [`Error`][`StdError`] trait when either
+the `std` feature or the `core-error` feature is enabled. The latter uses the
+`core::error` module, and so makes the trait available to `#![no_std]` builds
+on compilers that provide it.
+
+[`Error`]: self::Error
+[`FieldOverflow`]: crate::field::FieldOverflow
+[`IndexError`]: crate::slice::IndexError
+[`StdError`]: core::error::Error
+!*/
+
+use crate::{
+	array::CapacityError,
+	field::FieldOverflow,
+	index::{
+		BitIdxErr,
+		BitSelErr,
+	},
+	mem::{
+		BitMemory,
+		BitRegister,
+	},
+	slice::{
+		IndexError,
+		LengthMismatch,
+	},
+};
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+/// The standard error trait, from whichever library provides it.
+#[cfg(feature = "std")]
+pub(crate) use std::error::Error as StdError;
+
+/// The standard error trait, from whichever library provides it.
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+pub(crate) use core::error::Error as StdError;
+
+/** Any failure reported by a fallible `bitvec` operation.
+
+The narrow error types produced by individual APIs all convert into this enum
+through [`From`], so it can be used as the error type of a function that calls
+several of them.
+
+# Examples
+
+```rust
+use bitvec::{
+  error::Error,
+  prelude::*,
+};
+
+fn fill(data: &mut [u8], at: usize, value: u8) -> Result<(), Error> {
+  let bits = BitSlice::<Lsb0, _>::from_slice_mut(data)?;
+  let (_, rest) = bits.try_split_at_mut(at)?;
+  rest.try_get_range_mut(.. 4)?.try_store(value)?;
+  Ok(())
+}
+
+let mut data = [0u8; 2];
+assert!(fill(&mut data, 4, 10).is_ok());
+assert_eq!(data, [0xA0, 0]);
+
+assert!(matches!(
+  fill(&mut data, 20, 1),
+  Err(Error::Index(_)),
+));
+assert_eq!(
+  fill(&mut data, 0, 16),
+  Err(Error::FieldOverflow { width: 4 }),
+);
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Error {
+	/// A region has more bits than a [`BitSlice`] can describe.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	TooLong {
+		/// The number of bits requested.
+		len: usize,
+		/// The maximum number of bits the region can hold.
+		max: usize,
+	},
+	/// A base address was the null pointer.
+	Null,
+	/// A base address was not aligned to its storage type, or not aligned
+	/// enough to be encoded in a [`BitSlice`] pointer.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	Misaligned {
+		/// The rejected address.
+		addr: usize,
+		/// The alignment, in bytes, that the address required.
+		align: usize,
+	},
+	/// A region would extend past the end of the address space.
+	AddressOverflow {
+		/// The base address of the region.
+		addr: usize,
+		/// The number of elements in the region.
+		elts: usize,
+	},
+	/// A bit index was not less than the width of its register.
+	BadBitIndex {
+		/// The rejected index.
+		index: u8,
+		/// The width, in bits, of the register.
+		width: u8,
+	},
+	/// A selector value did not have exactly one bit set.
+	BadBitSelector {
+		/// The number of bits set in the rejected selector.
+		ones: u32,
+		/// The width, in bits, of the register.
+		width: u8,
+	},
+	/// An index or range did not fit in a [`BitSlice`].
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	Index(IndexError),
+	/// A value had set bits beyond the width of the field it was stored into.
+	FieldOverflow {
+		/// The width, in bits, of the field.
+		width: usize,
+	},
+	/// An encoded buffer ended before the data its header describes.
+	Truncated {
+		/// The number of bytes the buffer must have.
+		needed: usize,
+		/// The number of bytes the buffer has.
+		available: usize,
+	},
+	/// A set bit's index was too large for the index type of a foreign bitmap.
+	IndexOverflow {
+		/// The rejected index.
+		index: usize,
+		/// The greatest index the bitmap can hold.
+		max: usize,
+	},
+	/// A fixed-capacity buffer had no room for more bits.
+	Capacity {
+		/// The length that the rejected operation required.
+		needed: usize,
+		/// The capacity of the buffer.
+		capacity: usize,
+	},
+	/// Two bit-slices that must have the same length did not.
+	LengthMismatch {
+		/// The length of the left operand.
+		left: usize,
+		/// The length of the right operand.
+		right: usize,
+	},
+	/// The allocator could not provide a memory buffer.
+	Alloc {
+		/// The size, in bytes, of the requested buffer.
+		size: usize,
+		/// The alignment, in bytes, of the requested buffer.
+		align: usize,
+	},
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for Error {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Self::TooLong { len, max } => write!(
+				fmt,
+				"{} bits exceeds the maximum region length of {} bits",
+				len, max,
+			),
+			Self::Null => fmt.write_str("null base address"),
+			Self::Misaligned { addr, align } => write!(
+				fmt,
+				"address {:#x} is not aligned to {} bytes",
+				addr, align,
+			),
+			Self::AddressOverflow { addr, elts } => write!(
+				fmt,
+				"{} elements at address {:#x} wrap the address space",
+				elts, addr,
+			),
+			Self::BadBitIndex { index, width } => write!(
+				fmt,
+				"bit index {} is out of range for a {}-bit register",
+				index, width,
+			),
+			Self::BadBitSelector { ones, width } => write!(
+				fmt,
+				"a selector for a {}-bit register has {} bits set, not one",
+				width, ones,
+			),
+			Self::Index(err) => Display::fmt(&err, fmt),
+			Self::FieldOverflow { width } => {
+				write!(fmt, "value does not fit in a {}-bit field", width)
+			},
+			Self::Truncated { needed, available } => write!(
+				fmt,
+				"buffer of {} bytes is truncated; {} bytes are required",
+				available, needed,
+			),
+			Self::IndexOverflow { index, max } => write!(
+				fmt,
+				"bit index {} exceeds the maximum bitmap index of {}",
+				index, max,
+			),
+			Self::Capacity { needed, capacity } => write!(
+				fmt,
+				"{} bits exceeds the capacity of {} bits",
+				needed, capacity,
+			),
+			Self::LengthMismatch { left, right } => write!(
+				fmt,
+				"operands have different lengths: {} and {}",
+				left, right,
+			),
+			Self::Alloc { size, align } => write!(
+				fmt,
+				"failed to allocate {} bytes aligned to {} bytes",
+				size, align,
+			),
+		}
+	}
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl StdError for Error {
+	fn source(&self) -> Option<&(dyn StdError + 'static)> {
+		match self {
+			Self::Index(err) => Some(err),
+			_ => None,
+		}
+	}
+}
+
+impl From<IndexError> for Error {
+	fn from(err: IndexError) -> Self {
+		Self::Index(err)
+	}
+}
+
+impl From<CapacityError> for Error {
+	fn from(err: CapacityError) -> Self {
+		Self::Capacity {
+			needed: err.needed(),
+			capacity: err.capacity(),
+		}
+	}
+}
+
+impl From<LengthMismatch> for Error {
+	fn from(err: LengthMismatch) -> Self {
+		Self::LengthMismatch {
+			left: err.left(),
+			right: err.right(),
+		}
+	}
+}
+
+impl<R> From<BitIdxErr<R>> for Error
+where R: BitRegister
+{
+	fn from(err: BitIdxErr<R>) -> Self {
+		Self::BadBitIndex {
+			index: err.value(),
+			width: R::BITS,
+		}
+	}
+}
+
+impl<R> From<BitSelErr<R>> for Error
+where R: BitRegister
+{
+	fn from(err: BitSelErr<R>) -> Self {
+		Self::BadBitSelector {
+			ones: err.value().count_ones(),
+			width: R::BITS,
+		}
+	}
+}
+
+impl<M> From<FieldOverflow<M>> for Error
+where M: BitMemory
+{
+	fn from(err: FieldOverflow<M>) -> Self {
+		Self::FieldOverflow { width: err.width() }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use core::convert::TryFrom;
+
+	#[test]
+	fn conversions() {
+		let bits = bits![0; 10];
+		let err: Error = bits.try_split_at(11).unwrap_err().into();
+		assert!(matches!(err, Error::Index(_)));
+
+		let err: Error = crate::index::BitIdx::<u8>::new(9).unwrap_err().into();
+		assert_eq!(err, Error::BadBitIndex { index: 9, width: 8 });
+
+		let err: Error =
+			crate::index::BitSel::<u16>::try_from(6).unwrap_err().into();
+		assert_eq!(err, Error::BadBitSelector { ones: 2, width: 16 });
+
+		let mut data = 0u8;
+		let err: Error = data.view_bits_mut::<Msb0>()[.. 3]
+			.try_store(8u8)
+			.unwrap_err()
+			.into();
+		assert_eq!(err, Error::FieldOverflow { width: 3 });
+
+		let data = [0u16; 2];
+		let err = unsafe {
+			crate::slice::bits_from_raw_parts::<Lsb0, u16>(
+				(data.as_ptr() as *const u8).add(1) as *const u16,
+				0,
+				16,
+			)
+		}
+		.unwrap_err();
+		assert!(matches!(err, Error::Misaligned { align: 2, .. }));
+
+		let err = unsafe {
+			crate::slice::bits_from_raw_parts::<Lsb0, u16>(data.as_ptr(), 4, !0)
+		}
+		.unwrap_err();
+		assert!(matches!(err, Error::TooLong { .. }));
+	}
+}
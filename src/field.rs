@@ -58,7 +58,11 @@ use crate::{
 		Domain,
 		DomainMut,
 	},
-	index::BitMask,
+	index::{
+		BitIdx,
+		BitMask,
+		BitTail,
+	},
 	mem::BitMemory,
 	order::{
 		BitOrder,
@@ -71,6 +75,13 @@ use crate::{
 };
 
 use core::{
+	any::TypeId,
+	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
 	mem,
 	ptr,
 };
@@ -83,6 +94,28 @@ use crate::{
 	vec::BitVec,
 };
 
+/* Note on compile-time checked field access:
+
+The ideal API for statically-known fields places the field location in the type
+system, so that an out-of-range field or an undersized destination type is a
+compile error rather than a panic:
+
+```rust
+fn load_field<const OFFSET: usize, const WIDTH: usize, M>(&self) -> M
+where
+  M: BitMemory,
+  [(); M::BITS as usize - WIDTH]:,
+  [(); T::Mem::BITS as usize - OFFSET - WIDTH]:,
+{ … }
+```
+
+This requires const generics, which are unavailable at the crate’s minimum
+supported compiler version, and also requires using generic parameters in
+const-evaluated bounds, which remains unstable. Until both are available, the
+`register::RegisterField` trait carries the offset and width of a field as
+associated constants, and checks them once at the point of access.
+*/
+
 /** Performs C-style bitfield access through a [`BitSlice`].
 
 This trait transfers data between a [`BitSlice`] region and a local integer. The
@@ -243,6 +276,24 @@ pub trait BitField {
 	/// This method is encouraged to panic if `self` is empty, or wider than a
 	/// single element `M`.
 	///
+	/// # Examples
+	///
+	/// The element order is fixed, and does not depend on the [`BitOrder`]
+	/// parameter or on the target’s byte order.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x34u8, 0x12];
+	/// assert_eq!(data.view_bits::<Lsb0>().load_le::<u16>(), 0x1234);
+	/// assert_eq!(data.view_bits::<Msb0>().load_le::<u16>(), 0x1234);
+	///
+	/// let data = [0xABu8, 0xCD];
+	/// //  The low-address element holds the low nibble of the result.
+	/// assert_eq!(data.view_bits::<Msb0>()[4 .. 12].load_le::<u8>(), 0xCB);
+	/// ```
+	///
+	/// [`BitOrder`]: crate::order::BitOrder
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
 	fn load_le<M>(&self) -> M
@@ -275,6 +326,24 @@ pub trait BitField {
 	/// This method is encouraged to panic if `self` is empty, or wider than a
 	/// single element `M`.
 	///
+	/// # Examples
+	///
+	/// The element order is fixed, and does not depend on the [`BitOrder`]
+	/// parameter or on the target’s byte order.
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x12u8, 0x34];
+	/// assert_eq!(data.view_bits::<Lsb0>().load_be::<u16>(), 0x1234);
+	/// assert_eq!(data.view_bits::<Msb0>().load_be::<u16>(), 0x1234);
+	///
+	/// let data = [0xABu8, 0xCD];
+	/// //  The low-address element holds the high nibble of the result.
+	/// assert_eq!(data.view_bits::<Msb0>()[4 .. 12].load_be::<u8>(), 0xBC);
+	/// ```
+	///
+	/// [`BitOrder`]: crate::order::BitOrder
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
 	fn load_be<M>(&self) -> M
@@ -309,6 +378,20 @@ pub trait BitField {
 	/// This method is encouraged to panic if `self` is empty, or wider than a
 	/// single element `M`.
 	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// data.view_bits_mut::<Lsb0>().store_le(0x1234u16);
+	/// assert_eq!(data, [0x34, 0x12]);
+	///
+	/// data = [0; 2];
+	/// data.view_bits_mut::<Msb0>()[4 .. 12].store_le(0xCBu8);
+	/// assert_eq!(data, [0x0B, 0xC0]);
+	/// ```
+	///
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
 	fn store_le<M>(&mut self, value: M)
@@ -343,6 +426,20 @@ pub trait BitField {
 	/// This method is encouraged to panic if `self` is empty, or wider than a
 	/// single element `M`.
 	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// data.view_bits_mut::<Lsb0>().store_be(0x1234u16);
+	/// assert_eq!(data, [0x12, 0x34]);
+	///
+	/// data = [0; 2];
+	/// data.view_bits_mut::<Msb0>()[4 .. 12].store_be(0xBCu8);
+	/// assert_eq!(data, [0x0B, 0xC0]);
+	/// ```
+	///
 	/// [`M::BITS`]: crate::mem::BitMemory::BITS
 	/// [`self.len()`]: crate::slice::BitSlice::len
 	fn store_be<M>(&mut self, value: M)
@@ -741,6 +838,777 @@ where
 	}
 }
 
+/// Floating-point fields.
+///
+/// These methods transfer IEEE-754 bit patterns between a region and the
+/// floating-point types, using [`f32::to_bits`] and [`f32::from_bits`] (or their
+/// `f64` equivalents) around the integer [`BitField`] transfers.
+///
+/// Like [`.load()`] and [`.store()`], these methods use the target byte order
+/// to assign numeric significance to the elements of a region. Regions that
+/// leave the machine should use `f32::from_bits(bits.load_le())`, or the other
+/// explicit-order combinations, directly.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`f32::from_bits`]: f32::from_bits
+/// [`f32::to_bits`]: f32::to_bits
+/// [`.load()`]: crate::field::BitField::load
+/// [`.store()`]: crate::field::BitField::store
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads an `f32` from a 32-bit region.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is not exactly 32 bits wide.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 6];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// bits[4 .. 36].store_f32(-1.5);
+	/// assert_eq!(bits[4 .. 36].load_f32(), -1.5);
+	/// ```
+	pub fn load_f32(&self) -> f32 {
+		check_exact::<u32>("load", self.len());
+		f32::from_bits(self.load::<u32>())
+	}
+
+	/// Loads an `f64` from a 64-bit region.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is not exactly 64 bits wide.
+	pub fn load_f64(&self) -> f64 {
+		check_exact::<u64>("load", self.len());
+		f64::from_bits(self.load::<u64>())
+	}
+
+	/// Stores an `f32` into a 32-bit region.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is not exactly 32 bits wide.
+	pub fn store_f32(&mut self, value: f32) {
+		check_exact::<u32>("store", self.len());
+		self.store::<u32>(value.to_bits());
+	}
+
+	/// Stores an `f64` into a 64-bit region.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is not exactly 64 bits wide.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u16; 5];
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	/// bits[3 .. 67].store_f64(core::f64::consts::PI);
+	/// assert_eq!(bits[3 .. 67].load_f64(), core::f64::consts::PI);
+	/// ```
+	pub fn store_f64(&mut self, value: f64) {
+		check_exact::<u64>("store", self.len());
+		self.store::<u64>(value.to_bits());
+	}
+}
+
+/// Signed 128-bit fields.
+///
+/// The [`BitField`] methods transfer `u128` like the other unsigned integers.
+/// These methods transfer `i128` values: loads sign-extend the most significant
+/// bit of the region, and stores keep the [`self.len()`] least significant bits
+/// of the two’s-complement value. As with the `BitField` methods, the plain
+/// names use the target byte order, and the `_le` and `_be` names choose one.
+///
+/// # Panics
+///
+/// All of these methods panic if `self` is empty, or wider than 128 bits.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`self.len()`]: crate::slice::BitSlice::len
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads an `i128` from the region, using the target byte order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u32; 4];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[20 .. 116];
+	/// bits.store_i128(-(1 << 90));
+	/// assert_eq!(bits.load_i128(), -(1 << 90));
+	/// assert_eq!(bits.load::<u128>(), (1 << 96) - (1 << 90));
+	/// ```
+	pub fn load_i128(&self) -> i128 {
+		sign_extend(self.load::<u128>(), self.len())
+	}
+
+	/// Loads an `i128` from the region, using little-endian element ordering.
+	pub fn load_i128_le(&self) -> i128 {
+		sign_extend(self.load_le::<u128>(), self.len())
+	}
+
+	/// Loads an `i128` from the region, using big-endian element ordering.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xFFu8, 0x80, 0, 0x7F];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits[4 .. 12].load_i128_be(), -8);
+	/// assert_eq!(bits[24 ..].load_i128_be(), 127);
+	/// assert_eq!(bits[25 ..].load_i128_be(), -1);
+	/// ```
+	pub fn load_i128_be(&self) -> i128 {
+		sign_extend(self.load_be::<u128>(), self.len())
+	}
+
+	/// Stores an `i128` into the region, using the target byte order.
+	pub fn store_i128(&mut self, value: i128) {
+		self.store::<u128>(value as u128);
+	}
+
+	/// Stores an `i128` into the region, using little-endian element ordering.
+	pub fn store_i128_le(&mut self, value: i128) {
+		self.store_le::<u128>(value as u128);
+	}
+
+	/// Stores an `i128` into the region, using big-endian element ordering.
+	pub fn store_i128_be(&mut self, value: i128) {
+		self.store_be::<u128>(value as u128);
+	}
+}
+
+/// Byte-array fields.
+///
+/// These methods transfer fields of any width, including those wider than the
+/// widest integer, between a region and a byte array that holds the field as
+/// one large integer. The `_le` methods store the least significant byte of the
+/// integer first in the array, and the `_be` methods store it last. As with the
+/// [`BitField`] methods of the same suffix, the suffix also selects the
+/// significance of the memory elements of the region: a field of at most 128
+/// bits transfers the same value as [`.load_le()`] or [`.load_be()`] into a
+/// `u128`, written into the array with [`u128::to_le_bytes`] or
+/// [`u128::to_be_bytes`].
+///
+/// The region is moved an element at a time, and the array need only be large
+/// enough to hold it. A load clears the bytes of the array above the field, and
+/// a store ignores them.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`u128::to_be_bytes`]: u128::to_be_bytes
+/// [`u128::to_le_bytes`]: u128::to_le_bytes
+/// [`.load_be()`]: crate::field::BitField::load_be
+/// [`.load_le()`]: crate::field::BitField::load_le
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads the region into a byte array, least significant byte first.
+	///
+	/// # Panics
+	///
+	/// This panics if `dest` has fewer than `self.len()` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let key = [0x5Au8; 32];
+	/// let mut data = [0u16; 18];
+	/// let bits = &mut data.view_bits_mut::<Lsb0>()[5 .. 261];
+	/// bits.store_bytes_le(&key);
+	///
+	/// let mut out = [0xFFu8; 33];
+	/// bits.load_bytes_le(&mut out);
+	/// assert_eq!(out[.. 32], key);
+	/// assert_eq!(out[32], 0);
+	/// ```
+	pub fn load_bytes_le(&self, dest: &mut [u8]) {
+		check_bytes("load", self.len(), dest.len());
+		for byte in dest.iter_mut() {
+			*byte = 0;
+		}
+		let out = dest.view_bits_mut::<Lsb0>();
+		let mut offset = 0;
+		for chunk in self.chunks_by_element() {
+			let width = chunk.len();
+			out[offset .. offset + width].store_le(chunk.load_le::<T::Mem>());
+			offset += width;
+		}
+	}
+
+	/// Loads the region into a byte array, most significant byte first.
+	///
+	/// # Panics
+	///
+	/// This panics if `dest` has fewer than `self.len()` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xABu8, 0xCD, 0xEF];
+	/// let bits = &data.view_bits::<Msb0>()[4 .. 20];
+	///
+	/// let mut out = [0u8; 3];
+	/// bits.load_bytes_be(&mut out);
+	/// assert_eq!(out, [0x00, 0xBC, 0xDE]);
+	/// ```
+	pub fn load_bytes_be(&self, dest: &mut [u8]) {
+		check_bytes("load", self.len(), dest.len());
+		for byte in dest.iter_mut() {
+			*byte = 0;
+		}
+		let out = dest.view_bits_mut::<Msb0>();
+		let mut offset = out.len() - self.len();
+		for chunk in self.chunks_by_element() {
+			let width = chunk.len();
+			out[offset .. offset + width].store_be(chunk.load_be::<T::Mem>());
+			offset += width;
+		}
+	}
+
+	/// Stores a byte array, least significant byte first, into the region.
+	///
+	/// Only the `self.len()` least significant bits of the array are stored.
+	///
+	/// # Panics
+	///
+	/// This panics if `src` has fewer than `self.len()` bits.
+	pub fn store_bytes_le(&mut self, src: &[u8]) {
+		check_bytes("store", self.len(), src.len());
+		let from = src.view_bits::<Lsb0>();
+		let mut offset = 0;
+		for chunk in self.chunks_by_element_mut() {
+			let width = chunk.len();
+			chunk.store_le(from[offset .. offset + width].load_le::<T::Mem>());
+			offset += width;
+		}
+	}
+
+	/// Stores a byte array, most significant byte first, into the region.
+	///
+	/// Only the `self.len()` least significant bits of the array are stored.
+	///
+	/// # Panics
+	///
+	/// This panics if `src` has fewer than `self.len()` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// data.view_bits_mut::<Msb0>()[4 .. 20].store_bytes_be(&[0xFF, 0x12, 0x34]);
+	/// assert_eq!(data, [0x01, 0x23, 0x40]);
+	/// ```
+	pub fn store_bytes_be(&mut self, src: &[u8]) {
+		check_bytes("store", self.len(), src.len());
+		let from = src.view_bits::<Msb0>();
+		let mut offset = from.len() - self.len();
+		for chunk in self.chunks_by_element_mut() {
+			let width = chunk.len();
+			chunk.store_be(from[offset .. offset + width].load_be::<T::Mem>());
+			offset += width;
+		}
+	}
+}
+
+/// Sequences of fixed-width fields.
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Iterates over the slice as a sequence of `width`-bit integer fields.
+	///
+	/// Each field is loaded into an `M` with [`.load()`]. Bits at the end of
+	/// the slice that do not fill a whole field are not yielded, and are
+	/// available from [`Fields::remainder`].
+	///
+	/// # Type Parameters
+	///
+	/// - `M`: The integer type into which each field is loaded.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `width`: The width of each field.
+	///
+	/// # Returns
+	///
+	/// An iterator over the values of each field in the slice.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than [`M::BITS`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 5];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// let samples = [1u16, 2, 1023, 512];
+	/// bits.store_fields(10, samples.iter().copied());
+	///
+	/// assert!(bits.iter_fields::<u16>(10).eq(samples.iter().copied()));
+	/// ```
+	///
+	/// [`Fields::remainder`]: crate::field::Fields::remainder
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`.load()`]: crate::field::BitField::load
+	pub fn iter_fields<M>(&self, width: usize) -> Fields<O, T, M>
+	where M: BitMemory {
+		check::<M>("load", width);
+		Fields::new(self.chunks_exact(width))
+	}
+
+	/// Stores a sequence of values into successive `width`-bit fields.
+	///
+	/// This is the inverse of [`.iter_fields()`]. Each value is written with
+	/// [`.store()`], which keeps only its `width` least significant bits.
+	/// Storing stops when either the values or the whole fields of the slice
+	/// are exhausted.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The width of each field.
+	/// - `values`: The values to store into each field, in order.
+	///
+	/// # Returns
+	///
+	/// The number of fields written.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or greater than [`M::BITS`].
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`.iter_fields()`]: Self::iter_fields
+	/// [`.store()`]: crate::field::BitField::store
+	pub fn store_fields<M, I>(&mut self, width: usize, values: I) -> usize
+	where
+		M: BitMemory,
+		I: IntoIterator<Item = M>,
+		BitSlice<O, T::Alias>: BitField,
+	{
+		check::<M>("store", width);
+		self.chunks_exact_mut(width)
+			.zip(values)
+			.map(|(field, value)| field.store(value))
+			.count()
+	}
+}
+
+/// Checked field stores.
+///
+/// The [`BitField`] store methods keep only the [`self.len()`] least
+/// significant bits of a value, and silently discard the rest. These methods
+/// instead refuse to store a value whose set bits do not all fit in the region,
+/// and leave the region unmodified.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`self.len()`]: crate::slice::BitSlice::len
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Stores a value into `self` if it fits, using the target byte order.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: A value to store into `self`.
+	///
+	/// # Returns
+	///
+	/// `Ok` if `value` was stored. `Err` if `value` has any set bits at or
+	/// above [`self.len()`]; in this case, `self` is unchanged.
+	///
+	/// # Panics
+	///
+	/// This panics if `self` is empty, or wider than [`M::BITS`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = data.view_bits_mut::<Lsb0>();
+	///
+	/// assert!(bits[.. 4].try_store(15u8).is_ok());
+	/// let err = bits[4 .. 8].try_store(16u8).unwrap_err();
+	/// assert_eq!(err.value(), 16);
+	/// assert_eq!(err.width(), 4);
+	/// assert_eq!(data, 15);
+	/// ```
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	/// [`self.len()`]: Self::len
+	pub fn try_store<M>(&mut self, value: M) -> Result<(), FieldOverflow<M>>
+	where M: BitMemory {
+		check::<M>("store", self.len());
+		FieldOverflow::check(value, self.len())?;
+		self.store(value);
+		Ok(())
+	}
+
+	/// Stores a value into `self` if it fits, using little-endian element
+	/// ordering.
+	///
+	/// This behaves as [`.try_store()`], except that it uses [`.store_le()`]
+	/// to write the value.
+	///
+	/// [`.store_le()`]: crate::field::BitField::store_le
+	/// [`.try_store()`]: Self::try_store
+	pub fn try_store_le<M>(&mut self, value: M) -> Result<(), FieldOverflow<M>>
+	where M: BitMemory {
+		check::<M>("store", self.len());
+		FieldOverflow::check(value, self.len())?;
+		self.store_le(value);
+		Ok(())
+	}
+
+	/// Stores a value into `self` if it fits, using big-endian element
+	/// ordering.
+	///
+	/// This behaves as [`.try_store()`], except that it uses [`.store_be()`]
+	/// to write the value.
+	///
+	/// [`.store_be()`]: crate::field::BitField::store_be
+	/// [`.try_store()`]: Self::try_store
+	pub fn try_store_be<M>(&mut self, value: M) -> Result<(), FieldOverflow<M>>
+	where M: BitMemory {
+		check::<M>("store", self.len());
+		FieldOverflow::check(value, self.len())?;
+		self.store_be(value);
+		Ok(())
+	}
+}
+
+/// Field access at an offset.
+///
+/// These methods transfer a field that begins `offset` bits into `self` and is
+/// `width` bits wide. They are equivalent to indexing `self` with
+/// `offset .. offset + width` and then calling the matching [`BitField`]
+/// method, but for the [`Lsb0`] and [`Msb0`] orderings they compute the
+/// touched memory elements directly rather than constructing an intermediate
+/// subslice. Decoders that read many fields from one buffer should prefer
+/// them.
+///
+/// [`BitField`]: crate::field::BitField
+/// [`Lsb0`]: crate::order::Lsb0
+/// [`Msb0`]: crate::order::Msb0
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	BitSlice<O, T>: BitField,
+{
+	/// Loads a field at an offset, using the target byte order.
+	///
+	/// This is `self[offset .. offset + width].load()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than [`M::BITS`], or if the
+	/// field does not lie within `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 4];
+	/// let bits = data.view_bits_mut::<Msb0>();
+	/// bits.store_at(5, 12, 0xABCu16);
+	/// assert_eq!(bits.load_at::<u16>(5, 12), 0xABC);
+	/// assert_eq!(bits[5 .. 17].load::<u16>(), 0xABC);
+	/// ```
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	pub fn load_at<M>(&self, offset: usize, width: usize) -> M
+	where M: BitMemory {
+		#[cfg(target_endian = "little")]
+		return self.load_at_le::<M>(offset, width);
+
+		#[cfg(target_endian = "big")]
+		return self.load_at_be::<M>(offset, width);
+	}
+
+	/// Loads a field at an offset, using little-endian element ordering.
+	///
+	/// This is `self[offset .. offset + width].load_le()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than [`M::BITS`], or if the
+	/// field does not lie within `self`.
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	pub fn load_at_le<M>(&self, offset: usize, width: usize) -> M
+	where M: BitMemory {
+		self.check_at::<M>("load", offset, width);
+		if !is_contiguous::<O>() {
+			return unsafe { self.get_unchecked(offset .. offset + width) }
+				.load_le();
+		}
+
+		let mut accum = M::ZERO;
+		let mut done = 0;
+		self.for_each_span(offset, width, |elem, mask, shamt, len| {
+			let chunk = get::<T, M>(unsafe { &*elem }, mask, shamt);
+			accum |= chunk << done;
+			done += len;
+		});
+		accum
+	}
+
+	/// Loads a field at an offset, using big-endian element ordering.
+	///
+	/// This is `self[offset .. offset + width].load_be()`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than [`M::BITS`], or if the
+	/// field does not lie within `self`.
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	pub fn load_at_be<M>(&self, offset: usize, width: usize) -> M
+	where M: BitMemory {
+		self.check_at::<M>("load", offset, width);
+		if !is_contiguous::<O>() {
+			return unsafe { self.get_unchecked(offset .. offset + width) }
+				.load_be();
+		}
+
+		let mut accum = M::ZERO;
+		let mut left = width;
+		self.for_each_span(offset, width, |elem, mask, shamt, len| {
+			left -= len;
+			let chunk = get::<T, M>(unsafe { &*elem }, mask, shamt);
+			accum |= chunk << left;
+		});
+		accum
+	}
+
+	/// Stores a field at an offset, using the target byte order.
+	///
+	/// This is `self[offset .. offset + width].store(value)`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than [`M::BITS`], or if the
+	/// field does not lie within `self`.
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	pub fn store_at<M>(&mut self, offset: usize, width: usize, value: M)
+	where M: BitMemory {
+		#[cfg(target_endian = "little")]
+		self.store_at_le::<M>(offset, width, value);
+
+		#[cfg(target_endian = "big")]
+		self.store_at_be::<M>(offset, width, value);
+	}
+
+	/// Stores a field at an offset, using little-endian element ordering.
+	///
+	/// This is `self[offset .. offset + width].store_le(value)`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than [`M::BITS`], or if the
+	/// field does not lie within `self`.
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	pub fn store_at_le<M>(&mut self, offset: usize, width: usize, value: M)
+	where M: BitMemory {
+		self.check_at::<M>("store", offset, width);
+		if !is_contiguous::<O>() {
+			unsafe { self.get_unchecked_mut(offset .. offset + width) }
+				.store_le(value);
+			return;
+		}
+
+		let mut done = 0;
+		self.for_each_span(offset, width, |elem, mask, shamt, len| {
+			let access = unsafe { &*(elem as *const T::Access) };
+			set::<T, M>(access, value >> done, mask, shamt);
+			done += len;
+		});
+	}
+
+	/// Stores a field at an offset, using big-endian element ordering.
+	///
+	/// This is `self[offset .. offset + width].store_be(value)`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is zero or wider than [`M::BITS`], or if the
+	/// field does not lie within `self`.
+	///
+	/// [`M::BITS`]: crate::mem::BitMemory::BITS
+	pub fn store_at_be<M>(&mut self, offset: usize, width: usize, value: M)
+	where M: BitMemory {
+		self.check_at::<M>("store", offset, width);
+		if !is_contiguous::<O>() {
+			unsafe { self.get_unchecked_mut(offset .. offset + width) }
+				.store_be(value);
+			return;
+		}
+
+		let mut left = width;
+		self.for_each_span(offset, width, |elem, mask, shamt, len| {
+			left -= len;
+			let access = unsafe { &*(elem as *const T::Access) };
+			set::<T, M>(access, value >> left, mask, shamt);
+		});
+	}
+
+	/// Asserts that a field lies within `self` and fits in an `M`.
+	fn check_at<M>(&self, action: &'static str, offset: usize, width: usize)
+	where M: BitMemory {
+		check::<M>(action, width);
+		let len = self.len();
+		let end = offset.saturating_add(width);
+		assert!(
+			end <= len,
+			"Field {} .. {} out of bounds for length {}",
+			offset,
+			end,
+			len,
+		);
+	}
+
+	/// Walks the memory elements touched by a field.
+	///
+	/// This calls `func` once per element, in ascending address order, with
+	/// the element address, the mask of its live bits, the distance of those
+	/// bits from the least significant edge of the element, and their count.
+	///
+	/// This must only be called for [`Lsb0`] or [`Msb0`] ordering, after the
+	/// field has been checked to lie within `self`.
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
+	fn for_each_span<F>(&self, offset: usize, width: usize, mut func: F)
+	where F: FnMut(*const T, BitMask<T::Mem>, u8, usize) {
+		let (addr, head, _) = self.bitptr().raw_parts();
+		let start = head.value() as usize + offset;
+		let elt_bits = T::Mem::BITS as usize;
+		let lsb0 = TypeId::of::<O>() == TypeId::of::<Lsb0>();
+
+		let mut elem = unsafe { addr.to_const().add(start >> T::Mem::INDX) };
+		let mut lo = start & T::Mem::MASK as usize;
+		let mut left = width;
+		while left > 0 {
+			let len = cmp::min(elt_bits - lo, left);
+			let hi = lo + len;
+			let mask = unsafe {
+				O::mask(
+					BitIdx::<T::Mem>::new_unchecked(lo as u8),
+					BitTail::<T::Mem>::new_unchecked(hi as u8),
+				)
+			};
+			let shamt = if lsb0 { lo } else { elt_bits - hi };
+			func(elem, mask, shamt as u8, len);
+
+			left -= len;
+			lo = 0;
+			elem = unsafe { elem.add(1) };
+		}
+	}
+}
+
+/** The error type returned when a value is too wide to store in a region.
+
+This is produced by [`BitSlice::try_store`] and its explicit-order variants.
+
+[`BitSlice::try_store`]: crate::slice::BitSlice::try_store
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldOverflow<M>
+where M: BitMemory
+{
+	/// The value that could not be stored.
+	value: M,
+	/// The width of the region that rejected it.
+	width: usize,
+}
+
+impl<M> FieldOverflow<M>
+where M: BitMemory
+{
+	/// Tests whether `value` fits in a region `width` bits wide.
+	#[allow(clippy::op_ref)]
+	fn check(value: M, width: usize) -> Result<(), Self> {
+		if width < M::BITS as usize && value >> &width != M::ZERO {
+			return Err(Self { value, width });
+		}
+		Ok(())
+	}
+
+	/// Gets the value that could not be stored.
+	pub fn value(self) -> M {
+		self.value
+	}
+
+	/// Gets the width, in bits, of the region that rejected the value.
+	pub fn width(self) -> usize {
+		self.width
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<M> Display for FieldOverflow<M>
+where M: BitMemory
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"value {} does not fit in a {}-bit field",
+			self.value, self.width,
+		)
+	}
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<M> crate::error::StdError for FieldOverflow<M> where M: BitMemory
+{
+}
+
 /// Asserts that a slice length is within a memory element width.
 ///
 /// # Panics
@@ -760,6 +1628,53 @@ where M: BitMemory {
 	}
 }
 
+/// Sign-extends the `width` least significant bits of a loaded value.
+fn sign_extend(value: u128, width: usize) -> i128 {
+	let shamt = 128 - width as u32;
+	(value << shamt) as i128 >> shamt
+}
+
+/// Asserts that a byte array is wide enough to hold a region.
+///
+/// # Panics
+///
+/// This panics if `bytes` bytes have fewer than `len` bits.
+fn check_bytes(action: &'static str, len: usize, bytes: usize) {
+	if bytes.saturating_mul(8) < len {
+		panic!(
+			"Cannot {} a {}-bit region with a {}-byte array",
+			action, len, bytes,
+		);
+	}
+}
+
+/// Tests whether an ordering is one whose live bits within an element are
+/// always contiguous, and can be moved with a single shift.
+fn is_contiguous<O>() -> bool
+where O: BitOrder {
+	let id = TypeId::of::<O>();
+	id == TypeId::of::<Lsb0>() || id == TypeId::of::<Msb0>()
+}
+
+/// Asserts that a slice length is exactly a memory element width.
+///
+/// # Panics
+///
+/// This panics if len is not [`M::BITS`].
+///
+/// [`M::BITS`]: crate::mem::BitMemory::BITS
+fn check_exact<M>(action: &'static str, len: usize)
+where M: BitMemory {
+	if len != M::BITS as usize {
+		panic!(
+			"Cannot {} a {}-bit value with a {}-bit region",
+			action,
+			M::BITS,
+			len,
+		);
+	}
+}
+
 /** Reads a value out of a section of a memory element.
 
 This function is used to extract a portion of an `M` value from a portion of a
@@ -958,9 +1873,13 @@ compile_fail!(concat!(
 	env!(CARGO_PKG_REPOSITORY)
 ));
 
+mod iter;
+
 #[cfg(feature = "std")]
 mod io;
 
+pub use self::iter::Fields;
+
 #[cfg(test)]
 mod tests;
 
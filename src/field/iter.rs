@@ -0,0 +1,135 @@
+//! Iteration over sequences of fixed-width fields.
+
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::{
+		BitSlice,
+		ChunksExact,
+	},
+	store::BitStore,
+};
+
+use core::{
+	iter::FusedIterator,
+	marker::PhantomData,
+};
+
+/** An iterator over a [`BitSlice`] as a sequence of fixed-width integer
+fields.
+
+Each field is loaded into an `M` with [`.load()`]. When the slice length is not
+evenly divided by the field width, the last up to `width - 1` bits are not
+yielded, and can be retrieved from the [`.remainder()`] function of the
+iterator.
+
+This struct is created by the [`.iter_fields()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_fields()`]: crate::slice::BitSlice::iter_fields
+[`.load()`]: crate::field::BitField::load
+[`.remainder()`]: Self::remainder
+**/
+#[derive(Clone, Debug)]
+pub struct Fields<'a, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	/// The field-width chunks of the source slice.
+	inner: ChunksExact<'a, O, T>,
+	/// The type into which each field is loaded.
+	_mem: PhantomData<M>,
+}
+
+impl<'a, O, T, M> Fields<'a, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+{
+	pub(crate) fn new(inner: ChunksExact<'a, O, T>) -> Self {
+		Self {
+			inner,
+			_mem: PhantomData,
+		}
+	}
+
+	/// Returns the remainder of the original [`BitSlice`] that is not going to
+	/// be returned by the iterator. The returned `BitSlice` has at most
+	/// `width - 1` bits.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn remainder(&self) -> &'a BitSlice<O, T> {
+		self.inner.remainder()
+	}
+}
+
+impl<O, T, M> Iterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+	BitSlice<O, T>: BitField,
+{
+	type Item = M;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(BitField::load::<M>)
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth(n).map(BitField::load::<M>)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	fn count(self) -> usize {
+		self.inner.count()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T, M> DoubleEndedIterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+	BitSlice<O, T>: BitField,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(BitField::load::<M>)
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth_back(n).map(BitField::load::<M>)
+	}
+}
+
+impl<O, T, M> ExactSizeIterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+	BitSlice<O, T>: BitField,
+{
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+}
+
+impl<O, T, M> FusedIterator for Fields<'_, O, T, M>
+where
+	O: BitOrder,
+	T: BitStore,
+	M: BitMemory,
+	BitSlice<O, T>: BitField,
+{
+}
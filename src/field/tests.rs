@@ -98,7 +98,7 @@ fn wide_load() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Cannot fail 8 bits from a 10-bit region")]
 #[cfg(not(target_arch = "riscv64"))]
 fn check_panic() {
 	check::<u8>("fail", 10);
@@ -130,3 +130,176 @@ fn wrappers() {
 	assert_eq!(d.load_le::<u8>(), !0);
 	assert_eq!(d.load_be::<u8>(), !0);
 }
+
+#[test]
+fn floats() {
+	let mut data = [0u32; 3];
+	let bits = data.view_bits_mut::<Lsb0>();
+
+	bits[.. 32].store_f32(1.0);
+	assert_eq!(bits[.. 32].load::<u32>(), 1.0f32.to_bits());
+	bits[20 .. 84].store_f64(-0.25);
+	assert_eq!(bits[20 .. 84].load_f64(), -0.25);
+	assert_eq!(bits[.. 20].load::<u32>(), 1.0f32.to_bits() & 0xFFFFF);
+}
+
+#[test]
+#[should_panic(expected = "Cannot load a 32-bit value with a 31-bit region")]
+fn float_width() {
+	0u32.view_bits::<Msb0>()[1 ..].load_f32();
+}
+
+#[test]
+fn wide_signed() {
+	let mut data = [0u16; 10];
+	let bits = data.view_bits_mut::<Msb0>();
+
+	bits[5 .. 101].store_i128_be(-2);
+	assert_eq!(bits[5 .. 101].load_i128_be(), -2);
+	assert_eq!(bits[5 .. 101].load_be::<u128>(), (1 << 96) - 2);
+	assert!(bits[.. 5].not_any());
+	assert!(bits[101 ..].not_any());
+
+	bits[16 .. 144].store_i128_le(i128::MIN);
+	assert_eq!(bits[16 .. 144].load_i128_le(), i128::MIN);
+	bits[16 .. 144].store_i128(i128::MAX);
+	assert_eq!(bits[16 .. 144].load_i128(), i128::MAX);
+
+	bits[150 .. 151].store_i128(-1);
+	assert_eq!(bits[150 .. 151].load_i128(), -1);
+	bits[150 .. 152].store_i128(1);
+	assert_eq!(bits[150 .. 152].load_i128(), 1);
+}
+
+#[test]
+#[should_panic]
+fn wide_signed_width() {
+	[0u64; 3].view_bits::<Lsb0>()[.. 129].load_i128();
+}
+
+#[test]
+fn byte_arrays() {
+	let src = [0x0123_4567_89AB_CDEFu64, 0xFEDC_BA98_7654_3210, 0x5A5A_A5A5];
+	let (lb, mb) = (src.view_bits::<Lsb0>(), src.view_bits::<Msb0>());
+	let mut bytes = [0u8; 16];
+
+	for (offset, width) in [(0, 128), (3, 100), (60, 9), (17, 64)].iter() {
+		let (lf, mf) = (&lb[*offset ..][.. *width], &mb[*offset ..][.. *width]);
+		lf.load_bytes_le(&mut bytes);
+		assert_eq!(bytes, lf.load_le::<u128>().to_le_bytes());
+		lf.load_bytes_be(&mut bytes);
+		assert_eq!(bytes, lf.load_be::<u128>().to_be_bytes());
+		mf.load_bytes_le(&mut bytes);
+		assert_eq!(bytes, mf.load_le::<u128>().to_le_bytes());
+		mf.load_bytes_be(&mut bytes);
+		assert_eq!(bytes, mf.load_be::<u128>().to_be_bytes());
+	}
+
+	let mut key = [0u8; 40];
+	for (idx, byte) in key.iter_mut().enumerate() {
+		*byte = (idx as u8).wrapping_mul(37) ^ 0x5C;
+	}
+	let mut data = [0u32; 11];
+	round_trip(&mut data.view_bits_mut::<Lsb0>()[7 .. 327], &key);
+	round_trip(&mut data.view_bits_mut::<Msb0>()[13 .. 333], &key);
+
+	fn round_trip<O>(bits: &mut BitSlice<O, u32>, key: &[u8; 40])
+	where
+		O: BitOrder,
+		BitSlice<O, u32>: BitField,
+	{
+		let mut out = [0u8; 40];
+		bits.store_bytes_le(key);
+		bits.load_bytes_le(&mut out);
+		assert_eq!(out[..], key[..]);
+		bits.store_bytes_be(key);
+		bits.load_bytes_be(&mut out);
+		assert_eq!(out[..], key[..]);
+	}
+}
+
+#[test]
+#[should_panic(expected = "Cannot store a 17-bit region with a 2-byte array")]
+fn byte_array_width() {
+	0u32.view_bits_mut::<Lsb0>()[.. 17].store_bytes_be(&[0, 0]);
+}
+
+#[test]
+fn fields() {
+	let mut data = [0u16; 3];
+	let bits = data.view_bits_mut::<Lsb0>();
+	assert_eq!(bits.store_fields(12, (1u16 ..).map(|n| n * 0x111)), 4);
+
+	let mut fields = bits.iter_fields::<u16>(12);
+	assert_eq!(fields.len(), 4);
+	assert_eq!(fields.next_back(), Some(0x444));
+	assert_eq!(fields.next(), Some(0x111));
+	assert!(fields.eq([0x222, 0x333].iter().copied()));
+
+	let fields = bits[.. 40].iter_fields::<u8>(7);
+	assert_eq!(fields.remainder(), bits[35 .. 40]);
+	assert_eq!(fields.count(), 5);
+}
+
+#[test]
+fn try_store() {
+	let mut data = [0u8; 2];
+	let bits = data.view_bits_mut::<Msb0>();
+
+	assert!(bits[4 .. 12].try_store_be(0xFFu8).is_ok());
+	assert_eq!(bits[.. 4].try_store_le(0x10u16), Err(FieldOverflow {
+		value: 0x10,
+		width: 4,
+	}));
+	assert!(bits.try_store(!0u16).is_ok());
+	assert_eq!(data, [0xFF; 2]);
+}
+
+#[test]
+fn offset_fields() {
+	let src = [0xA5u8, 0x3C, 0x96, 0xF0, 0x0F, 0x5A];
+	let mut lsb = [0u16; 4];
+	let mut msb = [0u8; 6];
+
+	for offset in 0 .. 16 {
+		for width in 1 ..= 32 {
+			let (lb, mb) = (src.view_bits::<Lsb0>(), src.view_bits::<Msb0>());
+			assert_eq!(
+				lb.load_at_le::<u32>(offset, width),
+				lb[offset ..][.. width].load_le::<u32>(),
+			);
+			assert_eq!(
+				lb.load_at_be::<u32>(offset, width),
+				lb[offset ..][.. width].load_be::<u32>(),
+			);
+			assert_eq!(
+				mb.load_at_le::<u32>(offset, width),
+				mb[offset ..][.. width].load_le::<u32>(),
+			);
+			assert_eq!(
+				mb.load_at_be::<u32>(offset, width),
+				mb[offset ..][.. width].load_be::<u32>(),
+			);
+
+			let value = 0xDEAD_BEEFu32.rotate_left(offset as u32);
+			let mask = !0u32 >> (32 - width);
+			let bits = lsb.view_bits_mut::<Lsb0>();
+			bits.store_at_le(offset + 3, width, value);
+			let loaded = bits[offset + 3 ..][.. width].load_le::<u32>();
+			assert_eq!(loaded, value & mask);
+			bits.store_at_be(offset + 3, width, !value);
+			let loaded = bits.load_at_be::<u32>(offset + 3, width);
+			assert_eq!(loaded, !value & mask);
+
+			let bits = msb.view_bits_mut::<Msb0>();
+			bits.store_at(offset, width, value);
+			assert_eq!(bits.load_at::<u32>(offset, width), value & mask);
+		}
+	}
+}
+
+#[test]
+#[should_panic(expected = "Field 10 .. 20 out of bounds for length 16")]
+fn offset_fields_bounds() {
+	0u16.view_bits::<Lsb0>().load_at::<u16>(10, 10);
+}
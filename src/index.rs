@@ -41,11 +41,18 @@ operations, and is used to create selection masks [`BitSel`] and [`BitMask`].
 
 use crate::{
 	mem::BitRegister,
-	order::BitOrder,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
 };
 
 use core::{
-	any,
+	any::{
+		self,
+		TypeId,
+	},
 	convert::TryFrom,
 	fmt::{
 		self,
@@ -62,6 +69,7 @@ use core::{
 	ops::{
 		BitAnd,
 		BitOr,
+		BitXor,
 		Not,
 	},
 };
@@ -89,13 +97,16 @@ either a crash or incorrect memory access.
 
 # Construction
 
-This type can never be constructed outside of the [`bitvec`] crate. It is passed
-in to [`BitOrder`] implementations, which may use it to construct electrical
-position, selection, or mask values from it. All values of this type constructed
-by [`bitvec`] are known to be correct in their region; no other construction
-site can be trusted.
+This type is passed in to [`BitOrder`] implementations, which may use it to
+construct electrical position, selection, or mask values from it. It is also
+publicly constructible through [`BitIdx::new`], which checks its argument, and
+[`BitIdx::new_unchecked`], which does not. All values of this type constructed
+by [`bitvec`] are known to be correct in their region; values constructed by
+other crates are only as correct as their construction sites.
 
 [`BitOrder`]: crate::order::BitOrder
+[`BitIdx::new`]: Self::new
+[`BitIdx::new_unchecked`]: Self::new_unchecked
 [`BitOrder::at`]: crate::order::BitOrder::at
 [`R::BITS`]: crate::mem::BitMemory::BITS
 [`bitvec`]: crate
@@ -138,9 +149,24 @@ where R: BitRegister
 	/// This returns `value`, marked as either a valid or invalid index by
 	/// whether or not it is within the valid range `0 .. R::BITS`.
 	///
+	/// # Errors
+	///
+	/// If `value` is not less than `R::BITS`, this returns it wrapped in a
+	/// [`BitIdxErr`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::BitIdx;
+	///
+	/// assert_eq!(BitIdx::<u8>::new(7).unwrap().value(), 7);
+	/// assert_eq!(BitIdx::<u8>::new(8).unwrap_err().value(), 8);
+	/// ```
+	///
+	/// [`BitIdxErr`]: crate::index::BitIdxErr
 	/// [`Self::LAST`]: Self::LAST
 	/// [`Self::ZERO`]: Self::ZERO
-	pub(crate) fn new(value: u8) -> Result<Self, BitIdxErr<R>> {
+	pub fn new(value: u8) -> Result<Self, BitIdxErr<R>> {
 		if value >= R::BITS {
 			return Err(BitIdxErr::new(value));
 		}
@@ -166,7 +192,7 @@ where R: BitRegister
 	///
 	/// [`Self::LAST`]: Self::LAST
 	/// [`Self::ZERO`]: Self::ZERO
-	pub(crate) unsafe fn new_unchecked(value: u8) -> Self {
+	pub unsafe fn new_unchecked(value: u8) -> Self {
 		debug_assert!(
 			value < R::BITS,
 			"Bit index {} cannot exceed type width {}",
@@ -195,7 +221,17 @@ where R: BitRegister
 	///
 	/// - `.0`: The next index after `self`.
 	/// - `.1`: Indicates that the new index is in the next register.
-	pub(crate) fn next(self) -> (Self, bool) {
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::BitIdx;
+	///
+	/// let (next, wrapped) = BitIdx::<u8>::LAST.next();
+	/// assert_eq!(next, BitIdx::ZERO);
+	/// assert!(wrapped);
+	/// ```
+	pub fn next(self) -> (Self, bool) {
 		let next = self.idx + 1;
 		(
 			unsafe { Self::new_unchecked(next & R::MASK) },
@@ -213,7 +249,7 @@ where R: BitRegister
 	///
 	/// - `.0`: The previous index before `self`.
 	/// - `.1`: Indicates that the new index is in the previous register.
-	pub(crate) fn prev(self) -> (Self, bool) {
+	pub fn prev(self) -> (Self, bool) {
 		let prev = self.idx.wrapping_sub(1);
 		(
 			unsafe { Self::new_unchecked(prev & R::MASK) },
@@ -253,6 +289,68 @@ where R: BitRegister
 		self.select::<O>().mask()
 	}
 
+	/// Counts the bits from `self` up to a tail in the same register.
+	///
+	/// # Parameters
+	///
+	/// - `self`: The inclusive starting index.
+	/// - `upto`: The exclusive ending tail.
+	///
+	/// # Returns
+	///
+	/// The number of bits in the range `self .. upto`.
+	///
+	/// # Requirements
+	///
+	/// `self` must be no greater than `upto`. Debug builds panic when this is
+	/// violated.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::{BitIdx, BitTail};
+	///
+	/// let idx = BitIdx::<u8>::new(2).unwrap();
+	/// assert_eq!(idx.distance_to(BitTail::new(6).unwrap()), 4);
+	/// assert_eq!(idx.distance_to(BitTail::LAST), 6);
+	/// ```
+	pub fn distance_to(self, upto: BitTail<R>) -> usize {
+		BitTail::from(self).distance_to(upto)
+	}
+
+	/// Computes the selection mask spanning `self` up to a tail in the same
+	/// register, under an ordering.
+	///
+	/// This forwards to [`O::mask::<R>`].
+	///
+	/// # Parameters
+	///
+	/// - `self`: The inclusive starting index.
+	/// - `upto`: The exclusive ending tail.
+	///
+	/// # Returns
+	///
+	/// A mask with every bit in `self .. upto` set high and all others set low.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::index::{BitIdx, BitTail};
+	///
+	/// let idx = BitIdx::<u8>::new(2).unwrap();
+	/// let tail = BitTail::new(5).unwrap();
+	/// assert_eq!(idx.span_to::<Lsb0>(tail).value(), 0b0001_1100);
+	/// assert_eq!(idx.span_to::<Msb0>(tail).value(), 0b0011_1000);
+	/// ```
+	///
+	/// [`O::mask::<R>`]: crate::order::BitOrder::mask
+	pub fn span_to<O>(self, upto: BitTail<R>) -> BitMask<R>
+	where O: BitOrder {
+		debug_assert!(self.idx <= upto.end, "Ranges must run from low to high");
+		O::mask(self, upto)
+	}
+
 	/// Iterates over all indices between an inclusive start and exclusive end
 	/// point.
 	///
@@ -395,7 +493,7 @@ where R: BitRegister
 	///
 	/// [`BitTail::span`]: crate::index::BitTail::span
 	pub fn span(self, len: usize) -> (usize, BitTail<R>) {
-		unsafe { BitTail::<R>::new_unchecked(self.value()) }.span(len)
+		BitTail::from(self).span(len)
 	}
 }
 
@@ -507,8 +605,8 @@ where R: BitRegister
 	}
 }
 
-#[cfg(feature = "std")]
-impl<R> std::error::Error for BitIdxErr<R> where R: BitRegister
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<R> crate::error::StdError for BitIdxErr<R> where R: BitRegister
 {
 }
 
@@ -541,9 +639,15 @@ either a crash or incorrect memory access.
 
 # Construction
 
-This type can only be publicly constructed through [`BitIdx::span`].
+This type is publicly constructible through [`BitTail::new`], which checks its
+argument, and [`BitTail::new_unchecked`], which does not. It is also produced by
+[`BitIdx::span`] and [`BitTail::span`].
 
 [`BitIdx`]: crate::index::BitIdx
+[`BitIdx::span`]: crate::index::BitIdx::span
+[`BitTail::new`]: Self::new
+[`BitTail::new_unchecked`]: Self::new_unchecked
+[`BitTail::span`]: Self::span
 [`R::BITS`]: crate::mem::BitMemory::BITS
 **/
 #[repr(transparent)]
@@ -562,12 +666,12 @@ impl<R> BitTail<R>
 where R: BitRegister
 {
 	/// The inclusive maximum tail within an element `R`.
-	pub(crate) const LAST: Self = Self {
+	pub const LAST: Self = Self {
 		end: R::BITS,
 		_ty: PhantomData,
 	};
 	/// The inclusive minimum tail within an element `R`.
-	pub(crate) const ZERO: Self = Self {
+	pub const ZERO: Self = Self {
 		end: 0,
 		_ty: PhantomData,
 	};
@@ -612,7 +716,7 @@ where R: BitRegister
 	///
 	/// [`Self::LAST`]: Self::LAST
 	/// [`Self::ZERO`]: Self::ZERO
-	pub(crate) unsafe fn new_unchecked(value: u8) -> Self {
+	pub unsafe fn new_unchecked(value: u8) -> Self {
 		debug_assert!(
 			value <= R::BITS,
 			"Bit tail {} cannot exceed type width {}",
@@ -631,6 +735,50 @@ where R: BitRegister
 		self.end
 	}
 
+	/// Converts the tail into an index, if it is not the end of the register.
+	///
+	/// # Returns
+	///
+	/// `Some` index of the same value when `self` is less than
+	/// [`Self::LAST`], and `None` when `self` is [`Self::LAST`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::{BitIdx, BitTail};
+	///
+	/// assert_eq!(BitTail::<u8>::ZERO.to_idx(), Some(BitIdx::ZERO));
+	/// assert!(BitTail::<u8>::LAST.to_idx().is_none());
+	/// ```
+	///
+	/// [`Self::LAST`]: Self::LAST
+	pub fn to_idx(self) -> Option<BitIdx<R>> {
+		if self.end == R::BITS {
+			return None;
+		}
+		Some(unsafe { BitIdx::new_unchecked(self.end) })
+	}
+
+	/// Counts the bits from `self` up to a later tail in the same register.
+	///
+	/// # Parameters
+	///
+	/// - `self`: The starting tail.
+	/// - `upto`: The ending tail.
+	///
+	/// # Returns
+	///
+	/// The number of bits between the two tails.
+	///
+	/// # Requirements
+	///
+	/// `self` must be no greater than `upto`. Debug builds panic when this is
+	/// violated.
+	pub fn distance_to(self, upto: Self) -> usize {
+		debug_assert!(self.end <= upto.end, "Ranges must run from low to high");
+		(upto.end - self.end) as usize
+	}
+
 	/// Iterates over all tail indices at and after an inclusive starting point.
 	///
 	/// Because implementation details of the range type family, including the
@@ -685,9 +833,20 @@ where R: BitRegister
 	/// If `self` is [`BitTail::LAST`], then the new region starts at
 	/// [`BitIdx::ZERO`] in the next element.
 	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::BitTail;
+	///
+	/// let tail = BitTail::<u8>::new(6).unwrap();
+	/// let (elts, tail) = tail.span(4);
+	/// assert_eq!(elts, 2);
+	/// assert_eq!(tail.value(), 2);
+	/// ```
+	///
 	/// [`BitIdx::ZERO`]: crate::index::BitIdx::ZERO
 	/// [`BitTail::LAST`]: crate::index::BitTail::LAST
-	pub(crate) fn span(self, len: usize) -> (usize, Self) {
+	pub fn span(self, len: usize) -> (usize, Self) {
 		if len == 0 {
 			return (0, self);
 		}
@@ -713,6 +872,14 @@ where R: BitRegister
 	}
 }
 
+impl<R> From<BitIdx<R>> for BitTail<R>
+where R: BitRegister
+{
+	fn from(idx: BitIdx<R>) -> Self {
+		unsafe { Self::new_unchecked(idx.value()) }
+	}
+}
+
 impl<R> Binary for BitTail<R>
 where R: BitRegister
 {
@@ -853,7 +1020,7 @@ where R: BitRegister
 	}
 
 	/// Iterates over all possible position values.
-	pub(crate) fn range_all() -> impl Iterator<Item = Self>
+	pub fn range_all() -> impl Iterator<Item = Self>
 	+ DoubleEndedIterator
 	+ ExactSizeIterator
 	+ FusedIterator {
@@ -991,6 +1158,26 @@ where R: BitRegister
 		BitMask::new(self.sel)
 	}
 
+	/// Recovers the electrical position of the selected bit.
+	///
+	/// This is the inverse of [`BitPos::select`], and counts the trailing
+	/// zeros of the selector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::BitSel;
+	///
+	/// let sel = BitSel::new(0x20u8).unwrap();
+	/// assert_eq!(sel.position().value(), 5);
+	/// assert_eq!(sel.position().select(), sel);
+	/// ```
+	///
+	/// [`BitPos::select`]: crate::index::BitPos::select
+	pub fn position(self) -> BitPos<R> {
+		unsafe { BitPos::new_unchecked(self.sel.trailing_zeros() as u8) }
+	}
+
 	/// Iterates over all possible selector values.
 	pub fn range_all() -> impl Iterator<Item = Self>
 	+ DoubleEndedIterator
@@ -1000,6 +1187,34 @@ where R: BitRegister
 	}
 }
 
+impl<R> From<BitPos<R>> for BitSel<R>
+where R: BitRegister
+{
+	fn from(pos: BitPos<R>) -> Self {
+		pos.select()
+	}
+}
+
+/* A blanket `TryFrom<R> for BitSel<R>` conflicts with the standard library’s
+`TryFrom<U> for T where U: Into<T>`, so each register gets its own impl.
+*/
+macro_rules! sel_try_from {
+	($($t:ident),+ $(,)?) => { $(
+		impl TryFrom<$t> for BitSel<$t> {
+			type Error = BitSelErr<$t>;
+
+			fn try_from(value: $t) -> Result<Self, Self::Error> {
+				Self::new(value).ok_or(BitSelErr { err: value })
+			}
+		}
+	)+ };
+}
+
+sel_try_from!(u8, u16, u32, usize);
+
+#[cfg(target_pointer_width = "64")]
+sel_try_from!(u64);
+
 impl<R> Binary for BitSel<R>
 where R: BitRegister
 {
@@ -1024,6 +1239,65 @@ where R: BitRegister
 	}
 }
 
+/// Marks a value that is not a one-hot selector for a register type.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitSelErr<R>
+where R: BitRegister
+{
+	/// The value that is invalid as a [`BitSel<R>`].
+	///
+	/// [`BitSel<R>`]: crate::index::BitSel
+	err: R,
+}
+
+impl<R> BitSelErr<R>
+where R: BitRegister
+{
+	/// Removes the error wrapper, leaving the rejected value.
+	#[cfg(not(tarpaulin_include))]
+	pub fn value(self) -> R {
+		self.err
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<R> Debug for BitSelErr<R>
+where R: BitRegister
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"BitSelErr<{}>({:0>2$b})",
+			any::type_name::<R>(),
+			self.err,
+			R::BITS as usize,
+		)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<R> Display for BitSelErr<R>
+where R: BitRegister
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"The value {:0>1$b} has {2} bits set, and is not a one-hot \
+			 selector for {3}",
+			self.err,
+			R::BITS as usize,
+			self.err.count_ones(),
+			any::type_name::<R>(),
+		)
+	}
+}
+
+#[cfg(any(feature = "std", feature = "core-error"))]
+impl<R> crate::error::StdError for BitSelErr<R> where R: BitRegister
+{
+}
+
 /** A multi-bit selection mask for a register `R`.
 
 Unlike [`BitSel`], which enforces a strict one-hot mask encoding, this mask type
@@ -1136,6 +1410,107 @@ where R: BitRegister
 			mask: self.mask | sel.sel,
 		}
 	}
+
+	/// Iterates over each selector bit set high in the mask.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the one-hot selectors of each bit set in `self`, in
+	/// ascending electrical position.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::index::{BitMask, BitSel};
+	///
+	/// let mask = BitMask::new(0b1010u8);
+	/// let sels: Vec<BitSel<u8>> = mask.iter_sels().collect();
+	/// assert_eq!(sels, [BitSel::new(2).unwrap(), BitSel::new(8).unwrap()]);
+	/// ```
+	pub fn iter_sels(
+		self,
+	) -> impl Iterator<Item = BitSel<R>> + DoubleEndedIterator + FusedIterator {
+		BitSel::<R>::range_all().filter(move |&sel| self.test(sel))
+	}
+
+	/// Tests whether the mask contains every bit set in another mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: A mask whose bits are all tested in `self`.
+	///
+	/// # Returns
+	///
+	/// Whether every bit set high in `other` is also set high in `self`. This
+	/// is always true when `other` is empty.
+	pub fn contains_all(&self, other: Self) -> bool {
+		self.mask & other.mask == other.mask
+	}
+
+	/// Tests whether the mask shares any set bit with another mask.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: A mask whose bits are tested in `self`.
+	///
+	/// # Returns
+	///
+	/// Whether at least one bit is set high in both `self` and `other`.
+	pub fn intersects(&self, other: Self) -> bool {
+		self.mask & other.mask != R::ZERO
+	}
+
+	/// Finds the lowest index, under the ordering `O`, selected by the mask.
+	///
+	/// The `Lsb0` and `Msb0` orderings count the trailing or leading zeros of
+	/// the mask. Other orderings test each index in turn.
+	pub(crate) fn first_index<O>(self) -> Option<BitIdx<R>>
+	where O: BitOrder {
+		if self.mask == R::ZERO {
+			return None;
+		}
+		let order = TypeId::of::<O>();
+		let value = if order == TypeId::of::<Lsb0>() {
+			self.mask.trailing_zeros() as u8
+		}
+		else if order == TypeId::of::<Msb0>() {
+			self.mask.leading_zeros() as u8
+		}
+		else {
+			return BitIdx::range_all().find(|idx| self.test(idx.select::<O>()));
+		};
+		Some(unsafe { BitIdx::new_unchecked(value) })
+	}
+
+	/// Finds the highest index, under the ordering `O`, selected by the mask.
+	///
+	/// The `Lsb0` and `Msb0` orderings count the leading or trailing zeros of
+	/// the mask. Other orderings test each index in turn.
+	pub(crate) fn last_index<O>(self) -> Option<BitIdx<R>>
+	where O: BitOrder {
+		if self.mask == R::ZERO {
+			return None;
+		}
+		let order = TypeId::of::<O>();
+		let zeros = if order == TypeId::of::<Lsb0>() {
+			self.mask.leading_zeros() as u8
+		}
+		else if order == TypeId::of::<Msb0>() {
+			self.mask.trailing_zeros() as u8
+		}
+		else {
+			return BitIdx::range_all()
+				.rev()
+				.find(|idx| self.test(idx.select::<O>()));
+		};
+		Some(unsafe { BitIdx::new_unchecked(R::BITS - 1 - zeros) })
+	}
 }
 
 impl<R> Binary for BitMask<R>
@@ -1183,6 +1558,16 @@ where R: BitRegister
 	}
 }
 
+impl<R> BitAnd for BitMask<R>
+where R: BitRegister
+{
+	type Output = Self;
+
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self & rhs.mask
+	}
+}
+
 impl<R> BitOr<R> for BitMask<R>
 where R: BitRegister
 {
@@ -1195,6 +1580,38 @@ where R: BitRegister
 	}
 }
 
+impl<R> BitOr for BitMask<R>
+where R: BitRegister
+{
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self | rhs.mask
+	}
+}
+
+impl<R> BitXor<R> for BitMask<R>
+where R: BitRegister
+{
+	type Output = Self;
+
+	fn bitxor(self, rhs: R) -> Self::Output {
+		Self {
+			mask: self.mask ^ rhs,
+		}
+	}
+}
+
+impl<R> BitXor for BitMask<R>
+where R: BitRegister
+{
+	type Output = Self;
+
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		self ^ rhs.mask
+	}
+}
+
 impl<R> Not for BitMask<R>
 where R: BitRegister
 {
@@ -1226,6 +1643,11 @@ mod tests {
 			assert_eq!(idx.mask::<Lsb0>().value(), 1 << n);
 			assert_eq!(idx.mask::<Msb0>().value(), 128 >> n);
 		}
+
+		let (next, wrap) = BitIdx::<u8>::new(3).unwrap().next();
+		assert_eq!((next.value(), wrap), (4, false));
+		let (prev, wrap) = BitIdx::<u8>::ZERO.prev();
+		assert_eq!((prev, wrap), (BitIdx::LAST, true));
 	}
 
 	#[test]
@@ -1235,6 +1657,19 @@ mod tests {
 			assert_eq!(tail.value(), n);
 		}
 		assert!(BitTail::<u8>::new(9).is_none());
+
+		assert_eq!(BitTail::<u8>::ZERO.span(0), (0, BitTail::ZERO));
+		assert_eq!(BitTail::<u8>::LAST.span(8), (1, BitTail::LAST));
+		assert_eq!(BitPos::<u8>::range_all().count(), 8);
+
+		let idx = BitIdx::<u8>::new(3).unwrap();
+		let tail = BitTail::<u8>::from(idx);
+		assert_eq!(tail.value(), 3);
+		assert_eq!(tail.to_idx(), Some(idx));
+		assert_eq!(tail.distance_to(BitTail::LAST), 5);
+		assert_eq!(idx.distance_to(tail), 0);
+		assert_eq!(idx.span_to::<Lsb0>(tail), BitMask::ZERO);
+		assert_eq!(idx.span_to::<Lsb0>(BitTail::LAST).value(), 0xF8);
 	}
 
 	#[test]
@@ -1255,7 +1690,13 @@ mod tests {
 
 		for (n, sel) in BitSel::<u8>::range_all().enumerate() {
 			assert_eq!(sel, BitSel::<u8>::new((1 << n) as u8).unwrap());
+			assert_eq!(sel.position().value(), n as u8);
+			assert_eq!(BitSel::from(sel.position()), sel);
+			assert_eq!(BitSel::try_from(sel.value()), Ok(sel));
 		}
+		let err = BitSel::<u16>::try_from(0x0300).unwrap_err();
+		assert_eq!(err.value(), 0x0300);
+		assert!(BitSel::<u16>::try_from(0).is_err());
 	}
 
 	#[test]
@@ -1268,6 +1709,21 @@ mod tests {
 		);
 
 		assert_eq!(!BitMask::<u8>::ALL, BitMask::ZERO);
+
+		let low = BitMask::<u8>::new(0x0F);
+		let mid = BitMask::<u8>::new(0x3C);
+		assert_eq!(low & mid, BitMask::new(0x0C));
+		assert_eq!(low | mid, BitMask::new(0x3F));
+		assert!((low | mid).contains_all(low));
+		assert!(!low.contains_all(mid));
+		assert!(low.contains_all(BitMask::ZERO));
+		assert!(low.intersects(mid));
+		assert!(!low.intersects(!low));
+
+		assert_eq!(mid.iter_sels().count(), 4);
+		assert_eq!(mid.iter_sels().sum::<BitMask<u8>>(), mid);
+		assert_eq!(mid.iter_sels().next_back(), BitSel::new(0x20));
+		assert_eq!(BitMask::<u8>::ZERO.iter_sels().next(), None);
 	}
 
 	#[test]
@@ -275,17 +275,23 @@ pub mod macros;
 
 pub mod access;
 pub mod array;
+pub mod codec;
 mod devel;
 pub mod domain;
+pub mod error;
 pub mod field;
 pub mod index;
 pub mod mem;
 pub mod order;
+pub mod pool;
 pub mod prelude;
 pub mod ptr;
+pub mod rank;
+pub mod register;
 pub mod slice;
 pub mod store;
 pub mod view;
+pub mod wah;
 
 #[cfg(feature = "alloc")]
 pub mod boxed;
@@ -293,5 +299,23 @@ pub mod boxed;
 #[cfg(feature = "alloc")]
 pub mod vec;
 
+#[cfg(feature = "defmt")]
+mod defmts;
+
+#[cfg(all(feature = "memmap2", feature = "std"))]
+pub mod mmap;
+
+#[cfg(feature = "nom")]
+mod noms;
+
+#[cfg(all(feature = "roaring", feature = "alloc"))]
+mod roarings;
+
 #[cfg(feature = "serde")]
 mod serdes;
+
+#[cfg(all(feature = "wasm", feature = "alloc"))]
+pub mod wasm;
+
+#[cfg(feature = "zeroize")]
+mod zeroizes;
@@ -525,5 +525,456 @@ macro_rules! bitbox {
 	};
 }
 
+/** Defines a new [`BitOrder`] implementor from a translation rule.
+
+Writing a correct [`BitOrder`] implementation by hand requires upholding the
+entire contract of the [`index`] module. This macro instead takes a description
+of the index-to-position translation, generates a zero-sized type with the
+`BitOrder` implementation, and proves at compile time that the translation is a
+bijection over every register width from 8 to 64 bits. An invalid translation
+is a compile error, rather than a test failure.
+
+# Argument Rules
+
+The macro accepts any number of attributes (including doc-comments) and a
+visibility, followed by `struct Name` and then one of two rule forms:
+
+- `= |idx, bits| expr`: `expr` computes the electrical position (a `u8`) of the
+  semantic index `idx` (a `u8`) within a register that is `bits` wide. The
+  expression is placed in a `const fn` body, so it may only use `const`-capable
+  syntax such as arithmetic, `if`, and `match`.
+- `= [p0, p1, …]`: a permutation table whose length is a power of two no greater
+  than eight. The table is applied to each aligned group of that many bits, so
+  `[1, 0]` swaps every adjacent pair of bits in a register.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+bitvec::order! {
+  /// Traverses each byte from its high nibble to its low nibble.
+  pub struct HiLo = |idx, bits| idx ^ 4;
+}
+
+bitvec::order! {
+  /// Swaps each adjacent pair of bits.
+  pub struct Pairs = [1, 0];
+}
+
+let bits = 0x0Fu8.view_bits::<HiLo>();
+assert!(bits[.. 4].not_any());
+assert!(bits[4 ..].all());
+
+let bits = 1u8.view_bits::<Pairs>();
+assert!(bits[1]);
+assert_eq!(bits.count_ones(), 1);
+```
+
+This rule is not a bijection in any register, and fails to compile:
+
+```rust,compile_fail
+bitvec::order! {
+  struct Broken = |idx, bits| idx / 2;
+}
+```
+
+[`BitOrder`]: crate::order::BitOrder
+[`index`]: crate::index
+**/
+#[macro_export]
+macro_rules! order {
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident = |$idx:ident, $bits:ident| $rule:expr $(;)?
+	) => {
+		$(#[$attr])*
+		#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+		$vis struct $name;
+
+		impl $name {
+			#[doc(hidden)]
+			#[allow(unused_variables)]
+			const fn __bitvec_order_at($idx: u8, $bits: u8) -> u8 {
+				$rule
+			}
+		}
+
+		$crate::order!(@impl $name);
+	};
+
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident = [$($pos:expr),+ $(,)?] $(;)?
+	) => {
+		$crate::order! {
+			$(#[$attr])*
+			$vis struct $name = |idx, bits| {
+				const TABLE: &[u8] = &[$($pos),+];
+				let len = TABLE.len() as u8;
+				if len > 8 || len & (len - 1) != 0 {
+					//  Out-of-range positions fail the bijection check.
+					u8::MAX
+				}
+				else {
+					(idx & !(len - 1)) | TABLE[(idx & (len - 1)) as usize]
+				}
+			}
+		}
+	};
+
+	(@impl $name:ident) => {
+		const _: () = {
+			//  Walk every index of every register width, and require that each
+			//  produced position is in range and produced exactly once.
+			const VALID: bool = {
+				let mut valid = true;
+				let mut bits = 8u8;
+				while bits <= 64 {
+					let mut seen = 0u64;
+					let mut idx = 0u8;
+					while idx < bits {
+						let pos = $name::__bitvec_order_at(idx, bits);
+						if pos >= bits || seen & (1 << pos) != 0 {
+							valid = false;
+						}
+						else {
+							seen |= 1 << pos;
+						}
+						idx += 1;
+					}
+					bits *= 2;
+				}
+				valid
+			};
+			//  This array length underflows, and fails to compile, when the
+			//  translation is not a bijection.
+			let _: [(); 0 - !VALID as usize] = [];
+		};
+
+		unsafe impl $crate::order::BitOrder for $name {
+			fn at<R>(index: $crate::index::BitIdx<R>) -> $crate::index::BitPos<R>
+			where R: $crate::mem::BitRegister {
+				let pos = Self::__bitvec_order_at(index.value(), R::BITS);
+				unsafe { $crate::index::BitPos::new_unchecked(pos) }
+			}
+		}
+	};
+}
+
+/** Defines a set of named bit fields over a [`BitArray`].
+
+This is an alternative to integer flag constants for register and permission
+masks. The macro generates a wrapper type around a `BitArray`, with a typed
+getter and setter for each named field, a [`Debug`] implementation that prints
+the names of the set fields, and the set-algebra operators. Fields are located
+by bit index, so their placement follows the array’s [`BitOrder`] rather than
+any integer arithmetic, and an index that does not fit in the array is a compile
+error.
+
+# Argument Rules
+
+The macro accepts any number of attributes (including doc-comments) and a
+visibility, followed by `struct Name: BitArray<O, V>` and a braced list of
+fields. Each field is written as `getter, setter: Type = index;` or
+`getter, setter: Type = start .. end;`, and may carry its own attributes:
+
+- `bool` fields occupy the single bit at `index`, and work with any ordering.
+- Unsigned integer fields occupy the bits `start .. end`, which must not be
+  wider than the integer, and are accessed through [`BitField`] in little-endian
+  element order. This requires that `O` be [`Lsb0`] or [`Msb0`]. The setter
+  discards any high bits of its argument that do not fit.
+
+The generated type derives `Clone`, `Copy`, `Default`, `Eq`, `Hash`, and
+`PartialEq`, and has these inherent functions in addition to the field
+accessors:
+
+- `empty()` and `all()` produce values with no bits set, and with every bit of
+  every named field set.
+- `from_bits()`, `into_bits()`, and `as_bitslice()` convert to and from the
+  underlying array.
+- `is_empty()`, `is_all()`, `contains()`, and `intersects()` test the set.
+- `insert()`, `remove()`, and `toggle()` modify the set in place.
+
+The `&`, `|`, `^`, and `-` (difference) operators, and their assigning forms,
+combine two values. The `!` operator inverts only the bits of named fields.
+Bits outside the named fields are carried through the other operators, but are
+not printed by `Debug`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+bitvec::flags! {
+  /// The permissions of a file.
+  #[derive(PartialOrd)]
+  pub struct Perms: BitArray<Lsb0, [u16; 1]> {
+    /// Permission to read the file.
+    read, set_read: bool = 0;
+    write, set_write: bool = 1;
+    exec, set_exec: bool = 2;
+    /// The kind of file.
+    kind, set_kind: u8 = 12 .. 16;
+  }
+}
+
+let mut perms = Perms::empty();
+perms.set_read(true);
+perms.set_kind(5);
+assert!(perms.read());
+assert_eq!(perms.kind(), 5);
+assert_eq!(perms.into_bits().value(), [0x5001]);
+assert_eq!(format!("{:?}", perms), "Perms(read | kind = 5)");
+
+let mut rw = Perms::empty();
+rw.set_read(true);
+rw.set_write(true);
+assert!(rw.contains(perms & rw));
+assert_eq!(perms | rw, {
+  let mut out = rw;
+  out.set_kind(5);
+  out
+});
+assert_eq!(!rw, {
+  let mut out = Perms::all();
+  out.remove(rw);
+  out
+});
+```
+
+A field that does not fit in the array fails to compile:
+
+```rust,compile_fail
+use bitvec::prelude::*;
+
+bitvec::flags! {
+  struct Broken: BitArray<Lsb0, [u8; 1]> {
+    high, set_high: bool = 8;
+  }
+}
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitField`]: crate::field::BitField
+[`BitOrder`]: crate::order::BitOrder
+[`Debug`]: core::fmt::Debug
+[`Lsb0`]: crate::order::Lsb0
+[`Msb0`]: crate::order::Msb0
+**/
+#[macro_export]
+macro_rules! flags {
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident: BitArray<$ord:ty, $arr:ty> {
+			$(
+				$(#[$fattr:meta])*
+				$get:ident, $set:ident: $fty:ty = $start:literal $(.. $end:literal)?;
+			)*
+		}
+	) => {
+		$(#[$attr])*
+		#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+		$vis struct $name {
+			bits: $crate::array::BitArray<$ord, $arr>,
+		}
+
+		const _: () = {
+			const BITS: usize = $crate::macros::internal::core::mem::size_of::<$arr>() * 8;
+			$(
+				//  Each field must be non-empty, fit in the array, and fit in
+				//  its value type. These array lengths underflow, and fail to
+				//  compile, when it does not.
+				let _: [(); 0 - !($start < $crate::flags!(@end $start $(.. $end)?)) as usize] = [];
+				let _: [(); 0 - !($crate::flags!(@end $start $(.. $end)?) <= BITS) as usize] = [];
+				let _: [(); 0 - !(
+					$crate::flags!(@end $start $(.. $end)?) - $start
+					<= <$fty as $crate::macros::internal::FlagField<
+						$ord,
+						<$arr as $crate::view::BitView>::Store,
+					>>::WIDTH
+				) as usize] = [];
+			)*
+		};
+
+		#[allow(dead_code)]
+		impl $name {
+			/// Produces a value with no bits set.
+			$vis fn empty() -> Self {
+				Self { bits: $crate::array::BitArray::zeroed() }
+			}
+
+			/// Produces a value with every bit of every named field set.
+			$vis fn all() -> Self {
+				let mut this = Self::empty();
+				$(
+					this.bits[$start .. $crate::flags!(@end $start $(.. $end)?)]
+						.set_all(true);
+				)*
+				this
+			}
+
+			/// Wraps an array, keeping any bits outside the named fields.
+			$vis fn from_bits(bits: $crate::array::BitArray<$ord, $arr>) -> Self {
+				Self { bits }
+			}
+
+			/// Unwraps the underlying array.
+			$vis fn into_bits(self) -> $crate::array::BitArray<$ord, $arr> {
+				self.bits
+			}
+
+			/// Views the underlying bits.
+			$vis fn as_bitslice(
+				&self,
+			) -> &$crate::slice::BitSlice<
+				$ord,
+				<$arr as $crate::view::BitView>::Store,
+			> {
+				self.bits.as_bitslice()
+			}
+
+			/// Tests if no bits are set.
+			$vis fn is_empty(&self) -> bool {
+				self.bits.not_any()
+			}
+
+			/// Tests if every bit of every named field is set.
+			$vis fn is_all(&self) -> bool {
+				self.contains(Self::all())
+			}
+
+			/// Tests if every bit set in `other` is also set in `self`.
+			$vis fn contains(&self, other: Self) -> bool {
+				*self & other == other
+			}
+
+			/// Tests if any bit is set in both `self` and `other`.
+			$vis fn intersects(&self, other: Self) -> bool {
+				!(*self & other).is_empty()
+			}
+
+			/// Sets every bit that is set in `other`.
+			$vis fn insert(&mut self, other: Self) {
+				*self |= other;
+			}
+
+			/// Clears every bit that is set in `other`.
+			$vis fn remove(&mut self, other: Self) {
+				*self -= other;
+			}
+
+			/// Inverts every bit that is set in `other`.
+			$vis fn toggle(&mut self, other: Self) {
+				*self ^= other;
+			}
+
+			$(
+				$(#[$fattr])*
+				$vis fn $get(&self) -> $fty {
+					<$fty as $crate::macros::internal::FlagField<_, _>>::load(
+						&self.bits[$start .. $crate::flags!(@end $start $(.. $end)?)],
+					)
+				}
+
+				/// Writes the field read by the paired getter.
+				$vis fn $set(&mut self, value: $fty) {
+					<$fty as $crate::macros::internal::FlagField<_, _>>::store(
+						value,
+						&mut self.bits[$start .. $crate::flags!(@end $start $(.. $end)?)],
+					)
+				}
+			)*
+		}
+
+		impl $crate::macros::internal::core::fmt::Debug for $name {
+			fn fmt(
+				&self,
+				fmt: &mut $crate::macros::internal::core::fmt::Formatter,
+			) -> $crate::macros::internal::core::fmt::Result {
+				fmt.write_str(concat!(stringify!($name), "("))?;
+				let mut first = true;
+				$(
+					let value = self.$get();
+					if <$fty as $crate::macros::internal::FlagField<
+						$ord,
+						<$arr as $crate::view::BitView>::Store,
+					>>::is_set(&value)
+					{
+						if !first {
+							fmt.write_str(" | ")?;
+						}
+						first = false;
+						<$fty as $crate::macros::internal::FlagField<
+							$ord,
+							<$arr as $crate::view::BitView>::Store,
+						>>::fmt_named(
+							&value,
+							stringify!($get),
+							fmt,
+						)?;
+					}
+				)*
+				if first {
+					fmt.write_str("empty")?;
+				}
+				fmt.write_str(")")
+			}
+		}
+
+		$crate::flags!(@op $name, BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+		$crate::flags!(@op $name, BitOr, bitor, BitOrAssign, bitor_assign, |=);
+		$crate::flags!(@op $name, BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+
+		impl $crate::macros::internal::core::ops::SubAssign for $name {
+			fn sub_assign(&mut self, rhs: Self) {
+				self.bits &= !rhs.bits;
+			}
+		}
+
+		impl $crate::macros::internal::core::ops::Sub for $name {
+			type Output = Self;
+
+			fn sub(mut self, rhs: Self) -> Self {
+				self -= rhs;
+				self
+			}
+		}
+
+		impl $crate::macros::internal::core::ops::Not for $name {
+			type Output = Self;
+
+			fn not(self) -> Self {
+				Self { bits: !self.bits } & Self::all()
+			}
+		}
+	};
+
+	(@end $start:literal) => {
+		$start + 1
+	};
+
+	(@end $start:literal .. $end:literal) => {
+		$end
+	};
+
+	(@op $name:ident, $op:ident, $f:ident, $op_a:ident, $f_a:ident, $sigil:tt) => {
+		impl $crate::macros::internal::core::ops::$op_a for $name {
+			fn $f_a(&mut self, rhs: Self) {
+				self.bits $sigil rhs.bits;
+			}
+		}
+
+		impl $crate::macros::internal::core::ops::$op for $name {
+			type Output = Self;
+
+			fn $f(mut self, rhs: Self) -> Self {
+				$crate::macros::internal::core::ops::$op_a::$f_a(&mut self, rhs);
+				self
+			}
+		}
+	};
+}
+
 #[cfg(test)]
 mod tests;
@@ -10,6 +10,19 @@ public-API constructor macros.
 
 #![doc(hidden)]
 
+use crate::{
+	field::BitField,
+	mem::BitMemory,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::fmt::{
+	self,
+	Formatter,
+};
+
 #[doc(hidden)]
 pub use core;
 
@@ -435,6 +448,93 @@ pub use self::u8_from_be_bits as u8_from_ne_bits;
 pub const fn __deprecated_order_no_store() {
 }
 
+/** A value type that the [`flags!`] macro can store in a named field.
+
+This is implemented for `bool`, which occupies a single bit under any ordering,
+and for the unsigned integers, which occupy a run of bits accessed through
+[`BitField`] and so require an ordering that implements it.
+
+[`BitField`]: crate::field::BitField
+[`flags!`]: crate::flags
+**/
+#[doc(hidden)]
+pub trait FlagField<O, T>: Sized
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The maximum number of bits that the field can occupy.
+	const WIDTH: usize;
+
+	/// Reads the field value out of its bits.
+	fn load(bits: &BitSlice<O, T>) -> Self;
+
+	/// Writes the field value into its bits, discarding any high bits that do
+	/// not fit.
+	fn store(self, bits: &mut BitSlice<O, T>);
+
+	/// Tests if the field is displayed in the `Debug` rendering.
+	fn is_set(&self) -> bool;
+
+	/// Renders a set field in the `Debug` rendering.
+	fn fmt_named(&self, name: &str, fmt: &mut Formatter) -> fmt::Result;
+}
+
+impl<O, T> FlagField<O, T> for bool
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	const WIDTH: usize = 1;
+
+	fn load(bits: &BitSlice<O, T>) -> Self {
+		bits[0]
+	}
+
+	fn store(self, bits: &mut BitSlice<O, T>) {
+		bits.set(0, self);
+	}
+
+	fn is_set(&self) -> bool {
+		*self
+	}
+
+	fn fmt_named(&self, name: &str, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str(name)
+	}
+}
+
+macro_rules! flag_field {
+	($($t:ty),+ $(,)?) => { $(
+		impl<O, T> FlagField<O, T> for $t
+		where
+			O: BitOrder,
+			T: BitStore,
+			BitSlice<O, T>: BitField,
+		{
+			const WIDTH: usize = <$t as BitMemory>::BITS as usize;
+
+			fn load(bits: &BitSlice<O, T>) -> Self {
+				bits.load_le::<$t>()
+			}
+
+			fn store(self, bits: &mut BitSlice<O, T>) {
+				bits.store_le::<$t>(self);
+			}
+
+			fn is_set(&self) -> bool {
+				*self != 0
+			}
+
+			fn fmt_named(&self, name: &str, fmt: &mut Formatter) -> fmt::Result {
+				write!(fmt, "{} = {}", name, self)
+			}
+		}
+	)+ };
+}
+
+flag_field!(u8, u16, u32, u64, usize);
+
 #[cfg(test)]
 mod tests {
 	use super::*;
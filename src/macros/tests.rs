@@ -526,3 +526,114 @@ fn make_elem() {
 		invoke_make_elem!(crate::order::Lsb0, Cell<usize> as usize; 0, 0, 1, 1);
 	assert_eq!(cell.get(), 12);
 }
+
+#[test]
+fn compile_order() {
+	order! {
+		struct HiLo = |idx, bits| idx ^ 4;
+	}
+	order! {
+		struct Reverse = |idx, bits| bits - 1 - idx;
+	}
+	order! {
+		pub(crate) struct Pairs = [1, 0];
+	}
+	order! {
+		struct Nibbles = [3, 2, 1, 0, 7, 6, 5, 4,];
+	}
+
+	crate::order::verify::<HiLo>(false);
+	crate::order::verify::<Reverse>(false);
+	crate::order::verify::<Pairs>(false);
+	crate::order::verify::<Nibbles>(false);
+
+	assert_eq!(0x0Fu8.view_bits::<HiLo>(), bits![0, 0, 0, 0, 1, 1, 1, 1]);
+	assert_eq!(1u8.view_bits::<Reverse>(), 1u8.view_bits::<Msb0>());
+	assert_eq!(1u8.view_bits::<Pairs>(), bits![0, 1, 0, 0, 0, 0, 0, 0]);
+	assert_eq!(1u16.view_bits::<Nibbles>().iter_ones().next(), Some(3));
+}
+
+#[test]
+fn compile_flags() {
+	flags! {
+		/// A control register.
+		pub(crate) struct Ctrl: BitArray<Msb0, [u8; 2]> {
+			enable, set_enable: bool = 0;
+			/// The prescaler.
+			prescale, set_prescale: u16 = 3 .. 13;
+			irq, set_irq: bool = 15;
+		}
+	}
+
+	let mut ctrl = Ctrl::empty();
+	assert!(ctrl.is_empty());
+	ctrl.set_prescale(0x7FF);
+	assert_eq!(ctrl.prescale(), 0x3FF);
+	ctrl.set_prescale(0x155);
+	ctrl.set_irq(true);
+	assert_eq!(ctrl.into_bits().value(), [0b0001_0101, 0b0101_0001]);
+
+	let all = Ctrl::all();
+	assert!(all.is_all());
+	assert_eq!(all.as_bitslice().count_zeros(), 4);
+	assert!(all.contains(ctrl));
+	assert!(!ctrl.contains(all));
+	assert!(ctrl.intersects(all));
+
+	let mut irq = Ctrl::empty();
+	irq.set_irq(true);
+	assert_eq!(ctrl - irq, {
+		let mut out = ctrl;
+		out.set_irq(false);
+		out
+	});
+	assert_eq!(ctrl & irq, irq);
+	assert_eq!(ctrl ^ irq | irq, ctrl);
+	assert_eq!((!ctrl).prescale(), 0x2AA);
+	assert!(!(!ctrl).irq());
+	assert!((!ctrl).enable());
+
+	ctrl.toggle(irq);
+	assert!(!ctrl.irq());
+	ctrl.insert(irq);
+	assert!(ctrl.irq());
+	ctrl.remove(all);
+	assert!(ctrl.is_empty());
+
+	let raw = Ctrl::from_bits(BitArray::new([0b0100_0000, 0]));
+	assert!(!raw.is_empty());
+	assert!(!raw.intersects(all));
+
+	//  `bool` fields accept any ordering.
+	order! {
+		struct HiLo = |idx, bits| idx ^ 4;
+	}
+	flags! {
+		struct Nibble: BitArray<HiLo, [u8; 1]> {
+			low, set_low: bool = 4;
+		}
+	}
+	let mut nibble = Nibble::empty();
+	nibble.set_low(true);
+	assert_eq!(nibble.into_bits().value(), [1]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn flags_debug() {
+	flags! {
+		struct Perms: BitArray<Lsb0, [u8; 1]> {
+			read, set_read: bool = 0;
+			write, set_write: bool = 1;
+			mode, set_mode: u8 = 4 .. 8;
+		}
+	}
+
+	let mut perms = Perms::empty();
+	assert_eq!(format!("{:?}", perms), "Perms(empty)");
+	perms.set_write(true);
+	assert_eq!(format!("{:?}", perms), "Perms(write)");
+	perms.set_read(true);
+	perms.set_mode(9);
+	assert_eq!(format!("{:?}", perms), "Perms(read | write | mode = 9)");
+}
@@ -0,0 +1,423 @@
+/*! Bit-slices backed by memory-mapped files.
+
+This module is available when the `memmap2` and `std` features are both
+enabled. It wraps the [`Mmap`] and [`MmapMut`] handles from [`memmap2`] so that
+a mapped file can be used directly as a [`BitSlice`], without first copying it
+into a [`BitVec`].
+
+Creating a memory map is `unsafe`, because the file may be modified by another
+process while it is mapped, and `bitvec` cannot protect against that. Once a
+map exists, however, viewing it as bits is safe, so the constructors in this
+module take an already-created map rather than a file. Read-only and
+copy-on-write maps are both supported: [`MmapOptions::map`] and
+[`MmapOptions::map_copy_read_only`] produce an [`Mmap`] for [`BitMmap`], while
+[`MmapOptions::map_mut`] and [`MmapOptions::map_copy`] produce an [`MmapMut`]
+for [`BitMmapMut`].
+
+Memory maps are byte buffers, so the bit-slices produced here always use `u8`
+storage. They may use any [`BitOrder`].
+
+[`BitMmap`]: self::BitMmap
+[`BitMmapMut`]: self::BitMmapMut
+[`BitOrder`]: crate::order::BitOrder
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`Mmap`]: memmap2::Mmap
+[`MmapMut`]: memmap2::MmapMut
+[`MmapOptions::map`]: memmap2::MmapOptions::map
+[`MmapOptions::map_copy`]: memmap2::MmapOptions::map_copy
+[`MmapOptions::map_copy_read_only`]: memmap2::MmapOptions::map_copy_read_only
+[`MmapOptions::map_mut`]: memmap2::MmapOptions::map_mut
+[`memmap2`]: memmap2
+!*/
+
+#![cfg(all(feature = "memmap2", feature = "std"))]
+
+use crate::{
+	devel as dvl,
+	error::Error,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+	ops::{
+		Deref,
+		DerefMut,
+		RangeBounds,
+	},
+};
+
+use memmap2::{
+	Mmap,
+	MmapMut,
+};
+
+use std::io;
+
+/** A read-only memory map, viewed as a [`BitSlice`].
+
+The bit-slice covers every byte of the map. It borrows from this handle, so it
+cannot outlive the map.
+
+# Examples
+
+```rust
+use bitvec::{
+  mmap::BitMmap,
+  prelude::*,
+};
+use memmap2::MmapMut;
+
+let mut map = MmapMut::map_anon(2).unwrap();
+map.copy_from_slice(&[0x80, 0x01]);
+
+let bits = BitMmap::<Msb0>::new(map.make_read_only().unwrap()).unwrap();
+assert_eq!(bits.len(), 16);
+assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [0, 15]);
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+**/
+pub struct BitMmap<O = Lsb0>
+where O: BitOrder
+{
+	/// The mapped region.
+	map: Mmap,
+	/// The ordering used to view the mapped bytes.
+	_ord: PhantomData<O>,
+}
+
+impl<O> BitMmap<O>
+where O: BitOrder
+{
+	/// Views a read-only memory map as bits.
+	///
+	/// # Parameters
+	///
+	/// - `map`: A read-only memory map. This may be a view of a file, or a
+	///   private copy-on-write view that is not yet writable.
+	///
+	/// # Returns
+	///
+	/// A handle that owns `map` and dereferences to a [`BitSlice`] over all of
+	/// its bytes.
+	///
+	/// # Errors
+	///
+	/// This returns [`Error::TooLong`] if the map has more bytes than a
+	/// [`BitSlice`] can describe.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	pub fn new(map: Mmap) -> Result<Self, Error> {
+		BitSlice::<O, u8>::from_slice(&map)?;
+		Ok(Self {
+			map,
+			_ord: PhantomData,
+		})
+	}
+
+	/// Views the mapped region as bits.
+	pub fn as_bitslice(&self) -> &BitSlice<O, u8> {
+		unsafe { BitSlice::from_slice_unchecked(&self.map) }
+	}
+
+	/// Views the underlying memory map.
+	pub fn as_mmap(&self) -> &Mmap {
+		&self.map
+	}
+
+	/// Releases the underlying memory map.
+	pub fn into_mmap(self) -> Mmap {
+		self.map
+	}
+}
+
+impl<O> AsRef<BitSlice<O, u8>> for BitMmap<O>
+where O: BitOrder
+{
+	fn as_ref(&self) -> &BitSlice<O, u8> {
+		self.as_bitslice()
+	}
+}
+
+/// Mapped regions are typically very large, so only the address and length
+/// of the bit-slice are shown.
+#[cfg(not(tarpaulin_include))]
+impl<O> Debug for BitMmap<O>
+where O: BitOrder
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().bitptr().render(fmt, "Mmap", None)
+	}
+}
+
+impl<O> Deref for BitMmap<O>
+where O: BitOrder
+{
+	type Target = BitSlice<O, u8>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+/** A writable memory map, viewed as a [`BitSlice`].
+
+Writes to a map of a file are carried to the file by the operating system at
+some later time, or immediately by the [`.flush()`] family of methods. Writes
+to a copy-on-write map are private to this process, and are never carried to
+the file.
+
+# Examples
+
+```rust
+use bitvec::{
+  mmap::BitMmapMut,
+  prelude::*,
+};
+use memmap2::MmapMut;
+
+let map = MmapMut::map_anon(4).unwrap();
+let mut bits = BitMmapMut::<Lsb0>::new(map).unwrap();
+bits.set(9, true);
+bits[20 .. 24].store(0xFu8);
+bits.flush_range(8 .. 24).unwrap();
+
+assert_eq!(&bits.as_mmap()[..], &[0, 2, 0xF0, 0]);
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.flush()`]: Self::flush
+**/
+pub struct BitMmapMut<O = Lsb0>
+where O: BitOrder
+{
+	/// The mapped region.
+	map: MmapMut,
+	/// The ordering used to view the mapped bytes.
+	_ord: PhantomData<O>,
+}
+
+impl<O> BitMmapMut<O>
+where O: BitOrder
+{
+	/// Views a writable memory map as bits.
+	///
+	/// # Parameters
+	///
+	/// - `map`: A writable memory map. This may be a shared view of a file, a
+	///   private copy-on-write view of a file, or an anonymous map.
+	///
+	/// # Returns
+	///
+	/// A handle that owns `map` and dereferences to a [`BitSlice`] over all of
+	/// its bytes.
+	///
+	/// # Errors
+	///
+	/// This returns [`Error::TooLong`] if the map has more bytes than a
+	/// [`BitSlice`] can describe.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	pub fn new(map: MmapMut) -> Result<Self, Error> {
+		BitSlice::<O, u8>::from_slice(&map)?;
+		Ok(Self {
+			map,
+			_ord: PhantomData,
+		})
+	}
+
+	/// Views the mapped region as bits.
+	pub fn as_bitslice(&self) -> &BitSlice<O, u8> {
+		unsafe { BitSlice::from_slice_unchecked(&self.map) }
+	}
+
+	/// Views the mapped region as mutable bits.
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<O, u8> {
+		unsafe { BitSlice::from_slice_unchecked_mut(&mut self.map) }
+	}
+
+	/// Views the underlying memory map.
+	pub fn as_mmap(&self) -> &MmapMut {
+		&self.map
+	}
+
+	/// Releases the underlying memory map.
+	pub fn into_mmap(self) -> MmapMut {
+		self.map
+	}
+
+	/// Writes all outstanding modifications to the mapped file, and waits for
+	/// them to complete.
+	///
+	/// # Original
+	///
+	/// [`MmapMut::flush`](memmap2::MmapMut::flush)
+	pub fn flush(&self) -> io::Result<()> {
+		self.map.flush()
+	}
+
+	/// Begins writing all outstanding modifications to the mapped file, without
+	/// waiting for them to complete.
+	///
+	/// # Original
+	///
+	/// [`MmapMut::flush_async`](memmap2::MmapMut::flush_async)
+	pub fn flush_async(&self) -> io::Result<()> {
+		self.map.flush_async()
+	}
+
+	/// Writes outstanding modifications in a range of bits to the mapped file,
+	/// and waits for them to complete.
+	///
+	/// Every byte that holds a bit in `range` is written.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `range`: A range of bit indices in the map.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is out of bounds of the map.
+	///
+	/// # Original
+	///
+	/// [`MmapMut::flush_range`](memmap2::MmapMut::flush_range)
+	pub fn flush_range<R>(&self, range: R) -> io::Result<()>
+	where R: RangeBounds<usize> {
+		let (offset, len) = self.byte_span(range);
+		self.map.flush_range(offset, len)
+	}
+
+	/// Converts this handle into a read-only handle over the same map.
+	///
+	/// # Original
+	///
+	/// [`MmapMut::make_read_only`](memmap2::MmapMut::make_read_only)
+	pub fn make_read_only(self) -> io::Result<BitMmap<O>> {
+		self.map.make_read_only().map(|map| BitMmap {
+			map,
+			_ord: PhantomData,
+		})
+	}
+
+	/// Computes the byte offset and byte count of the map that hold a range of
+	/// bits.
+	fn byte_span<R>(&self, range: R) -> (usize, usize)
+	where R: RangeBounds<usize> {
+		let range = dvl::normalize_range(range, self.len());
+		dvl::assert_range(range.clone(), self.len());
+		let bytes = self.as_bitslice()[range].as_slice();
+		(
+			bytes.as_ptr() as usize - self.map.as_ptr() as usize,
+			bytes.len(),
+		)
+	}
+}
+
+impl<O> AsMut<BitSlice<O, u8>> for BitMmapMut<O>
+where O: BitOrder
+{
+	fn as_mut(&mut self) -> &mut BitSlice<O, u8> {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<O> AsRef<BitSlice<O, u8>> for BitMmapMut<O>
+where O: BitOrder
+{
+	fn as_ref(&self) -> &BitSlice<O, u8> {
+		self.as_bitslice()
+	}
+}
+
+/// Mapped regions are typically very large, so only the address and length
+/// of the bit-slice are shown.
+#[cfg(not(tarpaulin_include))]
+impl<O> Debug for BitMmapMut<O>
+where O: BitOrder
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().bitptr().render(fmt, "MmapMut", None)
+	}
+}
+
+impl<O> Deref for BitMmapMut<O>
+where O: BitOrder
+{
+	type Target = BitSlice<O, u8>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+impl<O> DerefMut for BitMmapMut<O>
+where O: BitOrder
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn byte_spans() {
+		let map = MmapMut::map_anon(8).unwrap();
+		let bits = BitMmapMut::<Msb0>::new(map).unwrap();
+		assert_eq!(bits.len(), 64);
+		assert_eq!(bits.byte_span(..), (0, 8));
+		assert_eq!(bits.byte_span(9 .. 17), (1, 2));
+		assert_eq!(bits.byte_span(16 .. 24), (2, 1));
+		assert_eq!(bits.byte_span(64 ..), (8, 0));
+	}
+
+	#[test]
+	fn copy_on_write() {
+		use std::io::Write;
+
+		let path = std::env::temp_dir()
+			.join(format!("bitvec-mmap-{}", std::process::id()));
+		let mut file = std::fs::OpenOptions::new()
+			.read(true)
+			.write(true)
+			.create(true)
+			.truncate(true)
+			.open(&path)
+			.unwrap();
+		file.write_all(&[0x0F, 0xF0]).unwrap();
+
+		let map =
+			unsafe { memmap2::MmapOptions::new().map_copy(&file) }.unwrap();
+		let mut bits = BitMmapMut::<Lsb0>::new(map).unwrap();
+		assert_eq!(bits.count_ones(), 8);
+		bits.set_all(true);
+		bits.flush().unwrap();
+
+		let map = unsafe { Mmap::map(&file) }.unwrap();
+		let bits = BitMmap::<Lsb0>::new(map).unwrap();
+		assert_eq!(bits.count_ones(), 8);
+		assert_eq!(bits[.. 4], bits![1; 4]);
+
+		drop(bits);
+		drop(file);
+		std::fs::remove_file(&path).unwrap();
+	}
+}
@@ -0,0 +1,268 @@
+/*! [`nom`] parser input support.
+
+This module implements `nom`’s input traits for `&BitSlice`, so that
+bit-level grammars can be written with the ordinary `nom` combinators, and run
+directly over `bitvec` storage. Each item of the input is one bit, as a `bool`,
+and every count and index is measured in bits. This replaces `nom`’s own
+`(&[u8], usize)` bit-input convention, which is limited to `Msb0` ordering over
+bytes.
+
+The implementations follow those that `nom` provides for `&[u8]`:
+
+- [`InputLength`], [`InputTake`], [`InputIter`], and [`Slice`] measure, split,
+  walk, and subslice the input. [`InputTakeAtPosition`] is provided by `nom`
+  from these.
+- [`Offset`] measures the distance in bits from an input to one of its
+  subslices.
+- [`Compare`] tests a prefix of the input against another bit-slice, of any
+  type parameters, so a bit-slice can be used as a `tag`.
+- [`FindToken<bool>`] tests whether a bit-slice contains a bit value, so a
+  bit-slice can be used as the token list of `is_a` and `is_not`.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use nom::bytes::complete::{tag, take, take_while1};
+use nom::sequence::tuple;
+use nom::IResult;
+
+type Input<'a> = &'a BitSlice<Msb0, u8>;
+
+let data = [0b110_010_11u8, 0b1_0000000];
+let header = bits![1, 1, 0];
+let mut frame = tuple((tag(header), take(3usize), take_while1(|bit| bit)));
+let res: IResult<Input, _> = frame(data.view_bits());
+
+let (rest, (_, kind, ones)) = res.unwrap();
+assert_eq!(kind, bits![0, 1, 0]);
+assert_eq!(ones.len(), 3);
+assert_eq!(rest.len(), 7);
+```
+
+[`Compare`]: nom::Compare
+[`FindToken<bool>`]: nom::FindToken
+[`InputIter`]: nom::InputIter
+[`InputLength`]: nom::InputLength
+[`InputTake`]: nom::InputTake
+[`InputTakeAtPosition`]: nom::InputTakeAtPosition
+[`Offset`]: nom::Offset
+[`Slice`]: nom::Slice
+[`nom`]: nom
+!*/
+
+#![cfg(feature = "nom")]
+
+use crate::{
+	order::BitOrder,
+	slice::{
+		BitSlice,
+		Iter,
+	},
+	store::BitStore,
+};
+
+use core::{
+	cmp,
+	iter::{
+		Copied,
+		Enumerate,
+	},
+	ops::{
+		Range,
+		RangeFrom,
+		RangeFull,
+		RangeTo,
+	},
+};
+
+use nom::{
+	Compare,
+	CompareResult,
+	FindToken,
+	InputIter,
+	InputLength,
+	InputTake,
+	Needed,
+	Offset,
+	Slice,
+	UnspecializedInput,
+};
+
+impl<O, T> InputLength for &BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn input_len(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<O, T> InputTake for &BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn take(&self, count: usize) -> Self {
+		&self[.. count]
+	}
+
+	fn take_split(&self, count: usize) -> (Self, Self) {
+		let (prefix, suffix) = self.split_at(count);
+		(suffix, prefix)
+	}
+}
+
+impl<'a, O, T> InputIter for &'a BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = bool;
+	type Iter = Enumerate<Self::IterElem>;
+	type IterElem = Copied<Iter<'a, O, T>>;
+
+	fn iter_indices(&self) -> Self::Iter {
+		self.iter_elements().enumerate()
+	}
+
+	fn iter_elements(&self) -> Self::IterElem {
+		self.iter().copied()
+	}
+
+	fn position<P>(&self, predicate: P) -> Option<usize>
+	where P: Fn(Self::Item) -> bool {
+		self.iter().copied().position(predicate)
+	}
+
+	fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+		let len = self.len();
+		if len >= count {
+			Ok(count)
+		}
+		else {
+			Err(Needed::new(count - len))
+		}
+	}
+}
+
+impl<O, T> UnspecializedInput for &BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+macro_rules! slice {
+	($($range:ty),+ $(,)?) => { $(
+		impl<'a, O, T> Slice<$range> for &'a BitSlice<O, T>
+		where
+			O: BitOrder,
+			T: BitStore,
+		{
+			fn slice(&self, range: $range) -> Self {
+				&self[range]
+			}
+		}
+	)+ };
+}
+
+slice!(Range<usize>, RangeFrom<usize>, RangeFull, RangeTo<usize>);
+
+impl<O, T> Offset for &BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Counts the bits from the start of `self` to the start of `second`.
+	///
+	/// As with `nom`’s implementation for `&[u8]`, `second` must be a subslice
+	/// of `self` that begins at or after it.
+	fn offset(&self, second: &Self) -> usize {
+		self.offset_from(second) as usize
+	}
+}
+
+impl<'b, O1, O2, T1, T2> Compare<&'b BitSlice<O2, T2>> for &BitSlice<O1, T1>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	T1: BitStore,
+	T2: BitStore,
+{
+	fn compare(&self, t: &'b BitSlice<O2, T2>) -> CompareResult {
+		let len = cmp::min(self.len(), t.len());
+		if self[.. len] != t[.. len] {
+			CompareResult::Error
+		}
+		else if len < t.len() {
+			CompareResult::Incomplete
+		}
+		else {
+			CompareResult::Ok
+		}
+	}
+
+	/// Bits have no case, so this is the same as [`.compare()`].
+	///
+	/// [`.compare()`]: Self::compare
+	fn compare_no_case(&self, t: &'b BitSlice<O2, T2>) -> CompareResult {
+		self.compare(t)
+	}
+}
+
+impl<O, T> FindToken<bool> for &BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn find_token(&self, token: bool) -> bool {
+		if token { self.any() } else { self.not_all() }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	use nom::{
+		Err,
+		IResult,
+		bytes::complete::{
+			is_a,
+			tag,
+			take_till,
+		},
+		error::Error,
+	};
+
+	#[test]
+	fn combinators() {
+		type Input<'a> = &'a BitSlice<Lsb0, u16>;
+
+		let data = [0b0110_0000_1111_1010u16];
+		let input: Input = data.view_bits();
+
+		let res: IResult<Input, Input> = tag(bits![Msb0, u8; 0, 1, 0])(input);
+		let (rest, head) = res.unwrap();
+		assert_eq!(head.len(), 3);
+		assert_eq!(input.offset(&rest), 3);
+
+		let res: IResult<Input, Input> = take_till(|bit: bool| !bit)(rest);
+		let (rest, ones) = res.unwrap();
+		assert_eq!(ones, bits![1; 5]);
+
+		let res: IResult<Input, Input> = is_a(bits![0])(rest);
+		let (rest, zeros) = res.unwrap();
+		assert_eq!(zeros, bits![0; 5]);
+		assert_eq!(rest, bits![1, 1, 0]);
+
+		let res: IResult<Input, Input> = tag(bits![1, 1, 0, 1])(rest);
+		assert!(matches!(res, Err(Err::Error(Error { .. }))));
+		assert_eq!(rest.compare(bits![1, 1, 0, 1]), CompareResult::Incomplete);
+		assert_eq!(rest.slice(1 ..), bits![1, 0]);
+		assert_eq!(rest.slice_index(5), Err(Needed::new(2)));
+	}
+}
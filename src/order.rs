@@ -352,6 +352,12 @@ Typically, your platform’s C compiler uses least-significant-bit-first orderin
 for bitfields. The [`Lsb0`] bit ordering and little-endian byte ordering are
 otherwise completely unrelated.
 
+Code that does not care which ordering it uses should name this alias, as it
+selects the ordering with the cheapest accesses on the target. As with both
+named orderings, copies and comparisons between slices that use it are
+accelerated by batched loads, and slices that start at the same index copy and
+compare their fully-spanned elements whole.
+
 [`Lsb0`]: crate::order::Lsb0
 **/
 #[cfg(target_endian = "little")]
@@ -363,6 +369,12 @@ Typically, your platform’s C compiler uses most-significant-bit-first ordering
 for bitfields. The [`Msb0`] bit ordering and big-endian byte ordering are
 otherwise completely unrelated.
 
+Code that does not care which ordering it uses should name this alias, as it
+selects the ordering with the cheapest accesses on the target. As with both
+named orderings, copies and comparisons between slices that use it are
+accelerated by batched loads, and slices that start at the same index copy and
+compare their fully-spanned elements whole.
+
 [`Msb0`]: crate::order::Msb0
 **/
 #[cfg(target_endian = "big")]
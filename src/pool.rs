@@ -0,0 +1,261 @@
+/*! A bitmap slot allocator.
+
+This module provides [`BitPool`], which hands out and takes back indices in a
+fixed range, such as page frames or table slots. It keeps a second, smaller
+bitmap that summarizes which storage elements of the main bitmap are full, so
+that finding a free slot does not need to scan the full part of the map bit by
+bit.
+
+[`BitPool`]: self::BitPool
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	index::BitMask,
+	mem::{
+		self,
+		BitMemory,
+		BitRegister,
+	},
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::fmt::{
+	self,
+	Debug,
+	Formatter,
+};
+
+/** A fixed-size pool of slots, each of which is free or allocated.
+
+The pool uses two bitmaps. The leaf map has one bit per slot, set when the slot
+is allocated. The summary map has one bit per storage element of the leaf map,
+set when every slot in that element is allocated. An allocation scans the
+summary map one element at a time for a leaf element with room, and then takes
+the first free slot in that element. This inspects one summary element for
+every `T::Mem::BITS²` slots that are already allocated, rather than one bit per
+slot, so allocation stays fast even when a very large pool is nearly full.
+Freeing a slot touches one bit in each map.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements.
+- `T`: The storage element type of both maps.
+
+# Examples
+
+```rust
+use bitvec::{
+  order::Lsb0,
+  pool::BitPool,
+};
+
+let mut pool = BitPool::<Lsb0, u8>::new(20);
+assert_eq!(pool.allocate(), Some(0));
+assert_eq!(pool.allocate(), Some(1));
+pool.free(0);
+assert_eq!(pool.allocate(), Some(0));
+
+while pool.allocate().is_some() {}
+assert!(pool.is_full());
+assert_eq!(pool.allocated(), 20);
+```
+**/
+#[derive(Clone)]
+pub struct BitPool<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// One bit per slot, set when the slot is allocated. The bits past `len`
+	/// in the last element are permanently set.
+	leaf: BitVec<O, T>,
+	/// One bit per element of `leaf`, set when the element is full. The bits
+	/// past the number of leaf elements are permanently set.
+	summary: BitVec<O, T>,
+	/// The number of slots in the pool.
+	len: usize,
+	/// The number of slots currently allocated.
+	allocated: usize,
+}
+
+impl<O, T> BitPool<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Creates a pool of `len` slots, all of which are free.
+	///
+	/// # Panics
+	///
+	/// This panics if `len` exceeds the maximum length of a [`BitVec`].
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	pub fn new(len: usize) -> Self {
+		Self {
+			leaf: Self::padded(len),
+			summary: Self::padded(mem::elts::<T>(len)),
+			len,
+			allocated: 0,
+		}
+	}
+
+	/// Counts the slots in the pool.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the pool has no slots at all.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Counts the slots that are currently allocated.
+	pub fn allocated(&self) -> usize {
+		self.allocated
+	}
+
+	/// Tests if every slot in the pool is allocated.
+	pub fn is_full(&self) -> bool {
+		self.allocated == self.len
+	}
+
+	/// Tests if a slot is allocated.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn is_allocated(&self, index: usize) -> bool {
+		self.as_bitslice()[index]
+	}
+
+	/// Views the allocation map, with one bit per slot, set for each slot that
+	/// is allocated.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		&self.leaf[.. self.len]
+	}
+
+	/// Allocates the lowest-numbered free slot.
+	///
+	/// # Returns
+	///
+	/// The index of the newly-allocated slot, or `None` if the pool is full.
+	pub fn allocate(&mut self) -> Option<usize> {
+		let width = T::Mem::BITS as usize;
+		let (outer, summary) = self
+			.summary
+			.as_slice()
+			.iter()
+			.map(BitStore::load_value)
+			.enumerate()
+			.find(|&(_, elem)| elem != T::Mem::ALL)?;
+		let elt = outer * width + first_zero::<O, T::Mem>(summary)?;
+		let leaf = self.leaf.as_slice()[elt].load_value();
+		let index = elt * width + first_zero::<O, T::Mem>(leaf)?;
+
+		self.leaf.set(index, true);
+		if self.leaf.as_slice()[elt].load_value() == T::Mem::ALL {
+			self.summary.set(elt, true);
+		}
+		self.allocated += 1;
+		Some(index)
+	}
+
+	/// Returns a slot to the pool.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than [`self.len()`], or if the slot
+	/// is not allocated.
+	///
+	/// [`self.len()`]: Self::len
+	pub fn free(&mut self, index: usize) {
+		assert!(self.is_allocated(index), "Slot {} is not allocated", index);
+		self.leaf.set(index, false);
+		self.summary.set(index / T::Mem::BITS as usize, false);
+		self.allocated -= 1;
+	}
+
+	/// Creates a bit-vector of `len` cleared bits, whose dead bits in the last
+	/// element are set.
+	fn padded(len: usize) -> BitVec<O, T> {
+		let width = T::Mem::BITS as usize;
+		let mut out = BitVec::repeat(true, mem::elts::<T>(len) * width);
+		out[.. len].set_all(false);
+		out
+	}
+}
+
+impl<O, T> Debug for BitPool<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("BitPool")
+			.field("len", &self.len)
+			.field("allocated", &self.allocated)
+			.finish()
+	}
+}
+
+/// Finds the index of the first cleared bit in a register, according to `O`.
+fn first_zero<O, R>(elem: R) -> Option<usize>
+where
+	O: BitOrder,
+	R: BitRegister,
+{
+	BitMask::new(!elem)
+		.first_index::<O>()
+		.map(|idx| idx.value() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::order::Msb0;
+
+	#[test]
+	fn allocate_free() {
+		let mut pool = BitPool::<Msb0, u8>::new(70);
+		assert_eq!(pool.summary.len(), 16);
+		for n in 0 .. 70 {
+			assert_eq!(pool.allocate(), Some(n));
+		}
+		assert!(pool.is_full());
+		assert_eq!(pool.allocate(), None);
+		assert!(pool.summary[.. 8].all());
+
+		pool.free(66);
+		pool.free(9);
+		pool.free(40);
+		assert_eq!(pool.allocated(), 67);
+		assert!(!pool.summary[1] && !pool.summary[5] && !pool.summary[8]);
+		assert_eq!(pool.allocate(), Some(9));
+		assert_eq!(pool.allocate(), Some(40));
+		assert_eq!(pool.allocate(), Some(66));
+		assert_eq!(pool.allocate(), None);
+		assert!(pool.as_bitslice().all());
+
+		let mut empty = BitPool::<Msb0, u8>::new(0);
+		assert!(empty.is_empty() && empty.is_full());
+		assert_eq!(empty.allocate(), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "Slot 3 is not allocated")]
+	fn double_free() {
+		let mut pool = BitPool::<Lsb0, u16>::new(8);
+		pool.allocate();
+		pool.free(3);
+	}
+}
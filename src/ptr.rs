@@ -15,6 +15,7 @@ for the project.
 use crate::{
 	access::BitAccess,
 	domain::Domain,
+	error::Error,
 	index::{
 		BitIdx,
 		BitTail,
@@ -194,8 +195,9 @@ documentation of [`slice::bits_from_raw_parts`] for slice safety requirements.
 
 # Returns
 
-If the input parameters are valid, this returns a shared reference to a
-[`BitSlice`]. The failure conditions that cause this to return `None` are:
+If the input parameters are valid, this returns a shared pointer to a
+[`BitSlice`]. The failure conditions that cause this to return an [`Error`]
+are:
 
 - `head` is not less than [`T::Mem::BITS`]
 - `bits` is greater than [`BitSlice::MAX_BITS`]
@@ -222,6 +224,7 @@ assert!(unsafe { &*bitptr }.all());
 
 [`BitSlice`]: crate::slice::BitSlice
 [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+[`Error`]: crate::error::Error
 [`T::Mem::BITS`]: crate::mem::BitMemory::BITS
 [`ptr::slice_from_raw_parts`]: core::ptr::slice_from_raw_parts
 [`slice::bits_from_raw_parts`]: crate::slice::bits_from_raw_parts
@@ -230,7 +233,7 @@ pub fn bitslice_from_raw_parts<O, T>(
 	addr: *const T,
 	head: BitIdx<T::Mem>,
 	bits: usize,
-) -> Option<*const BitSlice<O, T>>
+) -> Result<*const BitSlice<O, T>, Error>
 where
 	O: BitOrder,
 	T: BitStore,
@@ -266,8 +269,8 @@ documentation of [`slice::bits_from_raw_parts_mut`] for slice safety requirement
 
 # Returns
 
-If the input parameters are valid, this returns `Some` shared reference to a
-[`BitSlice`]. The failure conditions causing this to return `None` are:
+If the input parameters are valid, this returns a mutable pointer to a
+[`BitSlice`]. The failure conditions causing this to return an [`Error`] are:
 
 - `head` is not less than [`T::Mem::BITS`]
 - `bits` is greater than [`BitSlice::MAX_BITS`]
@@ -295,6 +298,7 @@ assert_eq!(data, 0xF0);
 
 [`BitSlice`]: crate::slice::BitSlice
 [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+[`Error`]: crate::error::Error
 [`T::Mem::BITS`]: crate::mem::BitMemory::BITS
 [`bitslice_from_raw_parts`]: crate::ptr::bitslice_from_raw_parts
 [`slice::bits_from_raw_parts_mut`]: crate::slice::bits_from_raw_parts_mut
@@ -303,7 +307,7 @@ pub fn bitslice_from_raw_parts_mut<O, T>(
 	addr: *mut T,
 	head: BitIdx<T::Mem>,
 	bits: usize,
-) -> Option<*mut BitSlice<O, T>>
+) -> Result<*mut BitSlice<O, T>, Error>
 where
 	O: BitOrder,
 	T: BitStore,
@@ -572,12 +576,12 @@ where
 	///
 	/// # Returns
 	///
-	/// This returns `None` in the following cases:
+	/// This returns an [`Error`] in the following cases:
 	///
 	/// - `addr` is the null pointer, or is not adequately aligned for `T`.
 	/// - `bits` is greater than `Self::REGION_MAX_BITS`, and cannot be encoded
 	///   into a `BitPtr`.
-	/// - addr` is so high in the address space that the element slice wraps
+	/// - `addr` is so high in the address space that the element slice wraps
 	///   around the address space boundary.
 	///
 	/// # Safety
@@ -587,28 +591,42 @@ where
 	/// in the caller’s memory space. The caller is responsible for ensuring
 	/// that the slice of memory the produced `BitPtr<T>` describes is all
 	/// governable in the caller’s context.
+	///
+	/// [`Error`]: crate::error::Error
 	pub(crate) fn new(
 		addr: impl Into<Address<T>>,
 		head: BitIdx<T::Mem>,
 		bits: usize,
-	) -> Option<Self>
+	) -> Result<Self, Error>
 	{
 		let addr = addr.into();
 
-		if addr.to_const().is_null()
-			|| (addr.value().trailing_zeros() as usize) < Self::PTR_HEAD_BITS
-			|| bits > Self::REGION_MAX_BITS
-		{
-			return None;
+		if addr.to_const().is_null() {
+			return Err(Error::Null);
+		}
+		if (addr.value().trailing_zeros() as usize) < Self::PTR_HEAD_BITS {
+			return Err(Error::Misaligned {
+				addr: addr.value(),
+				align: 1 << Self::PTR_HEAD_BITS,
+			});
+		}
+		if bits > Self::REGION_MAX_BITS {
+			return Err(Error::TooLong {
+				len: bits,
+				max: Self::REGION_MAX_BITS,
+			});
 		}
 
 		let elts = head.span(bits).0;
 		let last = addr.to_const().wrapping_add(elts);
 		if last < addr.to_const() {
-			return None;
+			return Err(Error::AddressOverflow {
+				addr: addr.value(),
+				elts,
+			});
 		}
 
-		Some(unsafe { Self::new_unchecked(addr, head, bits) })
+		Ok(unsafe { Self::new_unchecked(addr, head, bits) })
 	}
 
 	/// Creates a new `BitPtr<T>` from its components, without any validity
@@ -1294,6 +1312,11 @@ where
 	/// bit length of the pointer, as well as any additional fields provided by
 	/// the caller.
 	///
+	/// When the alternate flag (`{:#?}`) is set, `{fields}` also includes the
+	/// tail index, the number of elements the region spans, and the full names
+	/// of the ordering and storage type parameters, so that the rendering
+	/// records the complete provenance of the region.
+	///
 	/// Higher types in the crate should use this function to drive their
 	/// [`Debug`] implementations, and then use [`BitSlice`]’s list formatters
 	/// to display their buffer contents.
@@ -1314,16 +1337,39 @@ where
 			any::type_name::<O>(),
 			any::type_name::<T::Mem>()
 		)?;
+		let verbose = fmt.alternate();
 		let mut builder = fmt.debug_struct("");
 		builder
 			.field("addr", &self.pointer().fmt_pointer())
 			.field("head", &self.head().fmt_binary())
 			.field("bits", &self.len());
+		if verbose {
+			builder
+				.field("tail", &self.tail().fmt_binary())
+				.field("elts", &self.elements())
+				.field("order", &any::type_name::<O>())
+				.field("store", &any::type_name::<T>());
+		}
 		for (name, value) in fields {
 			builder.field(name, value);
 		}
 		builder.finish()
 	}
+
+	/// Renders the pointer as an element address and a head-bit index.
+	///
+	/// # Behavior
+	///
+	/// This function writes `{addr:p}[{head}]` into the `fmt` formatter. The
+	/// formatter’s flags apply to the address, as they do for ordinary
+	/// pointers. Higher types in the crate should use this function to drive
+	/// their [`Pointer`] implementations.
+	///
+	/// [`Pointer`]: core::fmt::Pointer
+	pub(crate) fn render_pointer(&self, fmt: &mut Formatter) -> fmt::Result {
+		Pointer::fmt(&self.pointer(), fmt)?;
+		write!(fmt, "[{}]", self.head().value())
+	}
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -1448,6 +1494,24 @@ mod tests {
 		let render = format!("{:#?}", bits);
 		assert!(render.starts_with("BitSlice<bitvec::order::Msb0, u8> {"));
 		assert!(render.ends_with("} [\n    0b0100,\n]"), "{}", render);
+		assert!(render.contains("    head: 000,\n"), "{}", render);
+		assert!(render.contains("    bits: 4,\n"), "{}", render);
+		assert!(render.contains("    tail: 0100,\n"), "{}", render);
+		assert!(render.contains("    elts: 1,\n"), "{}", render);
+		assert!(
+			render.contains("    order: \"bitvec::order::Msb0\",\n"),
+			"{}",
+			render
+		);
+		assert!(render.contains("    store: \"u8\",\n"), "{}", render);
+
+		let bits = bits![Msb0, u16; 0; 40];
+		let bits = &bits[7 .. 30];
+		let render = format!("{:#?}", bits);
+		assert!(render.contains("    head: 0111,\n"), "{}", render);
+		assert!(render.contains("    tail: 01110,\n"), "{}", render);
+		assert!(render.contains("    elts: 2,\n"), "{}", render);
+		assert!(!format!("{:?}", bits).contains("elts"));
 	}
 
 	#[test]
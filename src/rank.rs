@@ -0,0 +1,574 @@
+/*! A dynamic bit-sequence with rank and select queries.
+
+[`BitVec`] answers `rank` (how many `1` bits precede an index) and `select`
+(where is the `n`th `1` bit) only by counting from the front, and its
+`insert` and `remove` move every bit after the edit point. This module provides
+[`DynamicBitVec`], which keeps its bits in short blocks and keeps running
+summaries of the block lengths and `1` counts, so that all of these operations
+cost time proportional to the logarithm of the number of blocks plus the size
+of one block.
+
+[`BitVec`]: crate::vec::BitVec
+[`DynamicBitVec`]: self::DynamicBitVec
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FromIterator,
+};
+
+/// The number of bits that a block is split back down to when it grows past
+/// twice this length. Blocks are merged with a neighbor when their combined
+/// length falls to this value.
+const BLOCK: usize = 1024;
+
+/** A bit-sequence supporting edits, `rank`, and `select` in logarithmic time.
+
+The bits are stored in a list of [`BitVec`] blocks of at most `2 × 1024` bits.
+Two Fenwick trees over the blocks sum their lengths and their `1` counts. Every
+operation first finds its block by descending a tree, and then works inside
+that one block:
+
+- [`.get()`], [`.set()`], [`.rank()`], and [`.select()`] cost *O*(log *m*) for
+  *m* blocks, plus a scan within one block.
+- [`.insert()`] and [`.remove()`] cost the same, except that when a block must
+  be split or merged, the trees are rebuilt in *O*(*m*). This happens at most
+  once per thousand edits to any one block.
+
+# Type Parameters
+
+- `O`: The ordering of bits within the storage elements of each block.
+- `T`: The storage element type of each block.
+
+# Examples
+
+```rust
+use bitvec::{
+  prelude::*,
+  rank::DynamicBitVec,
+};
+
+let mut bits = DynamicBitVec::<Lsb0, usize>::from(bitvec![0, 1, 1, 0, 1]);
+assert_eq!(bits.rank(3), 2);
+assert_eq!(bits.select(2), Some(4));
+
+bits.insert(0, true);
+bits.remove(2);
+assert_eq!(bits.to_bitvec(), bits![1, 0, 1, 0, 1]);
+assert_eq!(bits.rank(bits.len()), 3);
+assert_eq!(bits.select(1), Some(2));
+```
+
+[`BitVec`]: crate::vec::BitVec
+[`.get()`]: Self::get
+[`.insert()`]: Self::insert
+[`.rank()`]: Self::rank
+[`.remove()`]: Self::remove
+[`.select()`]: Self::select
+[`.set()`]: Self::set
+**/
+#[derive(Clone)]
+pub struct DynamicBitVec<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The bits, in order. No block is empty.
+	blocks: Vec<BitVec<O, T>>,
+	/// Sums the lengths of `blocks`.
+	lens: Fenwick,
+	/// Sums the `1` counts of `blocks`.
+	ones: Fenwick,
+	/// The total number of bits.
+	len: usize,
+}
+
+impl<O, T> DynamicBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Constructs an empty sequence.
+	pub fn new() -> Self {
+		Self {
+			blocks: Vec::new(),
+			lens: Fenwick::default(),
+			ones: Fenwick::default(),
+			len: 0,
+		}
+	}
+
+	/// Copies a bit-slice into a new sequence.
+	pub fn from_bitslice<O2, T2>(bits: &BitSlice<O2, T2>) -> Self
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let mut out = Self::new();
+		out.blocks = bits
+			.chunks(BLOCK)
+			.map(|chunk| {
+				let mut block = BitVec::with_capacity(2 * BLOCK);
+				block.extend_from_bitslice(chunk);
+				block
+			})
+			.collect();
+		out.len = bits.len();
+		out.rebuild();
+		out
+	}
+
+	/// Counts the bits in the sequence.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the sequence is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Counts the `1` bits in the sequence.
+	pub fn count_ones(&self) -> usize {
+		self.ones.total()
+	}
+
+	/// Counts the `0` bits in the sequence.
+	pub fn count_zeros(&self) -> usize {
+		self.len - self.count_ones()
+	}
+
+	/// Reads a bit.
+	///
+	/// # Returns
+	///
+	/// The bit at `index`, or `None` if `index` is not less than
+	/// [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn get(&self, index: usize) -> Option<bool> {
+		if index >= self.len {
+			return None;
+		}
+		let (block, offset) = self.lens.search(index);
+		Some(self.blocks[block][offset])
+	}
+
+	/// Writes a bit.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn set(&mut self, index: usize, value: bool) {
+		self.assert_in_bounds(index);
+		let (block, offset) = self.lens.search(index);
+		let old = self.blocks[block][offset];
+		self.blocks[block].set(offset, value);
+		match (old, value) {
+			(false, true) => self.ones.add(block, 1),
+			(true, false) => self.ones.sub(block, 1),
+			_ => {},
+		}
+	}
+
+	/// Inserts a bit, moving all bits after it up by one.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn insert(&mut self, index: usize, value: bool) {
+		assert!(
+			index <= self.len,
+			"Index {} out of bounds: {}",
+			index,
+			self.len
+		);
+		if self.blocks.is_empty() {
+			self.blocks.push(BitVec::with_capacity(2 * BLOCK));
+			self.rebuild();
+		}
+		let (block, offset) = if index == self.len {
+			let last = self.blocks.len() - 1;
+			(last, self.blocks[last].len())
+		}
+		else {
+			self.lens.search(index)
+		};
+
+		self.blocks[block].insert(offset, value);
+		self.len += 1;
+		self.lens.add(block, 1);
+		if value {
+			self.ones.add(block, 1);
+		}
+
+		if self.blocks[block].len() > 2 * BLOCK {
+			let tail = self.blocks[block].split_off(BLOCK);
+			self.blocks.insert(block + 1, tail);
+			self.rebuild();
+		}
+	}
+
+	/// Appends a bit to the end of the sequence.
+	pub fn push(&mut self, value: bool) {
+		self.insert(self.len, value);
+	}
+
+	/// Removes a bit, moving all bits after it down by one.
+	///
+	/// # Returns
+	///
+	/// The removed bit.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is not less than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn remove(&mut self, index: usize) -> bool {
+		self.assert_in_bounds(index);
+		let (block, offset) = self.lens.search(index);
+		let value = self.blocks[block].remove(offset);
+		self.len -= 1;
+		self.lens.sub(block, 1);
+		if value {
+			self.ones.sub(block, 1);
+		}
+
+		let here = self.blocks[block].len();
+		if here == 0 {
+			self.blocks.remove(block);
+			self.rebuild();
+		}
+		else if let Some(next) = self.blocks.get(block + 1) {
+			if here + next.len() <= BLOCK {
+				let mut next = self.blocks.remove(block + 1);
+				self.blocks[block].append(&mut next);
+				self.rebuild();
+			}
+		}
+		value
+	}
+
+	/// Counts the `1` bits before an index.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The end of the counted region. This may be equal to
+	///   [`self.len()`], to count the whole sequence.
+	///
+	/// # Returns
+	///
+	/// The number of `1` bits in `0 .. index`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn rank(&self, index: usize) -> usize {
+		assert!(
+			index <= self.len,
+			"Index {} out of bounds: {}",
+			index,
+			self.len
+		);
+		if index == self.len {
+			return self.count_ones();
+		}
+		let (block, offset) = self.lens.search(index);
+		self.ones.prefix(block) + self.blocks[block][.. offset].count_ones()
+	}
+
+	/// Counts the `0` bits before an index.
+	///
+	/// This is `index - self.rank(index)`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	pub fn rank_zeros(&self, index: usize) -> usize {
+		index - self.rank(index)
+	}
+
+	/// Finds a `1` bit by its rank.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `nth`: The number of `1` bits that precede the sought bit.
+	///
+	/// # Returns
+	///
+	/// The index of the `1` bit with `nth` `1` bits before it, or `None` if the
+	/// sequence has no more than `nth` `1` bits. For any such index `i`,
+	/// `self.rank(i) == nth`.
+	pub fn select(&self, nth: usize) -> Option<usize> {
+		if nth >= self.count_ones() {
+			return None;
+		}
+		let (block, rest) = self.ones.search(nth);
+		let offset = self.blocks[block].iter_ones().nth(rest)?;
+		Some(self.lens.prefix(block) + offset)
+	}
+
+	/// Iterates over the bits in the sequence.
+	pub fn iter(&self) -> impl '_ + Iterator<Item = bool> {
+		self.blocks.iter().flat_map(|block| block.iter().copied())
+	}
+
+	/// Copies the sequence into a contiguous bit-vector.
+	pub fn to_bitvec(&self) -> BitVec<O, T> {
+		let mut out = BitVec::with_capacity(self.len);
+		for block in &self.blocks {
+			out.extend_from_bitslice(block);
+		}
+		out
+	}
+
+	/// Recomputes both summary trees from the blocks.
+	fn rebuild(&mut self) {
+		self.lens = self.blocks.iter().map(|block| block.len()).collect();
+		self.ones = self.blocks.iter().map(|block| block.count_ones()).collect();
+	}
+
+	/// Asserts that `index` is less than [`self.len()`].
+	///
+	/// [`self.len()`]: Self::len
+	fn assert_in_bounds(&self, index: usize) {
+		assert!(
+			index < self.len,
+			"Index {} out of bounds: {}",
+			index,
+			self.len
+		);
+	}
+}
+
+impl<O, T> Debug for DynamicBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<O, T> Default for DynamicBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<O, T> Extend<bool> for DynamicBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = bool> {
+		for bit in iter {
+			self.push(bit);
+		}
+	}
+}
+
+impl<O, T> From<BitVec<O, T>> for DynamicBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bv: BitVec<O, T>) -> Self {
+		Self::from_bitslice(&bv)
+	}
+}
+
+impl<O, T> FromIterator<bool> for DynamicBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = bool> {
+		Self::from_bitslice(&iter.into_iter().collect::<BitVec>())
+	}
+}
+
+/// A Fenwick (binary indexed) tree of counts.
+#[derive(Clone, Debug, Default)]
+struct Fenwick {
+	/// `tree[i - 1]` holds the sum of the counts in `i - lsb(i) .. i`.
+	tree: Vec<usize>,
+}
+
+impl Fenwick {
+	/// Adds `by` to the count at `index`.
+	fn add(&mut self, index: usize, by: usize) {
+		let mut idx = index + 1;
+		while idx <= self.tree.len() {
+			self.tree[idx - 1] += by;
+			idx += idx & idx.wrapping_neg();
+		}
+	}
+
+	/// Subtracts `by` from the count at `index`.
+	fn sub(&mut self, index: usize, by: usize) {
+		let mut idx = index + 1;
+		while idx <= self.tree.len() {
+			self.tree[idx - 1] -= by;
+			idx += idx & idx.wrapping_neg();
+		}
+	}
+
+	/// Sums the counts in `0 .. end`.
+	fn prefix(&self, end: usize) -> usize {
+		let mut sum = 0;
+		let mut idx = end;
+		while idx > 0 {
+			sum += self.tree[idx - 1];
+			idx &= idx - 1;
+		}
+		sum
+	}
+
+	/// Sums all counts.
+	fn total(&self) -> usize {
+		self.prefix(self.tree.len())
+	}
+
+	/// Finds the position whose span of the running sum contains `target`.
+	///
+	/// This returns the least `index` such that `self.prefix(index + 1)` is
+	/// greater than `target`, along with `target - self.prefix(index)`. The
+	/// caller must ensure that `target` is less than `self.total()`.
+	fn search(&self, mut target: usize) -> (usize, usize) {
+		let len = self.tree.len();
+		let mut pos = 0;
+		let mut step = if len == 0 {
+			0
+		}
+		else {
+			1 << (usize::MAX.count_ones() - 1 - len.leading_zeros())
+		};
+		while step > 0 {
+			let next = pos + step;
+			if next <= len && self.tree[next - 1] <= target {
+				pos = next;
+				target -= self.tree[next - 1];
+			}
+			step >>= 1;
+		}
+		(pos, target)
+	}
+}
+
+impl FromIterator<usize> for Fenwick {
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut tree = iter.into_iter().collect::<Vec<_>>();
+		let len = tree.len();
+		for idx in 1 ..= len {
+			let parent = idx + (idx & idx.wrapping_neg());
+			if parent <= len {
+				tree[parent - 1] += tree[idx - 1];
+			}
+		}
+		Self { tree }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		devel::XorShift,
+		order::Msb0,
+	};
+
+	#[test]
+	fn fenwick() {
+		let tree = [3, 0, 5, 1, 0, 2].iter().copied().collect::<Fenwick>();
+		assert_eq!(tree.prefix(0), 0);
+		assert_eq!(tree.prefix(3), 8);
+		assert_eq!(tree.total(), 11);
+		assert_eq!(tree.search(0), (0, 0));
+		assert_eq!(tree.search(2), (0, 2));
+		assert_eq!(tree.search(3), (2, 0));
+		assert_eq!(tree.search(8), (3, 0));
+		assert_eq!(tree.search(10), (5, 1));
+	}
+
+	#[test]
+	fn edits_match_bitvec() {
+		let mut model = BitVec::<Msb0, u16>::new();
+		let mut bits = DynamicBitVec::<Msb0, u16>::new();
+		let mut rng = XorShift::default();
+		let mut next = || rng.next_u32() as usize;
+
+		for _ in 0 .. 6000 {
+			let value = next() % 3 == 0;
+			let index = next() % (model.len() + 1);
+			model.insert(index, value);
+			bits.insert(index, value);
+		}
+		assert!(bits.blocks.len() > 2);
+		for _ in 0 .. 500 {
+			let index = next() % model.len();
+			let value = next() & 1 == 1;
+			model.set(index, value);
+			bits.set(index, value);
+		}
+		for _ in 0 .. 5000 {
+			let index = next() % model.len();
+			assert_eq!(bits.remove(index), model.remove(index));
+		}
+		assert!(bits.blocks.iter().all(|block| !block.is_empty()));
+
+		assert_eq!(bits.len(), model.len());
+		assert_eq!(bits.to_bitvec(), model);
+		for index in 0 ..= model.len() {
+			assert_eq!(bits.rank(index), model[.. index].count_ones());
+		}
+		for (nth, index) in model.iter_ones().enumerate() {
+			assert_eq!(bits.select(nth), Some(index));
+		}
+		assert_eq!(bits.select(model.count_ones()), None);
+
+		while !bits.is_empty() {
+			bits.remove(0);
+		}
+		assert!(bits.blocks.is_empty());
+		bits.push(true);
+		assert_eq!(bits.select(0), Some(0));
+	}
+}
@@ -0,0 +1,560 @@
+/*! Memory-mapped register modeling.
+
+Peripheral registers in embedded devices are single memory elements, subdivided
+into named bitfields that each have a fixed offset and width. This module
+describes such a register as a [`BitArray`] holding exactly one element, with
+fields selected by the [`RegisterField`] trait rather than by ad-hoc shift and
+mask arithmetic.
+
+Fields are always addressed with [`Lsb0`] ordering: the `OFFSET` of a field is
+its distance from the least significant bit of the register, matching the way
+that datasheets number register bits. Field values are transferred with
+[`BitField::load_le`] and [`BitField::store_le`], so the numeric value of a
+field does not depend on the target byte order.
+
+The [`Register`] type is an ordinary value, and can be used as a local shadow
+copy of a hardware register. The [`VolatileRegister`] type is a handle to a
+register in memory, and performs exactly one volatile read or write for each
+whole-register access.
+
+# Examples
+
+```rust
+use bitvec::register::{
+  Register,
+  RegisterField,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+  Input,
+  Output,
+  Alternate,
+}
+
+struct ModeField;
+
+impl RegisterField<u32> for ModeField {
+  type Value = Mode;
+  const OFFSET: usize = 4;
+  const WIDTH: usize = 2;
+
+  fn decode(raw: u32) -> Option<Mode> {
+    match raw {
+      0 => Some(Mode::Input),
+      1 => Some(Mode::Output),
+      2 => Some(Mode::Alternate),
+      _ => None,
+    }
+  }
+
+  fn encode(value: Mode) -> u32 {
+    value as u32
+  }
+}
+
+let mut reg = Register::<u32>::new(0);
+reg.set::<ModeField>(Mode::Alternate);
+assert_eq!(reg.value(), 0b10_0000);
+assert_eq!(reg.get::<ModeField>(), Some(Mode::Alternate));
+
+reg.set_raw::<ModeField>(3);
+assert_eq!(reg.get::<ModeField>(), None);
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitField::load_le`]: crate::field::BitField::load_le
+[`BitField::store_le`]: crate::field::BitField::store_le
+[`Lsb0`]: crate::order::Lsb0
+[`Register`]: self::Register
+[`RegisterField`]: self::RegisterField
+[`VolatileRegister`]: self::VolatileRegister
+!*/
+
+use crate::{
+	array::BitArray,
+	field::BitField,
+	mem::BitRegister,
+	order::Lsb0,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	ptr::{
+		self,
+		NonNull,
+	},
+};
+
+/** Describes a single bitfield within a register of type `R`.
+
+Implementors are usually zero-sized marker types, one per field of a register
+description. The field occupies the bits `OFFSET .. OFFSET + WIDTH` of the
+register, counted from its least significant bit.
+
+# Type Parameters
+
+- `R`: The register type in which the field lives.
+
+# Enumerated Fields
+
+Fields whose raw values map onto an enumeration can reject reserved encodings
+by returning `None` from [`decode`]. Fields that hold plain integers should set
+`Value = R` and always return `Some`.
+
+[`decode`]: Self::decode
+**/
+pub trait RegisterField<R>
+where R: BitRegister {
+	/// The typed value that the field holds.
+	type Value;
+
+	/// The distance of the field from the least significant bit of `R`.
+	const OFFSET: usize;
+
+	/// The number of bits in the field. This must be at least one.
+	const WIDTH: usize;
+
+	/// Converts the raw contents of the field into its typed value.
+	///
+	/// # Parameters
+	///
+	/// - `raw`: The field bits, shifted down to the least significant edge of
+	///   the register and zero-extended.
+	///
+	/// # Returns
+	///
+	/// The typed value encoded by `raw`, or `None` if `raw` is not a valid
+	/// encoding for this field.
+	fn decode(raw: R) -> Option<Self::Value>;
+
+	/// Converts a typed value into the raw contents of the field.
+	///
+	/// Only the low `WIDTH` bits of the returned value are written into the
+	/// register; any higher bits are discarded.
+	fn encode(value: Self::Value) -> R;
+}
+
+/** A register value, with typed access to its bitfields.
+
+This is a newtype over a one-element [`BitArray`] in [`Lsb0`] order. It can be
+freely copied, and exposes its [`BitSlice`] view through [`.as_bitslice()`] for
+any access that the field interface does not cover.
+
+# Type Parameters
+
+- `R`: The register type. This defaults to `u32`, which is the most common
+  width of peripheral registers.
+
+[`BitArray`]: crate::array::BitArray
+[`BitSlice`]: crate::slice::BitSlice
+[`Lsb0`]: crate::order::Lsb0
+[`.as_bitslice()`]: Self::as_bitslice
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct Register<R = u32>
+where R: BitRegister + BitStore
+{
+	bits: BitArray<Lsb0, [R; 1]>,
+}
+
+impl<R> Register<R>
+where R: BitRegister + BitStore
+{
+	/// Wraps a raw register value.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::register::Register;
+	///
+	/// let reg = Register::new(0x8000_0001u32);
+	/// assert!(reg.as_bitslice()[0]);
+	/// assert!(reg.as_bitslice()[31]);
+	/// ```
+	pub fn new(value: R) -> Self {
+		Self {
+			bits: BitArray::new([value]),
+		}
+	}
+
+	/// Removes the `Register` wrapper, leaving the raw register value.
+	pub fn value(self) -> R {
+		self.bits.value()[0]
+	}
+
+	/// Views the register as a [`BitArray`].
+	///
+	/// [`BitArray`]: crate::array::BitArray
+	pub fn as_bitarray(&self) -> &BitArray<Lsb0, [R; 1]> {
+		&self.bits
+	}
+
+	/// Mutably views the register as a [`BitArray`].
+	///
+	/// [`BitArray`]: crate::array::BitArray
+	pub fn as_mut_bitarray(&mut self) -> &mut BitArray<Lsb0, [R; 1]> {
+		&mut self.bits
+	}
+
+	/// Views the register as a [`BitSlice`].
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_bitslice(&self) -> &BitSlice<Lsb0, R> {
+		self.bits.as_bitslice()
+	}
+
+	/// Views the register as a mutable [`BitSlice`].
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<Lsb0, R> {
+		self.bits.as_mut_bitslice()
+	}
+
+	/// Views the bits of a single field.
+	///
+	/// # Type Parameters
+	///
+	/// - `F`: The field to select.
+	///
+	/// # Panics
+	///
+	/// This panics if `F` is empty or does not fit within `R`.
+	pub fn field<F>(&self) -> &BitSlice<Lsb0, R>
+	where F: RegisterField<R> {
+		let (start, end) = span::<R, F>();
+		&self.as_bitslice()[start .. end]
+	}
+
+	/// Mutably views the bits of a single field.
+	///
+	/// # Type Parameters
+	///
+	/// - `F`: The field to select.
+	///
+	/// # Panics
+	///
+	/// This panics if `F` is empty or does not fit within `R`.
+	pub fn field_mut<F>(&mut self) -> &mut BitSlice<Lsb0, R>
+	where F: RegisterField<R> {
+		let (start, end) = span::<R, F>();
+		&mut self.as_mut_bitslice()[start .. end]
+	}
+
+	/// Reads the raw contents of a field, without decoding them.
+	///
+	/// # Returns
+	///
+	/// The bits of the field, shifted down to the least significant edge.
+	pub fn get_raw<F>(&self) -> R
+	where F: RegisterField<R> {
+		self.field::<F>().load_le::<R>()
+	}
+
+	/// Reads and decodes a field.
+	///
+	/// # Returns
+	///
+	/// The decoded field value, or `None` if the field contains an encoding
+	/// that [`F::decode`] rejects.
+	///
+	/// [`F::decode`]: crate::register::RegisterField::decode
+	pub fn get<F>(&self) -> Option<F::Value>
+	where F: RegisterField<R> {
+		F::decode(self.get_raw::<F>())
+	}
+
+	/// Writes raw bits into a field, without encoding them.
+	///
+	/// Only the low `F::WIDTH` bits of `raw` are written.
+	pub fn set_raw<F>(&mut self, raw: R)
+	where F: RegisterField<R> {
+		self.field_mut::<F>().store_le::<R>(raw);
+	}
+
+	/// Encodes and writes a value into a field.
+	///
+	/// All other fields of the register are left unchanged.
+	pub fn set<F>(&mut self, value: F::Value)
+	where F: RegisterField<R> {
+		self.set_raw::<F>(F::encode(value));
+	}
+
+	/// Writes a field, and returns the modified register.
+	///
+	/// This is a by-value form of [`.set()`], for building a register value
+	/// from a chain of field writes.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::register::{Register, RegisterField};
+	///
+	/// struct Low;
+	/// impl RegisterField<u8> for Low {
+	///   type Value = u8;
+	///   const OFFSET: usize = 0;
+	///   const WIDTH: usize = 4;
+	///   fn decode(raw: u8) -> Option<u8> { Some(raw) }
+	///   fn encode(value: u8) -> u8 { value }
+	/// }
+	///
+	/// struct High;
+	/// impl RegisterField<u8> for High {
+	///   type Value = u8;
+	///   const OFFSET: usize = 4;
+	///   const WIDTH: usize = 4;
+	///   fn decode(raw: u8) -> Option<u8> { Some(raw) }
+	///   fn encode(value: u8) -> u8 { value }
+	/// }
+	///
+	/// let reg = Register::new(0u8).with::<Low>(0xA).with::<High>(0x5);
+	/// assert_eq!(reg.value(), 0x5A);
+	/// ```
+	///
+	/// [`.set()`]: Self::set
+	pub fn with<F>(mut self, value: F::Value) -> Self
+	where F: RegisterField<R> {
+		self.set::<F>(value);
+		self
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<R> Debug for Register<R>
+where R: BitRegister + BitStore
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("Register").field(&self.bits).finish()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<R> From<R> for Register<R>
+where R: BitRegister + BitStore
+{
+	fn from(value: R) -> Self {
+		Self::new(value)
+	}
+}
+
+/** A handle to a register in device memory.
+
+All reads and writes through this handle are volatile, and transfer the entire
+register at once. Field-level modification is done by reading the register into
+a local [`Register`] shadow, editing it, and writing it back with
+[`.modify()`].
+
+# Type Parameters
+
+- `R`: The register type.
+
+[`Register`]: self::Register
+[`.modify()`]: Self::modify
+**/
+pub struct VolatileRegister<R = u32>
+where R: BitRegister + BitStore
+{
+	addr: NonNull<R>,
+}
+
+impl<R> VolatileRegister<R>
+where R: BitRegister + BitStore
+{
+	/// Creates a handle to a register at some address.
+	///
+	/// # Parameters
+	///
+	/// - `addr`: The address of the register.
+	///
+	/// # Returns
+	///
+	/// A handle to the register, if `addr` is non-null.
+	///
+	/// # Safety
+	///
+	/// `addr` must be well-aligned for `R`, and valid for volatile reads and
+	/// writes for as long as the handle, or any copy of it, is used.
+	pub unsafe fn new(addr: *mut R) -> Option<Self> {
+		NonNull::new(addr).map(|addr| Self { addr })
+	}
+
+	/// Gets the address of the register.
+	pub fn as_ptr(&self) -> *mut R {
+		self.addr.as_ptr()
+	}
+
+	/// Performs a volatile read of the register.
+	pub fn read(&self) -> Register<R> {
+		Register::new(unsafe { ptr::read_volatile(self.addr.as_ptr()) })
+	}
+
+	/// Performs a volatile write of the register.
+	pub fn write(&mut self, value: Register<R>) {
+		unsafe {
+			ptr::write_volatile(self.addr.as_ptr(), value.value());
+		}
+	}
+
+	/// Reads, modifies, and writes back the register.
+	///
+	/// This performs exactly one volatile read and one volatile write. It is
+	/// not atomic with respect to other bus masters.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::register::VolatileRegister;
+	///
+	/// let mut hw = 0u16;
+	/// let mut reg = unsafe { VolatileRegister::new(&mut hw) }.unwrap();
+	/// reg.modify(|r| r.as_mut_bitslice().set(3, true));
+	/// assert_eq!(reg.read().value(), 8);
+	/// ```
+	pub fn modify<F>(&mut self, func: F)
+	where F: FnOnce(&mut Register<R>) {
+		let mut value = self.read();
+		func(&mut value);
+		self.write(value);
+	}
+
+	/// Reads and decodes a single field.
+	pub fn get<F>(&self) -> Option<F::Value>
+	where F: RegisterField<R> {
+		self.read().get::<F>()
+	}
+
+	/// Encodes and writes a single field, preserving the others.
+	pub fn set<F>(&mut self, value: F::Value)
+	where F: RegisterField<R> {
+		self.modify(|reg| reg.set::<F>(value));
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<R> Debug for VolatileRegister<R>
+where R: BitRegister + BitStore
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("VolatileRegister")
+			.field("addr", &self.addr)
+			.finish()
+	}
+}
+
+/// Computes the bit range of a field, and checks that it fits its register.
+///
+/// # Panics
+///
+/// This panics if the field is empty or extends past the end of `R`.
+fn span<R, F>() -> (usize, usize)
+where
+	R: BitRegister,
+	F: RegisterField<R>,
+{
+	let (start, width) = (F::OFFSET, F::WIDTH);
+	let end = start.saturating_add(width);
+	if width == 0 || end > R::BITS as usize {
+		panic!(
+			"Field at {} with width {} does not fit in a {}-bit register",
+			start,
+			width,
+			R::BITS,
+		);
+	}
+	(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct Flag;
+	impl RegisterField<u32> for Flag {
+		type Value = bool;
+
+		const OFFSET: usize = 31;
+		const WIDTH: usize = 1;
+
+		fn decode(raw: u32) -> Option<bool> {
+			Some(raw != 0)
+		}
+
+		fn encode(value: bool) -> u32 {
+			value as u32
+		}
+	}
+
+	struct Count;
+	impl RegisterField<u32> for Count {
+		type Value = u32;
+
+		const OFFSET: usize = 8;
+		const WIDTH: usize = 12;
+
+		fn decode(raw: u32) -> Option<u32> {
+			Some(raw)
+		}
+
+		fn encode(value: u32) -> u32 {
+			value
+		}
+	}
+
+	struct TooWide;
+	impl RegisterField<u32> for TooWide {
+		type Value = u32;
+
+		const OFFSET: usize = 24;
+		const WIDTH: usize = 9;
+
+		fn decode(raw: u32) -> Option<u32> {
+			Some(raw)
+		}
+
+		fn encode(value: u32) -> u32 {
+			value
+		}
+	}
+
+	#[test]
+	fn fields() {
+		let mut reg = Register::<u32>::default();
+		reg.set::<Count>(0xABC);
+		reg.set::<Flag>(true);
+		assert_eq!(reg.value(), 0x800A_BC00);
+		assert_eq!(reg.get::<Count>(), Some(0xABC));
+		assert_eq!(reg.get::<Flag>(), Some(true));
+
+		//  Writes are truncated to the field width.
+		reg.set::<Count>(!0);
+		assert_eq!(reg.value(), 0x800F_FF00);
+
+		reg.set::<Flag>(false);
+		assert_eq!(reg.get_raw::<Flag>(), 0);
+		assert_eq!(reg.field::<Count>().count_ones(), 12);
+	}
+
+	#[test]
+	fn volatile() {
+		let mut hw = 0x0000_1200u32;
+		let mut reg = unsafe { VolatileRegister::new(&mut hw) }.unwrap();
+		assert_eq!(reg.get::<Count>(), Some(0x12));
+		reg.set::<Flag>(true);
+		assert_eq!(reg.read().value(), 0x8000_1200);
+		assert!(unsafe { VolatileRegister::<u32>::new(ptr::null_mut()) }.is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		Register::<u32>::default().get_raw::<TooWide>();
+	}
+}
@@ -0,0 +1,180 @@
+/*! [`roaring`] bitmap interoperability.
+
+[`RoaringBitmap`] stores a set of `u32` values in compressed containers, and is
+a common format for persisting and exchanging large sparse or run-heavy sets.
+This module converts between it and the `bitvec` types by treating each `1` bit
+as a member of the set, at the value of its index.
+
+- A [`BitSlice`] or [`BitVec`] converts into a [`RoaringBitmap`] through
+  [`TryFrom`], which fails if any `1` bit has an index that does not fit in a
+  `u32`.
+- A [`RoaringBitmap`] converts into a [`BitVec`] through [`From`]. The
+  bit-vector is just long enough to hold the greatest member of the set.
+- [`BitSlice::try_iter_ones_u32`] yields the indices of the `1` bits as `u32`
+  values, so that they can be fed into any of the bitmap’s bulk-insertion
+  methods without first collecting a new bitmap.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`BitSlice::try_iter_ones_u32`]: crate::slice::BitSlice::try_iter_ones_u32
+[`BitVec`]: crate::vec::BitVec
+[`From`]: core::convert::From
+[`RoaringBitmap`]: roaring::RoaringBitmap
+[`TryFrom`]: core::convert::TryFrom
+[`roaring`]: roaring
+!*/
+
+#![cfg(all(feature = "roaring", feature = "alloc"))]
+
+use crate::{
+	error::Error,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use core::convert::TryFrom;
+
+use roaring::RoaringBitmap;
+
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Iterates over the indices of the `1` bits as `u32` values.
+	///
+	/// This is [`.iter_ones()`] narrowed to the index type of
+	/// [`RoaringBitmap`]. The indices are yielded in increasing order, so they
+	/// are accepted by [`RoaringBitmap::append`] and
+	/// [`RoaringBitmap::from_sorted_iter`].
+	///
+	/// # Errors
+	///
+	/// This checks the whole slice before yielding anything, and fails with
+	/// [`Error::IndexOverflow`] if any `1` bit has an index greater than
+	/// `u32::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use roaring::RoaringBitmap;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 1];
+	/// let mut set = RoaringBitmap::new();
+	/// set.insert(2);
+	/// set.extend(bits.try_iter_ones_u32().unwrap());
+	/// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2, 4, 5]);
+	/// ```
+	///
+	/// [`Error::IndexOverflow`]: crate::error::Error::IndexOverflow
+	/// [`RoaringBitmap`]: roaring::RoaringBitmap
+	/// [`RoaringBitmap::append`]: roaring::RoaringBitmap::append
+	/// [`RoaringBitmap::from_sorted_iter`]: roaring::RoaringBitmap::from_sorted_iter
+	/// [`.iter_ones()`]: Self::iter_ones
+	pub fn try_iter_ones_u32(
+		&self,
+	) -> Result<impl '_ + Iterator<Item = u32>, Error> {
+		let max = u32::MAX as usize;
+		match self.last_one() {
+			Some(index) if index > max => {
+				Err(Error::IndexOverflow { index, max })
+			},
+			_ => Ok(self.iter_ones().map(|index| index as u32)),
+		}
+	}
+}
+
+impl<O, T> TryFrom<&BitSlice<O, T>> for RoaringBitmap
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Error = Error;
+
+	fn try_from(bits: &BitSlice<O, T>) -> Result<Self, Self::Error> {
+		let ones = bits.try_iter_ones_u32()?;
+		Ok(Self::from_sorted_iter(ones)
+			.expect("`iter_ones` yields indices in increasing order"))
+	}
+}
+
+impl<O, T> TryFrom<&BitVec<O, T>> for RoaringBitmap
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Error = Error;
+
+	fn try_from(bv: &BitVec<O, T>) -> Result<Self, Self::Error> {
+		Self::try_from(bv.as_bitslice())
+	}
+}
+
+impl<O, T> From<&RoaringBitmap> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Sets the bit at the index of each member of the set.
+	///
+	/// The bit-vector’s length is one more than the greatest member, or zero
+	/// if the set is empty.
+	///
+	/// # Panics
+	///
+	/// This panics if the greatest member is not less than
+	/// [`BitSlice::MAX_BITS`], which is only possible on targets with 32-bit
+	/// pointers.
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	fn from(set: &RoaringBitmap) -> Self {
+		let len = set.max().map_or(0, |max| max as usize + 1);
+		let mut out = Self::repeat(false, len);
+		for index in set {
+			unsafe {
+				out.set_unchecked(index as usize, true);
+			}
+		}
+		out
+	}
+}
+
+impl<O, T> From<RoaringBitmap> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(set: RoaringBitmap) -> Self {
+		Self::from(&set)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn round_trip() {
+		let mut bv = bitvec![Msb0, u16; 0; 300];
+		for &index in &[3, 17, 18, 19, 150, 298] {
+			bv.set(index, true);
+		}
+		let set = RoaringBitmap::try_from(&bv).unwrap();
+		assert_eq!(set.len(), 6);
+		assert_eq!(set.iter().collect::<Vec<_>>(), [3, 17, 18, 19, 150, 298]);
+
+		let back = BitVec::<Msb0, u16>::from(&set);
+		assert_eq!(back.len(), 299);
+		assert_eq!(back, bv[.. 299]);
+
+		let partial = RoaringBitmap::try_from(&bv[5 .. 160]).unwrap();
+		assert_eq!(partial.iter().collect::<Vec<_>>(), [12, 13, 14, 145]);
+
+		assert!(BitVec::<Lsb0, u8>::from(RoaringBitmap::new()).is_empty());
+		let zeros = RoaringBitmap::try_from(bits![0; 40]).unwrap();
+		assert!(zeros.is_empty());
+	}
+}
@@ -10,11 +10,20 @@ bare value sequences.
 
 [`BitSlice`] is able to implement [`Serialize`], but [`serde`] does not provide
 a behavior to deserialize data into a buffer provided by the calling context, so
-it cannot deserialize into any of the owning structures.
+it cannot deserialize into any of the owning structures. A `&BitSlice<O, u8>`
+can, however, be deserialized without copying, by borrowing the byte string in
+which it was serialized, from formats that are able to lend their input.
 
 [`BitBox`] and [`BitVec`] implement [`Serialize`] through [`BitSlice`], and can
 deserialize the [`BitSlice`] format into themselves.
 
+When the memory elements are `u8`, the `data` field of the [`BitSlice`] format
+is written as a byte string, rather than as a sequence of integers. Formats with
+a native byte-string type, such as bincode and CBOR, store this compactly;
+formats without one, such as JSON, write the same sequence of numbers that they
+do for other element types. The owning structures accept either form when they
+deserialize.
+
 If you require de/serialization compatibility between [`BitArray`] and the other
 structures, please file an issue.
 
@@ -33,8 +42,11 @@ structures, please file an issue.
 use crate::{
 	array::BitArray,
 	domain::Domain,
-	index::BitIdxErr,
-	mem::BitMemory,
+	index::{
+		BitIdx,
+		BitIdxErr,
+	},
+	mem::BitRegister,
 	order::BitOrder,
 	ptr::BitPtr,
 	slice::BitSlice,
@@ -43,6 +55,7 @@ use crate::{
 };
 
 use core::{
+	any::TypeId,
 	cmp,
 	convert::TryInto,
 	fmt::{
@@ -58,6 +71,7 @@ use serde::{
 		self,
 		Deserialize,
 		Deserializer,
+		Expected,
 		MapAccess,
 		SeqAccess,
 		Unexpected,
@@ -79,6 +93,9 @@ use crate::{
 	vec::BitVec,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 impl<O, T> Serialize for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -92,12 +109,75 @@ where
 
 		state.serialize_field("head", &head.value())?;
 		state.serialize_field("bits", &(self.len() as u64))?;
-		state.serialize_field("data", &self.domain())?;
+		if TypeId::of::<T::Mem>() == TypeId::of::<u8>() {
+			state.serialize_field("data", &Bytes(self))?;
+		}
+		else {
+			state.serialize_field("data", &self.domain())?;
+		}
 
 		state.end()
 	}
 }
 
+/** Serializes the memory under a `u8`-backed bit-slice as a byte string.
+
+This is only constructed when `T::Mem` is `u8`.
+**/
+struct Bytes<'a, O, T>(&'a BitSlice<O, T>)
+where
+	O: BitOrder,
+	T: BitStore;
+
+impl<O, T> Serialize for Bytes<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	T::Mem: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where S: Serializer {
+		//  Only the unaliased store can be viewed as plain bytes. The others
+		//  permit shared mutation, so their elements must be loaded one by one.
+		if TypeId::of::<T>() == TypeId::of::<u8>() {
+			let elts = self.0.as_slice();
+			let bytes = unsafe { &*(elts as *const [T] as *const [u8]) };
+			return serializer.serialize_bytes(bytes);
+		}
+		serialize_loaded_bytes(self.0.domain(), serializer)
+	}
+}
+
+/// Copies the elements of an aliased `u8` region out into a byte string.
+#[cfg(feature = "alloc")]
+fn serialize_loaded_bytes<T, S>(
+	domain: Domain<T>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	T: BitStore,
+	S: Serializer,
+{
+	let bytes = domain
+		.map(|elem| unsafe { core::mem::transmute_copy::<T::Mem, u8>(&elem) })
+		.collect::<Vec<u8>>();
+	serializer.serialize_bytes(&bytes)
+}
+
+/// Without an allocator, an aliased `u8` region is written as a sequence.
+#[cfg(not(feature = "alloc"))]
+fn serialize_loaded_bytes<T, S>(
+	domain: Domain<T>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	T: BitStore,
+	T::Mem: Serialize,
+	S: Serializer,
+{
+	domain.serialize(serializer)
+}
+
 impl<T> Serialize for Domain<'_, T>
 where
 	T: BitStore,
@@ -169,6 +249,89 @@ where
 	}
 }
 
+impl<'de, 'a, O> Deserialize<'de> for &'a BitSlice<O, u8>
+where
+	'de: 'a,
+	O: BitOrder,
+{
+	/// Borrows a bit-slice from the byte string in the deserializer’s input.
+	///
+	/// This only succeeds with formats that can lend out their input, such as
+	/// bincode reading from a byte slice, and only for data that was written
+	/// with `u8` elements.
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct("BitSeq", FIELDS, BitSliceVisitor {
+			_ref: PhantomData,
+		})
+	}
+}
+
+/// The field names of the `BitSeq` format.
+const FIELDS: &[&str] = &["head", "bits", "data"];
+
+/** Aid for deserializing a protocol into a borrowed [`BitSlice`].
+
+[`BitSlice`]: crate::slice::BitSlice
+**/
+struct BitSliceVisitor<'a, O>
+where O: BitOrder
+{
+	_ref: PhantomData<&'a BitSlice<O, u8>>,
+}
+
+impl<'a, O> BitSliceVisitor<'a, O>
+where O: BitOrder
+{
+	/// Constructs a bit-slice over deserialized components.
+	///
+	/// This fails if `head` is invalid. A `bits` counter longer than `data`
+	/// is shortened to fit.
+	fn assemble<E>(
+		&self,
+		head: u8,
+		bits: u64,
+		data: &'a [u8],
+	) -> Result<&'a BitSlice<O, u8>, E>
+	where
+		E: de::Error,
+	{
+		let head = decode_head(head)?;
+		BitPtr::new(data.as_ptr(), head, clamp_bits(head, bits, data.len()))
+			.map(BitPtr::to_bitslice_ref)
+			.map_err(|_| {
+				de::Error::invalid_value(
+					Unexpected::Other("invalid bit-region source data"),
+					self,
+				)
+			})
+	}
+}
+
+impl<'de, 'a, O> Visitor<'de> for BitSliceVisitor<'a, O>
+where
+	'de: 'a,
+	O: BitOrder,
+{
+	type Value = &'a BitSlice<O, u8>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a BitSeq data series borrowing its data")
+	}
+
+	fn visit_seq<V>(self, seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let (head, bits, data) = seq_fields(seq, &self)?;
+		self.assemble(head, bits, data)
+	}
+
+	fn visit_map<V>(self, map: V) -> Result<Self::Value, V::Error>
+	where V: MapAccess<'de> {
+		let (head, bits, data) = map_fields(map)?;
+		self.assemble(head, bits, data)
+	}
+}
+
 /** Aid for deserializing a protocol into a [`BitVec`].
 
 [`BitVec`]: crate::vec::BitVec
@@ -214,7 +377,7 @@ where
 	fn assemble<E>(
 		&self,
 		head: u8,
-		bits: usize,
+		bits: u64,
 		data: Vec<T>,
 	) -> Result<<Self as Visitor<'de>>::Value, E>
 	where
@@ -222,23 +385,16 @@ where
 	{
 		//  Disable the destructor on the deserialized buffer
 		let data = ManuallyDrop::new(data);
+		let head = decode_head(head)?;
 		//  Assemble a region pointer
 		BitPtr::new(
 			data.as_ptr() as *mut T,
-			//  Attempt to read the `head` index as a `BitIdx` bounded by the
-			//  destination type.
-			head.try_into().map_err(|val: BitIdxErr<_>| {
-				de::Error::invalid_value(
-					Unexpected::Unsigned(val.value() as u64),
-					&"a head-bit index less than the deserialized element \
-					  type’s bit width",
-				)
-			})?,
+			head,
 			//  Ensure that the `bits` counter is not lying about the data size.
-			cmp::min(bits, data.len().saturating_mul(T::Mem::BITS as usize)),
+			clamp_bits(head, bits, data.len()),
 		)
 		//  Fail if the source cannot be encoded into a bit pointer.
-		.ok_or_else(|| {
+		.map_err(|_| {
 			de::Error::invalid_value(
 				Unexpected::Other("invalid bit-region source data"),
 				self,
@@ -263,66 +419,182 @@ where
 
 	/// Visit a sequence of anonymous data elements. These must be in the order
 	/// `u8` (head-bit index), `u64` (length counter), `[T]` (data contents).
-	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	fn visit_seq<V>(self, seq: V) -> Result<Self::Value, V::Error>
 	where V: SeqAccess<'de> {
-		let head = seq
-			.next_element::<u8>()?
-			.ok_or_else(|| de::Error::invalid_length(0, &self))?;
-		let bits = seq
-			.next_element::<u64>()?
-			.ok_or_else(|| de::Error::invalid_length(1, &self))?;
-		let data = seq
-			.next_element::<Vec<T>>()?
-			.ok_or_else(|| de::Error::invalid_length(2, &self))?;
-
-		self.assemble(head, bits as usize, data)
+		let (head, bits, Elements(data)) = seq_fields(seq, &self)?;
+		self.assemble(head, bits, data)
 	}
 
 	/// Visit a map of named data elements. These may be in any order, and must
 	/// be the pairs `head: u8`, `bits: u64`, and `data: [T]`.
-	fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+	fn visit_map<V>(self, map: V) -> Result<Self::Value, V::Error>
 	where V: MapAccess<'de> {
-		let mut head: Option<u8> = None;
-		let mut bits: Option<u64> = None;
-		let mut data: Option<Vec<T>> = None;
-
-		while let Some(key) = map.next_key()? {
-			match key {
-				"head" => {
-					if head.replace(map.next_value()?).is_some() {
-						return Err(de::Error::duplicate_field("head"));
-					}
-				},
-				"bits" => {
-					if bits.replace(map.next_value()?).is_some() {
-						return Err(de::Error::duplicate_field("bits"));
-					}
-				},
-				"data" => {
-					if data.replace(map.next_value()?).is_some() {
-						return Err(de::Error::duplicate_field("data"));
-					}
-				},
-				f => {
-					/* Once a key is pulled from the map, a value **must** also
-					be pulled, otherwise `serde` will fail with its own error
-					rather than this one.
-					*/
-					let _ = map.next_value::<()>();
-					return Err(de::Error::unknown_field(f, &[
-						"head", "bits", "data",
-					]));
-				},
-			}
+		let (head, bits, Elements(data)) = map_fields(map)?;
+		self.assemble(head, bits, data)
+	}
+}
+
+/** The `data` field of a `BitSeq`, deserialized into an owned buffer.
+
+This accepts a sequence of elements, or, when `T::Mem` is `u8`, a byte string.
+**/
+#[cfg(feature = "alloc")]
+struct Elements<T>(Vec<T>);
+
+#[cfg(feature = "alloc")]
+impl<'de, T> Deserialize<'de> for Elements<T>
+where T: BitStore + Deserialize<'de>
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		let visitor = ElementsVisitor { _ty: PhantomData };
+		if TypeId::of::<T::Mem>() == TypeId::of::<u8>() {
+			deserializer.deserialize_bytes(visitor)
+		}
+		else {
+			deserializer.deserialize_seq(visitor)
 		}
-		let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
-		let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
-		let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+		.map(Elements)
+	}
+}
+
+/// Aid for deserializing the `data` field of a `BitSeq`.
+#[cfg(feature = "alloc")]
+struct ElementsVisitor<T> {
+	_ty: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> Visitor<'de> for ElementsVisitor<T>
+where T: BitStore + Deserialize<'de>
+{
+	type Value = Vec<T>;
 
-		self.assemble(head, bits as usize, data)
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a sequence of memory elements")
+	}
+
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		//  Do not trust the size hint with more than a page of memory.
+		let mut out = Vec::with_capacity(cmp::min(
+			seq.size_hint().unwrap_or(0),
+			4096 / core::mem::size_of::<T>(),
+		));
+		while let Some(elem) = seq.next_element()? {
+			out.push(elem);
+		}
+		Ok(out)
+	}
+
+	fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+	where E: de::Error {
+		if TypeId::of::<T::Mem>() != TypeId::of::<u8>() {
+			return Err(de::Error::invalid_type(
+				Unexpected::Bytes(bytes),
+				&self,
+			));
+		}
+		//  Every store whose register is `u8` has the layout of `u8`.
+		Ok(bytes
+			.iter()
+			.map(|byte| unsafe { core::mem::transmute_copy::<u8, T>(byte) })
+			.collect())
 	}
 }
 
+/// Reads the `head`, `bits`, and `data` fields of a `BitSeq`, in that order,
+/// from a sequence.
+fn seq_fields<'de, V, D>(
+	mut seq: V,
+	expected: &dyn Expected,
+) -> Result<(u8, u64, D), V::Error>
+where
+	V: SeqAccess<'de>,
+	D: Deserialize<'de>,
+{
+	let head = seq
+		.next_element::<u8>()?
+		.ok_or_else(|| de::Error::invalid_length(0, expected))?;
+	let bits = seq
+		.next_element::<u64>()?
+		.ok_or_else(|| de::Error::invalid_length(1, expected))?;
+	let data = seq
+		.next_element::<D>()?
+		.ok_or_else(|| de::Error::invalid_length(2, expected))?;
+	Ok((head, bits, data))
+}
+
+/// Reads the `head`, `bits`, and `data` fields of a `BitSeq`, in any order,
+/// from a map.
+fn map_fields<'de, V, D>(mut map: V) -> Result<(u8, u64, D), V::Error>
+where
+	V: MapAccess<'de>,
+	D: Deserialize<'de>,
+{
+	let mut head: Option<u8> = None;
+	let mut bits: Option<u64> = None;
+	let mut data: Option<D> = None;
+
+	while let Some(key) = map.next_key()? {
+		match key {
+			"head" => {
+				if head.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("head"));
+				}
+			},
+			"bits" => {
+				if bits.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("bits"));
+				}
+			},
+			"data" => {
+				if data.replace(map.next_value()?).is_some() {
+					return Err(de::Error::duplicate_field("data"));
+				}
+			},
+			f => {
+				/* Once a key is pulled from the map, a value **must** also be
+				pulled, otherwise `serde` will fail with its own error rather
+				than this one.
+				*/
+				let _ = map.next_value::<()>();
+				return Err(de::Error::unknown_field(f, FIELDS));
+			},
+		}
+	}
+	let head = head.ok_or_else(|| de::Error::missing_field("head"))?;
+	let bits = bits.ok_or_else(|| de::Error::missing_field("bits"))?;
+	let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+	Ok((head, bits, data))
+}
+
+/// Reads a deserialized head-bit index as a `BitIdx` bounded by the
+/// destination type.
+fn decode_head<R, E>(head: u8) -> Result<BitIdx<R>, E>
+where
+	R: BitRegister,
+	E: de::Error,
+{
+	head.try_into().map_err(|val: BitIdxErr<_>| {
+		de::Error::invalid_value(
+			Unexpected::Unsigned(val.value() as u64),
+			&"a head-bit index less than the deserialized element type’s bit \
+			  width",
+		)
+	})
+}
+
+/// Limits a deserialized length counter to the bits that `elts` memory
+/// elements hold after `head`.
+fn clamp_bits<R>(head: BitIdx<R>, bits: u64, elts: usize) -> usize
+where R: BitRegister {
+	let avail = elts
+		.saturating_mul(R::BITS as usize)
+		.saturating_sub(head.value() as usize);
+	cmp::min(bits, avail as u64) as usize
+}
+
 #[cfg(feature = "alloc")]
 impl<'de, O, T> Deserialize<'de> for BitBox<O, T>
 where
@@ -345,11 +617,7 @@ where
 {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer.deserialize_struct(
-			"BitSeq",
-			&["head", "bits", "data"],
-			BitVecVisitor::THIS,
-		)
+		deserializer.deserialize_struct("BitSeq", FIELDS, BitVecVisitor::THIS)
 	}
 }
 
@@ -360,14 +628,10 @@ mod tests {
 	use serde::Deserialize;
 
 	use serde_test::{
-		assert_ser_tokens,
 		Token,
-	};
-
-	#[cfg(feature = "alloc")]
-	use serde_test::{
 		assert_de_tokens,
 		assert_de_tokens_error,
+		assert_ser_tokens,
 	};
 
 	macro_rules! bvtok {
@@ -382,6 +646,24 @@ mod tests {
 				Token::StructEnd,
 			]
 		};
+		( b $head:expr, $bits:expr, $bytes:expr ) => {
+			&[
+				Token::Struct { name: "BitSeq", len: 3, },
+				Token::Str("head"), Token::U8( $head ),
+				Token::Str("bits"), Token::U64( $bits ),
+				Token::Str("data"), Token::Bytes( $bytes ),
+				Token::StructEnd,
+			]
+		};
+		( db $head:expr, $bits:expr, $bytes:expr ) => {
+			&[
+				Token::Struct { name: "BitSeq", len: 3, },
+				Token::BorrowedStr("head"), Token::U8( $head ),
+				Token::BorrowedStr("bits"), Token::U64( $bits ),
+				Token::BorrowedStr("data"), Token::Bytes( $bytes ),
+				Token::StructEnd,
+			]
+		};
 		( d $elts:expr, $head:expr, $bits:expr, $ty:ident $( , $data:expr )* ) => {
 			&[
 				Token::Struct { name: "BitSeq", len: 3, },
@@ -399,7 +681,7 @@ mod tests {
 	fn empty() {
 		let slice = BitSlice::<Msb0, u8>::empty();
 
-		assert_ser_tokens(&slice, bvtok![s 0, 0, 0, U8]);
+		assert_ser_tokens(&slice, bvtok![b 0, 0, &[]]);
 
 		#[cfg(feature = "alloc")]
 		assert_de_tokens(&bitvec![], bvtok![ d 0, 0, 0, U8 ]);
@@ -409,7 +691,7 @@ mod tests {
 	fn small() {
 		let bits = 0b1111_1000u8.view_bits::<Msb0>();
 		let bits = &bits[1 .. 5];
-		assert_ser_tokens(&bits, bvtok![s 1, 1, 4, U8, 0b1111_1000]);
+		assert_ser_tokens(&bits, bvtok![b 1, 4, &[0b1111_1000]]);
 
 		let bits = 0b00001111_11111111u16.view_bits::<Lsb0>();
 		let bits = &bits[.. 12];
@@ -424,7 +706,7 @@ mod tests {
 	fn wide() {
 		let src: &[u8] = &[0, !0];
 		let bs = src.view_bits::<LocalBits>();
-		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![s 2, 1, 14, U8, 0, !0]);
+		assert_ser_tokens(&(&bs[1 .. 15]), bvtok![b 1, 14, &[0, !0]]);
 	}
 
 	#[test]
@@ -445,8 +727,58 @@ mod tests {
 		let bv = bitvec![Msb0, u8; 0, 1, 1, 0, 1, 0];
 		let bb = bv.clone().into_boxed_bitslice();
 
-		assert_ser_tokens(&bv, bvtok![s 1, 0, 6, U8, 0b0110_1000]);
-		assert_ser_tokens(&bb, bvtok![s 1, 0, 6, U8, 0b0110_1000]);
+		assert_ser_tokens(&bv, bvtok![b 0, 6, &[0b0110_1000]]);
+		assert_ser_tokens(&bb, bvtok![b 0, 6, &[0b0110_1000]]);
+	}
+
+	#[test]
+	fn bytes() {
+		static DATA: [u8; 2] = [0b1011_0110, 0b0100_0000];
+		let bits = &DATA.view_bits::<Msb0>()[2 .. 10];
+		assert_de_tokens(&bits, &[
+			Token::Struct {
+				name: "BitSeq",
+				len: 3,
+			},
+			Token::BorrowedStr("head"),
+			Token::U8(2),
+			Token::BorrowedStr("bits"),
+			Token::U64(8),
+			Token::BorrowedStr("data"),
+			Token::BorrowedBytes(&DATA),
+			Token::StructEnd,
+		]);
+		//  A length counter past the end of the data is shortened.
+		assert_de_tokens(&&bits[.. 6], &[
+			Token::Seq { len: Some(3) },
+			Token::U8(2),
+			Token::U64(100),
+			Token::BorrowedBytes(&DATA[.. 1]),
+			Token::SeqEnd,
+		]);
+		assert_de_tokens_error::<&BitSlice<Msb0, u8>>(
+			bvtok![db 0, 8, &DATA],
+			"invalid type: byte array, expected a borrowed byte array",
+		);
+
+		//  Aliased `u8` stores are also written as bytes.
+		let mut data = [0x5Au8, 0xC3];
+		let (left, _) = data.view_bits_mut::<Lsb0>().split_at_mut(12);
+		assert_ser_tokens(&left, bvtok![b 0, 12, &[0x5A, 0xC3]]);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn bytes_owned() {
+		let bv = bitvec![Msb0, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1];
+		assert_de_tokens(&bv, bvtok![db 0, 9, &[0b0110_1001, 0b1000_0000]]);
+		let bb = bv.clone().into_boxed_bitslice();
+		assert_de_tokens(&bb, bvtok![db 0, 9, &[0b0110_1001, 0b1000_0000]]);
+
+		assert_de_tokens_error::<BitVec<Msb0, u16>>(
+			bvtok![db 0, 8, &[0xFF]],
+			"invalid type: byte array, expected a sequence of memory elements",
+		);
 	}
 
 	#[test]
@@ -1,13 +1,14 @@
 //! Port of the `[T]` inherent API.
 
 use crate::{
-	array::BitArray,
 	devel as dvl,
 	mem::BitMemory,
 	order::BitOrder,
 	ptr::BitPtr,
 	slice::{
 		iter::{
+			ArrayChunks,
+			ChunkBy,
 			Chunks,
 			ChunksExact,
 			ChunksExactMut,
@@ -32,19 +33,17 @@ use crate::{
 		BitSlice,
 	},
 	store::BitStore,
+	view::BitView,
 };
 
-use core::{
-	cmp,
-	ops::{
-		Range,
-		RangeBounds,
-		RangeFrom,
-		RangeFull,
-		RangeInclusive,
-		RangeTo,
-		RangeToInclusive,
-	},
+use core::ops::{
+	Range,
+	RangeBounds,
+	RangeFrom,
+	RangeFull,
+	RangeInclusive,
+	RangeTo,
+	RangeToInclusive,
 };
 
 use tap::tap::Tap;
@@ -968,6 +967,53 @@ where
 		ChunksExactMut::new(self, chunk_size)
 	}
 
+	/// Returns an iterator over the slice in chunks as wide as a
+	/// [`BitArray<O, V>`], each copied into a new array, starting at the
+	/// beginning of the slice.
+	///
+	/// Each chunk has exactly [`V::const_bits()`] bits, so code that processes
+	/// fixed-size records gets a type whose width is known at compile time.
+	/// If this width does not divide the length of the slice, then the last
+	/// bits will be omitted and can be retrieved from the [`.remainder()`]
+	/// method of the iterator.
+	///
+	/// # Original
+	///
+	/// [`slice::array_chunks`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.array_chunks)
+	///
+	/// # API Differences
+	///
+	/// The standard library takes the chunk length as a const-generic
+	/// parameter. `BitArray` is sized by its storage type, rather than by a
+	/// bit count, so this takes the storage type `V` of the produced arrays
+	/// instead. The chunks are copied out, because they cannot be borrowed in
+	/// place when the slice does not begin at an element edge.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xA5u8, 0x3C, 0x0F];
+	/// let bits = &data.view_bits::<Msb0>()[2 ..];
+	/// let mut iter = bits.array_chunks::<u8>();
+	/// assert_eq!(iter.len(), 2);
+	///
+	/// let first: BitArray<Msb0, u8> = iter.next().unwrap();
+	/// assert_eq!(first.value(), 0x94);
+	/// assert_eq!(iter.next().unwrap().value(), 0xF0);
+	/// assert!(iter.next().is_none());
+	/// assert_eq!(iter.remainder(), bits![0, 0, 1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`BitArray<O, V>`]: crate::array::BitArray
+	/// [`V::const_bits()`]: crate::view::BitView::const_bits
+	/// [`.remainder()`]: crate::slice::ArrayChunks::remainder
+	pub fn array_chunks<V>(&self) -> ArrayChunks<O, T, V>
+	where V: BitView {
+		ArrayChunks::new(self)
+	}
+
 	/// Returns an iterator over `chunk_size` bits of the slice at a time,
 	/// starting at the end of the slice.
 	///
@@ -1162,6 +1208,47 @@ where
 		RChunksExactMut::new(self, chunk_size)
 	}
 
+	/// Returns an iterator over the slice producing non-overlapping runs of
+	/// bits, using a predicate to separate them.
+	///
+	/// The predicate is called on pairs of adjacent bits: first on `self[0]`
+	/// and `self[1]`, then on `self[1]` and `self[2]`, and so on. A run ends
+	/// between two bits where the predicate returns `false`.
+	///
+	/// # Original
+	///
+	/// [`slice::chunk_by`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.chunk_by)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// let mut iter = bits.chunk_by(|a, b| a == b);
+	///
+	/// assert_eq!(iter.next(), Some(bits![0, 0]));
+	/// assert_eq!(iter.next(), Some(bits![1, 1, 1]));
+	/// assert_eq!(iter.next_back(), Some(bits![1]));
+	/// assert_eq!(iter.next(), Some(bits![0]));
+	/// assert!(iter.next().is_none());
+	/// ```
+	///
+	/// Other predicates choose other boundaries. This one starts a new run at
+	/// each `1` bit:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 0, 1, 1, 0];
+	/// let runs = bits.chunk_by(|_, b| !*b).map(BitSlice::len);
+	/// assert!(runs.eq([3, 1, 2].iter().copied()));
+	/// ```
+	pub fn chunk_by<F>(&self, pred: F) -> ChunkBy<O, T, F>
+	where F: FnMut(&bool, &bool) -> bool {
+		ChunkBy::new(self, pred)
+	}
+
 	/// Divides one slice into two at an index.
 	///
 	/// The first will contain all indices from `[0, mid)` (excluding the index
@@ -1280,7 +1367,8 @@ where
 		mid: usize,
 	) -> (&mut BitSlice<O, T::Alias>, &mut BitSlice<O, T::Alias>)
 	{
-		self.assert_in_bounds(mid);
+		let len = self.len();
+		assert!(mid <= len, "Index {} out of bounds: {}", mid, len);
 		unsafe { self.split_at_unchecked_mut(mid) }
 	}
 
@@ -1784,38 +1872,50 @@ where
 	/// ```
 	///
 	/// [`self.len()`]: Self::len
-	pub fn rotate_left(&mut self, mut by: usize) {
+	pub fn rotate_left(&mut self, by: usize) {
 		let len = self.len();
 		assert!(
 			by <= len,
 			"Slices cannot be rotated by more than their length"
 		);
-		if by == 0 || by == len {
-			return;
-		}
 		/* The standard one-element-at-a-time algorithm is necessary for `[T]`
 		rotation, because it must not allocate, but bit slices have an advantage
 		in that placing a single processor word on the stack as a temporary has
 		significant logical acceleration.
 
-		Instead, we can move `min(usize::BITS, by)` bits from the front of the
-		slice into the stack, then shunt the rest of the slice downwards, then
-		insert the stack bits into the now-open back, repeating until complete.
-
-		There is no reason to use a stack temporary smaller than a processor
-		word, so this uses `usize` instead of `T` for performance benefits.
+		A rotation by no more than a processor word moves that many bits into
+		the stack, shunts the rest of the slice over them, and places the stack
+		bits in the opened space, all with word-level copies. Longer rotations
+		use the block-swap algorithm: the shorter side of the split point is
+		exchanged, one word at a time, with the far end of the longer side,
+		which puts it in its final place and leaves a smaller rotation of the
+		remaining bits. Each bit is moved a bounded number of times, so the
+		whole rotation is linear in the slice length.
 		*/
-		let mut tmp = BitArray::<O, usize>::zeroed();
-		while by > 0 {
-			let shamt = cmp::min(<usize as BitMemory>::BITS as usize, by);
-			unsafe {
-				let tmp_bits = tmp.get_unchecked_mut(.. shamt);
-				tmp_bits.clone_from_bitslice(self.get_unchecked(.. shamt));
-				self.copy_within_unchecked(shamt .., 0);
-				self.get_unchecked_mut(len - shamt ..)
-					.clone_from_bitslice(tmp_bits);
+		let word = <usize as BitMemory>::BITS as usize;
+		let (mut start, mut end, mut left) = (0, len, by);
+		loop {
+			let right = end - start - left;
+			let rest = unsafe { self.get_unchecked_mut(start .. end) };
+			if left <= word || right <= word {
+				rest.rotate_short(left);
+				return;
+			}
+			if left <= right {
+				//  `[A, B1, B2]` becomes `[B2, B1, A]`.
+				unsafe {
+					rest.swap_within_unchecked(0, right, left);
+				}
+				end -= left;
+			}
+			else {
+				//  `[A1, A2, B]` becomes `[B, A2, A1]`.
+				unsafe {
+					rest.swap_within_unchecked(0, left, right);
+				}
+				start += right;
+				left -= right;
 			}
-			by -= shamt;
 		}
 	}
 
@@ -1858,28 +1958,72 @@ where
 	/// ```
 	///
 	/// [`self.len()`]: Self::len
-	pub fn rotate_right(&mut self, mut by: usize) {
+	pub fn rotate_right(&mut self, by: usize) {
 		let len = self.len();
 		assert!(
 			by <= len,
 			"Slices cannot be rotated by more than their length"
 		);
-		if by == 0 || by == len {
-			return;
-		}
-		let mut tmp = BitArray::<O, usize>::zeroed();
-		while by > 0 {
-			let shamt = cmp::min(<usize as BitMemory>::BITS as usize, by);
-			let mid = len - shamt;
-			unsafe {
-				let tmp_bits = tmp.get_unchecked_mut(.. shamt);
-				tmp_bits.clone_from_bitslice(self.get_unchecked(mid ..));
-				self.copy_within_unchecked(.. mid, shamt);
-				self.get_unchecked_mut(.. shamt)
-					.clone_from_bitslice(tmp_bits);
-			}
-			by -= shamt;
-		}
+		self.rotate_left(len - by);
+	}
+
+	/// Fills the slice with a bit value.
+	///
+	/// # Original
+	///
+	/// [`slice::fill`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.fill)
+	///
+	/// # API Differences
+	///
+	/// This is a synonym for [`.set_all()`]. The memory elements that the
+	/// slice fully spans are written whole, and only its partial edge elements
+	/// are masked.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// data.view_bits_mut::<Lsb0>()[4 .. 20].fill(true);
+	/// assert_eq!(data, [0xF0, 0xFF, 0x0F]);
+	/// ```
+	///
+	/// [`.set_all()`]: Self::set_all
+	pub fn fill(&mut self, value: bool) {
+		self.set_all(value);
+	}
+
+	/// Fills the slice with bits produced by calling a generator repeatedly.
+	///
+	/// # Original
+	///
+	/// [`slice::fill_with`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.fill_with)
+	///
+	/// # API Differences
+	///
+	/// `func` is called once for each bit, in ascending index order. Its
+	/// results are collected for each memory element and written with a
+	/// single store, as [`.for_each_mut()`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let mut toggle = false;
+	/// data.view_bits_mut::<Msb0>()[.. 12].fill_with(|| {
+	///   toggle = !toggle;
+	///   toggle
+	/// });
+	/// assert_eq!(data, 0xAAA0);
+	/// ```
+	///
+	/// [`.for_each_mut()`]: Self::for_each_mut
+	pub fn fill_with<F>(&mut self, mut func: F)
+	where F: FnMut() -> bool {
+		self.for_each_mut(|_, _| func());
 	}
 
 	/// The name is preserved for API compatibility. See
@@ -1913,6 +2057,10 @@ where
 	/// length as `src`. The two ranges may overlap. The ends of the two ranges
 	/// must be less than or equal to [`self.len()`].
 	///
+	/// When the two ranges start at the same index within their memory
+	/// elements, the elements that `src` fully spans are moved whole, as
+	/// `memmove` does, and only the bits at its edges are moved individually.
+	///
 	/// # Original
 	///
 	/// [`slice::copy_within`](https://doc.rust-lang.org/stable/std/primitive.slice.html#method.copy_within)
@@ -1996,6 +2144,15 @@ where
 	/// behavior for *correctness* of your work; it is only a possible
 	/// performance improvement.
 	///
+	/// # Bit Order
+	///
+	/// When `U` is wider than `T`, each element of the middle slice is built
+	/// from several `T` elements in the byte order of the target. The bits of
+	/// the middle slice are only at the same indices as the corresponding bits
+	/// of `self` when the ordering agrees with that byte order: [`Lsb0`] on
+	/// little-endian targets, or [`Msb0`] on big-endian targets. Other
+	/// combinations permute the bits within each `U` element.
+	///
 	/// # Safety
 	///
 	/// This method is essentially a [`mem::transmute`][mt] with respect to the
@@ -2027,6 +2184,25 @@ where
 	/// }
 	/// ```
 	///
+	/// Viewing a byte buffer through the widest register:
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let words = [!0u64; 4];
+	/// let bytes = unsafe {
+	///   core::slice::from_raw_parts(words.as_ptr() as *const u8, 32)
+	/// };
+	/// let bits = &bytes.view_bits::<Lsb0>()[5 .. 250];
+	/// let (prefix, wide, suffix) = unsafe { bits.align_to::<u64>() };
+	/// assert_eq!(prefix.len(), 59);
+	/// assert_eq!(wide.len(), 128);
+	/// assert_eq!(suffix.len(), 58);
+	/// assert!(wide.all());
+	/// ```
+	///
+	/// [`Lsb0`]: crate::order::Lsb0
+	/// [`Msb0`]: crate::order::Msb0
 	/// [mt]: core::mem::transmute
 	/// [`BitStore`]: crate::store::BitStore
 	/// [`Domain`]: crate::domain::Domain
@@ -2285,11 +2461,11 @@ where
 	T: BitStore,
 {
 	super::bits_from_raw_parts(data, 0, len * T::Mem::BITS as usize)
-		.unwrap_or_else(|| {
+		.unwrap_or_else(|err| {
 			panic!(
-				"Failed to construct `&{}BitSlice` from invalid pointer {:p} \
-				 or element count {}",
-				"", data, len
+				"Failed to construct `&{}BitSlice` from pointer {:p} and \
+				 element count {}: {}",
+				"", data, len, err
 			)
 		})
 }
@@ -2335,11 +2511,11 @@ where
 	T: BitStore,
 {
 	super::bits_from_raw_parts_mut(data, 0, len * T::Mem::BITS as usize)
-		.unwrap_or_else(|| {
+		.unwrap_or_else(|err| {
 			panic!(
-				"Failed to construct `&{}BitSlice` from invalid pointer {:p} \
-				 or element count {}",
-				"mut ", data, len
+				"Failed to construct `&{}BitSlice` from pointer {:p} and \
+				 element count {}: {}",
+				"mut ", data, len, err
 			)
 		})
 }
@@ -1,6 +1,7 @@
 //! Iterators over `[T]`.
 
 use crate::{
+	array::BitArray,
 	index::BitIdx,
 	mem::BitMemory,
 	order::BitOrder,
@@ -11,6 +12,7 @@ use crate::{
 		BitSliceIndex,
 	},
 	store::BitStore,
+	view::BitView,
 };
 
 use core::{
@@ -20,9 +22,13 @@ use core::{
 		Debug,
 		Formatter,
 	},
-	iter::FusedIterator,
+	iter::{
+		Copied,
+		FusedIterator,
+	},
 	marker::PhantomData,
 	mem,
+	ops::Range,
 	ptr::NonNull,
 };
 
@@ -189,6 +195,70 @@ where
 		.to_bitslice_ref()
 	}
 
+	/// Converts the iterator into a view of the bits it has not yet yielded.
+	///
+	/// This is the same view as [`.as_bitslice()`], and exists so that `Iter`
+	/// and [`IterMut`] can be consumed by the same name.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0];
+	/// let mut iter = bits.iter();
+	/// iter.next();
+	/// iter.next_back();
+	/// assert_eq!(iter.into_bitslice(), bits![1, 1]);
+	/// ```
+	///
+	/// [`IterMut`]: crate::slice::IterMut
+	/// [`.as_bitslice()`]: Self::as_bitslice
+	pub fn into_bitslice(self) -> &'a BitSlice<O, T> {
+		self.as_bitslice()
+	}
+
+	/// Adapts the iterator to yield `&bool` references.
+	///
+	/// This is the mode that `Iter` already uses, and so this method returns
+	/// the iterator unchanged. It exists so that generic code can name the
+	/// mode it requires, alongside [`.by_vals()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1];
+	/// let refs: Vec<&bool> = bits.iter().by_refs().collect();
+	/// assert_eq!(refs, [&false, &true]);
+	/// ```
+	///
+	/// [`.by_vals()`]: Self::by_vals
+	pub fn by_refs(self) -> Self {
+		self
+	}
+
+	/// Adapts the iterator to yield `bool` values.
+	///
+	/// Bits have no address, so the references that `Iter` yields point to
+	/// static `true` and `false` values, and carry no information beyond the
+	/// bit. This mode drops the indirection, and is equivalent to
+	/// `.copied()`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1];
+	/// let vals: Vec<bool> = bits.iter().by_vals().collect();
+	/// assert_eq!(vals, [false, true, true]);
+	/// ```
+	pub fn by_vals(self) -> Copied<Self> {
+		self.copied()
+	}
+
 	/* Allow the standard-library name to resolve, but instruct the user to
 	rename.
 
@@ -432,11 +502,81 @@ where
 		self.into_bitslice()
 	}
 
-	/// Used only for `Debug` printing.
-	pub(super) fn as_bitslice(&self) -> &BitSlice<O, T::Alias> {
+	/// Views the bits that the iterator has not yet yielded.
+	///
+	/// The view borrows the iterator, so it cannot be held across a call that
+	/// produces a new mutable proxy.
+	///
+	/// # Original
+	///
+	/// [`IterMut::as_slice`](core::slice::IterMut::as_slice)
+	///
+	/// # API Differences
+	///
+	/// As this views a [`BitSlice`], rather than a `[T]` or `[bool]` slice, it
+	/// has been renamed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 0];
+	/// let mut iter = bits.iter_mut();
+	/// *iter.next().unwrap() = true;
+	/// assert_eq!(iter.as_bitslice(), bits![1, 0]);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_bitslice(&self) -> &BitSlice<O, T::Alias> {
 		unsafe { core::ptr::read(self) }.into_bitslice()
 	}
 
+	/// Adapts the iterator to yield [`BitMut`] proxy references.
+	///
+	/// This is the mode that `IterMut` already uses, and so this method
+	/// returns the iterator unchanged. It exists so that generic code can name
+	/// the mode it requires, alongside [`.by_vals()`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0; 3];
+	/// for (idx, mut bit) in bits.iter_mut().by_refs().enumerate() {
+	///   *bit = idx != 1;
+	/// }
+	/// assert_eq!(bits, bits![1, 0, 1]);
+	/// ```
+	///
+	/// [`BitMut`]: crate::slice::BitMut
+	/// [`.by_vals()`]: Self::by_vals
+	pub fn by_refs(self) -> Self {
+		self
+	}
+
+	/// Adapts the iterator to yield `bool` values.
+	///
+	/// This gives up write access to the remaining bits, and reads them as an
+	/// ordinary [`Iter`] would, without creating a [`BitMut`] proxy for each.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1];
+	/// assert_eq!(bits.iter_mut().by_vals().filter(|&bit| bit).count(), 1);
+	/// ```
+	///
+	/// [`BitMut`]: crate::slice::BitMut
+	/// [`Iter`]: crate::slice::Iter
+	pub fn by_vals(self) -> Copied<Iter<'a, O, T::Alias>> {
+		let bits: &'a BitSlice<O, T::Alias> = self.into_bitslice();
+		bits.iter().copied()
+	}
+
 	/// Removes the bit at the front of the iterator.
 	fn pop_front(&mut self) -> <Self as Iterator>::Item {
 		let out =
@@ -804,6 +944,40 @@ where
 	width: usize,
 }
 
+impl<'a, O, T> Chunks<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Views the bits that the iterator has not yet yielded.
+	///
+	/// This has the same lifetime as the original [`BitSlice`], and so the
+	/// iterator can continue to be used while this exists.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 0, 1, 0];
+	/// let mut chunks = bits.chunks(3);
+	/// chunks.next();
+	/// assert_eq!(chunks.as_bitslice(), bits![1, 0, 1, 0]);
+	/// chunks.next_back();
+	/// assert_eq!(chunks.as_bitslice(), bits![1, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_bitslice(&self) -> &'a BitSlice<O, T> {
+		self.slice
+	}
+
+	/// Converts the iterator into a view of the bits it has not yet yielded.
+	pub fn into_bitslice(self) -> &'a BitSlice<O, T> {
+		self.slice
+	}
+}
+
 group!(Chunks => &'a BitSlice<O, T> {
 	fn next(&mut self) -> Option<Self::Item> {
 		let len = self.slice.len();
@@ -913,6 +1087,32 @@ where
 	width: usize,
 }
 
+impl<'a, O, T> ChunksMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Converts the iterator into a view of the bits it has not yet yielded.
+	///
+	/// To avoid creating `&mut` references that alias, this is forced to
+	/// consume the iterator.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0; 7];
+	/// let mut chunks = bits.chunks_mut(3);
+	/// chunks.next();
+	/// chunks.into_bitslice().set_all(true);
+	/// assert_eq!(bits, bits![0, 0, 0, 1, 1, 1, 1]);
+	/// ```
+	pub fn into_bitslice(self) -> &'a mut BitSlice<O, T::Alias> {
+		self.slice
+	}
+}
+
 group!(ChunksMut => &'a mut BitSlice<O, T::Alias> {
 	fn next(&mut self) -> Option<Self::Item> {
 		let slice = mem::take(&mut self.slice);
@@ -1246,6 +1446,145 @@ group!(ChunksExactMut => &'a mut BitSlice<O, T::Alias> {
 	}
 });
 
+/** An iterator over a [`BitSlice`] in (non-overlapping) chunks, each copied
+into a [`BitArray`], starting at the beginning of the slice.
+
+Each chunk is as wide as a [`BitArray<O, V>`], which is [`V::const_bits()`]
+bits. When the slice length is not evenly divided by this width, the last up to
+`V::const_bits() - 1` bits will be omitted but can be retrieved from the
+[`.remainder()`] function from the iterator.
+
+This struct is created by the [`.array_chunks()`] method on [`BitSlice`]s.
+
+# Original
+
+[`slice::ArrayChunks`](core::slice::ArrayChunks)
+
+# API Differences
+
+The standard library yields references to arrays within the slice. Bit-slices
+can begin at any bit of a memory element, so this instead copies each chunk
+into a new `BitArray` value. Its storage type does not need to match `T`.
+
+[`BitArray`]: crate::array::BitArray
+[`BitArray<O, V>`]: crate::array::BitArray
+[`BitSlice`]: crate::slice::BitSlice
+[`V::const_bits()`]: crate::view::BitView::const_bits
+[`.array_chunks()`]: crate::slice::BitSlice::array_chunks
+[`.remainder()`]: Self::remainder
+**/
+#[derive(Clone, Debug)]
+pub struct ArrayChunks<'a, O, T, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	V: BitView,
+{
+	/// The whole-width chunks of the source [`BitSlice`].
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	inner: ChunksExact<'a, O, T>,
+	/// The type of the produced arrays.
+	_arr: PhantomData<V>,
+}
+
+impl<'a, O, T, V> ArrayChunks<'a, O, T, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	V: BitView,
+{
+	pub(super) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self {
+			inner: ChunksExact::new(slice, V::const_bits()),
+			_arr: PhantomData,
+		}
+	}
+
+	/// Returns the remainder of the original [`BitSlice`] that is not going to
+	/// be returned by the iterator. The returned `BitSlice` has fewer bits
+	/// than one array.
+	///
+	/// # Original
+	///
+	/// [`slice::ArrayChunks::remainder`](core::slice::ArrayChunks::remainder)
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn remainder(&self) -> &'a BitSlice<O, T> {
+		self.inner.remainder()
+	}
+
+	/// Copies a chunk into a new array.
+	fn load(chunk: &BitSlice<O, T>) -> BitArray<O, V> {
+		let mut out = BitArray::zeroed();
+		out.clone_from_bitslice(chunk);
+		out
+	}
+}
+
+impl<O, T, V> Iterator for ArrayChunks<'_, O, T, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	V: BitView,
+{
+	type Item = BitArray<O, V>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(Self::load)
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth(n).map(Self::load)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T, V> DoubleEndedIterator for ArrayChunks<'_, O, T, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	V: BitView,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(Self::load)
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.inner.nth_back(n).map(Self::load)
+	}
+}
+
+impl<O, T, V> ExactSizeIterator for ArrayChunks<'_, O, T, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	V: BitView,
+{
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+}
+
+impl<O, T, V> FusedIterator for ArrayChunks<'_, O, T, V>
+where
+	O: BitOrder,
+	T: BitStore,
+	V: BitView,
+{
+}
+
 /** An iterator over a [`BitSlice`] in (non-overlapping) chunks (`chunk_size`
 bits at a time), starting at the end of the slice.
 
@@ -1750,56 +2089,178 @@ new_group!(
 	RChunksMut mut .alias_mut(),
 );
 
-macro_rules! split {
-	($iter:ident => $item:ty $( where $alias:ident )? {
-		$next:item
-		$next_back:item
-	}) => {
-		impl<'a, O, T, P> $iter <'a, O, T, P>
-		where
-			O: BitOrder,
-			T: BitStore,
-			P: FnMut(usize, &bool) -> bool,
-		{
-			pub(super) fn new(slice: $item, pred: P) -> Self {
-				Self {
-					slice,
-					pred,
-					done: false,
-				}
-			}
-		}
+/** An iterator over subslices of consecutive bits that are grouped together by
+a predicate.
 
-		impl<O, T, P> Debug for $iter <'_, O, T, P>
-		where
-			O: BitOrder,
-			T: BitStore,
-			P: FnMut(usize, &bool) -> bool,
-		{
-			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-				fmt.debug_struct(stringify!($iter))
-					.field("slice", &self.slice)
-					.field("done", &self.done)
-					.finish()
-			}
-		}
+This struct is created by the [`.chunk_by()`] method on [`BitSlice`]s.
 
-		impl<'a, O, T, P> Iterator for $iter <'a, O, T, P>
-		where
-			O: BitOrder,
-			T: BitStore,
-			P: FnMut(usize, &bool) -> bool,
-		{
-			type Item = $item;
+# Original
 
-			$next
+[`slice::ChunkBy`](https://doc.rust-lang.org/stable/std/slice/struct.ChunkBy.html)
 
-			fn size_hint(&self) -> (usize, Option<usize>) {
-				if self.done {
-					(0, Some(0))
-				}
-				else {
-					(1, Some(self.slice.len() + 1))
+[`BitSlice`]: crate::slice::BitSlice
+[`.chunk_by()`]: crate::slice::BitSlice::chunk_by
+**/
+#[derive(Clone)]
+pub struct ChunkBy<'a, O, T, P>
+where
+	O: BitOrder,
+	T: BitStore,
+	P: FnMut(&bool, &bool) -> bool,
+{
+	/// The [`BitSlice`] being grouped.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	slice: &'a BitSlice<O, T>,
+	/// The function used to test whether two adjacent bits share a group.
+	pred: P,
+}
+
+impl<'a, O, T, P> ChunkBy<'a, O, T, P>
+where
+	O: BitOrder,
+	T: BitStore,
+	P: FnMut(&bool, &bool) -> bool,
+{
+	pub(super) fn new(slice: &'a BitSlice<O, T>, pred: P) -> Self {
+		Self { slice, pred }
+	}
+}
+
+impl<O, T, P> Debug for ChunkBy<'_, O, T, P>
+where
+	O: BitOrder,
+	T: BitStore,
+	P: FnMut(&bool, &bool) -> bool,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("ChunkBy")
+			.field("slice", &self.slice)
+			.finish()
+	}
+}
+
+impl<'a, O, T, P> Iterator for ChunkBy<'a, O, T, P>
+where
+	O: BitOrder,
+	T: BitStore,
+	P: FnMut(&bool, &bool) -> bool,
+{
+	type Item = &'a BitSlice<O, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let slice = self.slice;
+		let len = slice.len();
+		if len == 0 {
+			return None;
+		}
+		let mut prev = unsafe { *slice.get_unchecked(0) };
+		let mut end = 1;
+		while end < len {
+			let next = unsafe { *slice.get_unchecked(end) };
+			if !(self.pred)(&prev, &next) {
+				break;
+			}
+			prev = next;
+			end += 1;
+		}
+		let (out, rest) = unsafe { slice.split_at_unchecked(end) };
+		self.slice = rest;
+		Some(out)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.slice.len();
+		((len != 0) as usize, Some(len))
+	}
+}
+
+impl<O, T, P> DoubleEndedIterator for ChunkBy<'_, O, T, P>
+where
+	O: BitOrder,
+	T: BitStore,
+	P: FnMut(&bool, &bool) -> bool,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let slice = self.slice;
+		let len = slice.len();
+		if len == 0 {
+			return None;
+		}
+		let mut next = unsafe { *slice.get_unchecked(len - 1) };
+		let mut start = len - 1;
+		while start > 0 {
+			let prev = unsafe { *slice.get_unchecked(start - 1) };
+			if !(self.pred)(&prev, &next) {
+				break;
+			}
+			next = prev;
+			start -= 1;
+		}
+		let (rest, out) = unsafe { slice.split_at_unchecked(start) };
+		self.slice = rest;
+		Some(out)
+	}
+}
+
+impl<O, T, P> FusedIterator for ChunkBy<'_, O, T, P>
+where
+	O: BitOrder,
+	T: BitStore,
+	P: FnMut(&bool, &bool) -> bool,
+{
+}
+
+macro_rules! split {
+	($iter:ident => $item:ty $( where $alias:ident )? {
+		$next:item
+		$next_back:item
+	}) => {
+		impl<'a, O, T, P> $iter <'a, O, T, P>
+		where
+			O: BitOrder,
+			T: BitStore,
+			P: FnMut(usize, &bool) -> bool,
+		{
+			pub(super) fn new(slice: $item, pred: P) -> Self {
+				Self {
+					slice,
+					pred,
+					done: false,
+				}
+			}
+		}
+
+		impl<O, T, P> Debug for $iter <'_, O, T, P>
+		where
+			O: BitOrder,
+			T: BitStore,
+			P: FnMut(usize, &bool) -> bool,
+		{
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				fmt.debug_struct(stringify!($iter))
+					.field("slice", &self.slice)
+					.field("done", &self.done)
+					.finish()
+			}
+		}
+
+		impl<'a, O, T, P> Iterator for $iter <'a, O, T, P>
+		where
+			O: BitOrder,
+			T: BitStore,
+			P: FnMut(usize, &bool) -> bool,
+		{
+			type Item = $item;
+
+			$next
+
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				if self.done {
+					(0, Some(0))
+				}
+				else {
+					(1, Some(self.slice.len() + 1))
 				}
 			}
 		}
@@ -2320,6 +2781,221 @@ split_n!(SplitNMut => SplitMut => &'a mut BitSlice<O, T::Alias> );
 split_n!(RSplitN => RSplit => &'a BitSlice<O, T>);
 split_n!(RSplitNMut => RSplitMut => &'a mut BitSlice<O, T::Alias> );
 
+/** An iterator over a [`BitSlice`] in (non-overlapping) chunks that are cut at
+the boundaries of its memory elements.
+
+The first chunk runs from the start of the slice to the end of its first
+element, the last chunk runs from the start of its last element to the end of
+the slice, and every other chunk covers exactly one whole element.
+
+This struct is created by the [`.chunks_by_element()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.chunks_by_element()`]: crate::slice::BitSlice::chunks_by_element
+**/
+#[derive(Clone, Debug)]
+pub struct ChunksByElement<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The [`BitSlice`] being chunked.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	slice: &'a BitSlice<O, T>,
+}
+
+group!(ChunksByElement => &'a BitSlice<O, T> {
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.slice.is_empty() {
+			return None;
+		}
+		let (out, rest) =
+			unsafe { self.slice.split_at_unchecked(front_chunk(self.slice)) };
+		self.slice = rest;
+		Some(out)
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.len() {
+			self.slice = Default::default();
+			return None;
+		}
+		let start = chunk_start(self.slice, n);
+		self.slice = unsafe { self.slice.get_unchecked(start ..) };
+		self.next()
+	}
+
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let len = self.slice.len();
+		if len == 0 {
+			return None;
+		}
+		let (rest, out) = unsafe {
+			self.slice.split_at_unchecked(len - back_chunk(self.slice))
+		};
+		self.slice = rest;
+		Some(out)
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let len = self.len();
+		if n >= len {
+			self.slice = Default::default();
+			return None;
+		}
+		let end = chunk_start(self.slice, len - n);
+		self.slice = unsafe { self.slice.get_unchecked(.. end) };
+		self.next_back()
+	}
+
+	fn len(&self) -> usize {
+		match self.slice.len() {
+			0 => 0,
+			_ => self.slice.bitptr().elements(),
+		}
+	}
+});
+
+/** An iterator over a [`BitSlice`] in (non-overlapping) mutable chunks that are
+cut at the boundaries of its memory elements.
+
+The first chunk runs from the start of the slice to the end of its first
+element, the last chunk runs from the start of its last element to the end of
+the slice, and every other chunk covers exactly one whole element.
+
+This struct is created by the [`.chunks_by_element_mut()`] method on
+[`BitSlice`]s.
+
+# API Differences
+
+Unlike [`ChunksMut`], the slices yielded from this iterator are *not* marked as
+aliased, because no two of them can ever touch the same memory element.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`ChunksMut`]: crate::slice::ChunksMut
+[`.chunks_by_element_mut()`]: crate::slice::BitSlice::chunks_by_element_mut
+**/
+#[derive(Debug)]
+pub struct ChunksByElementMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The [`BitSlice`] being chunked.
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	slice: &'a mut BitSlice<O, T>,
+}
+
+group!(ChunksByElementMut => &'a mut BitSlice<O, T> {
+	fn next(&mut self) -> Option<Self::Item> {
+		let slice = mem::take(&mut self.slice);
+		if slice.is_empty() {
+			return None;
+		}
+		let mid = front_chunk(slice);
+		//  Element-boundary splits never share memory, and so never alias.
+		let (out, rest) = unsafe { slice.split_at_unchecked_mut_noalias(mid) };
+		self.slice = rest;
+		Some(out)
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let slice = mem::take(&mut self.slice);
+		if n >= slice.bitptr().elements() || slice.is_empty() {
+			return None;
+		}
+		let start = chunk_start(slice, n);
+		self.slice = unsafe { slice.get_unchecked_mut(start ..) };
+		self.next()
+	}
+
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let slice = mem::take(&mut self.slice);
+		let len = slice.len();
+		if len == 0 {
+			return None;
+		}
+		let mid = len - back_chunk(slice);
+		let (rest, out) = unsafe { slice.split_at_unchecked_mut_noalias(mid) };
+		self.slice = rest;
+		Some(out)
+	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let len = self.len();
+		let slice = mem::take(&mut self.slice);
+		if n >= len {
+			return None;
+		}
+		let end = chunk_start(slice, len - n);
+		self.slice = unsafe { slice.get_unchecked_mut(.. end) };
+		self.next_back()
+	}
+
+	fn len(&self) -> usize {
+		match self.slice.len() {
+			0 => 0,
+			_ => self.slice.bitptr().elements(),
+		}
+	}
+});
+
+impl<'a, O, T> ChunksByElement<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(super) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self { slice }
+	}
+}
+
+impl<'a, O, T> ChunksByElementMut<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(super) fn new(slice: &'a mut BitSlice<O, T>) -> Self {
+		Self { slice }
+	}
+}
+
+/// Computes the length of the chunk at the front of an element-chunked slice.
+fn front_chunk<O, T>(slice: &BitSlice<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let head = slice.bitptr().head().value() as usize;
+	cmp::min(slice.len(), T::Mem::BITS as usize - head)
+}
+
+/// Computes the length of the chunk at the back of an element-chunked slice.
+fn back_chunk<O, T>(slice: &BitSlice<O, T>) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let tail = slice.bitptr().tail().value() as usize;
+	cmp::min(slice.len(), tail)
+}
+
+/// Computes the index in an element-chunked slice at which chunk `n` begins.
+///
+/// `n` may be equal to the number of chunks, in which case this produces the
+/// slice length.
+fn chunk_start<O, T>(slice: &BitSlice<O, T>, n: usize) -> usize
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let head = slice.bitptr().head().value() as usize;
+	let start = (n * T::Mem::BITS as usize).saturating_sub(head);
+	cmp::min(start, slice.len())
+}
+
 /** Enumerates bits in a [`BitSlice`] that are set to `1`.
 
 This struct is created by the [`.iter_ones()`] method on [`BitSlice`]s.
@@ -2373,7 +3049,7 @@ where
 	type Item = usize;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().position(|b| b) {
+		match self.inner.first_one() {
 			Some(n) => {
 				//  Split on the far side of the found index. This is always
 				//  safe, as split(len) yields (self, empty).
@@ -2411,7 +3087,7 @@ where
 	T: BitStore,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().rposition(|b| b) {
+		match self.inner.last_one() {
 			Some(n) => {
 				let (rest, _) = unsafe { self.inner.split_at_unchecked(n) };
 				self.inner = rest;
@@ -2495,7 +3171,7 @@ where
 	type Item = usize;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().position(|b| !b) {
+		match self.inner.first_zero() {
 			Some(n) => {
 				let (_, rest) = unsafe { self.inner.split_at_unchecked(n + 1) };
 				self.inner = rest;
@@ -2530,7 +3206,7 @@ where
 	T: BitStore,
 {
 	fn next_back(&mut self) -> Option<Self::Item> {
-		match self.inner.iter().copied().rposition(|b| !b) {
+		match self.inner.last_zero() {
 			Some(n) => {
 				let (rest, _) = unsafe { self.inner.split_at_unchecked(n) };
 				self.inner = rest;
@@ -2561,6 +3237,193 @@ where
 {
 }
 
+/** Enumerates the maximal runs of `1` bits in a [`BitSlice`].
+
+This struct is created by the [`.iter_one_ranges()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_one_ranges()`]: crate::slice::BitSlice::iter_one_ranges
+**/
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct IterOneRanges<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The remaining slice whose runs are to be found.
+	inner: &'a BitSlice<O, T>,
+	/// The offset from the front of the original slice to current `inner`.
+	front: usize,
+}
+
+impl<'a, O, T> IterOneRanges<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	pub(crate) fn new(slice: &'a BitSlice<O, T>) -> Self {
+		Self {
+			inner: slice,
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Default for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self {
+			inner: Default::default(),
+			front: 0,
+		}
+	}
+}
+
+impl<O, T> Iterator for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = Range<usize>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = match self.inner.first_one() {
+			Some(n) => n,
+			None => {
+				*self = Default::default();
+				return None;
+			},
+		};
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(start) };
+		let end = start + rest.first_zero().unwrap_or_else(|| rest.len());
+		//  The bit at `end`, if any, is `0`, and cannot begin the next run.
+		let skip = cmp::min(end + 1, self.inner.len());
+		let (_, rest) = unsafe { self.inner.split_at_unchecked(skip) };
+		self.inner = rest;
+		let out = self.front + start .. self.front + end;
+		self.front += skip;
+		Some(out)
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<O, T> DoubleEndedIterator for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let end = match self.inner.last_one() {
+			Some(n) => n + 1,
+			None => {
+				*self = Default::default();
+				return None;
+			},
+		};
+		let (rest, _) = unsafe { self.inner.split_at_unchecked(end) };
+		let start = rest.last_zero().map_or(0, |n| n + 1);
+		let (rest, _) = unsafe { self.inner.split_at_unchecked(start) };
+		self.inner = rest;
+		Some(self.front + start .. self.front + end)
+	}
+}
+
+impl<O, T> FusedIterator for IterOneRanges<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** Enumerates the indices at which two [`BitSlice`]s differ.
+
+This struct is created by the [`.iter_mismatches()`] method on [`BitSlice`]s.
+
+[`BitSlice`]: crate::slice::BitSlice
+[`.iter_mismatches()`]: crate::slice::BitSlice::iter_mismatches
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct IterMismatches<'a, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	/// The remaining region of the left slice.
+	this: &'a BitSlice<O1, T1>,
+	/// The remaining region of the right slice. It has the length of `this`.
+	that: &'a BitSlice<O2, T2>,
+	/// The offset from the front of the original slices to the current ones.
+	front: usize,
+}
+
+impl<'a, O1, T1, O2, T2> IterMismatches<'a, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	pub(crate) fn new(
+		this: &'a BitSlice<O1, T1>,
+		that: &'a BitSlice<O2, T2>,
+	) -> Self {
+		let len = cmp::min(this.len(), that.len());
+		Self {
+			this: unsafe { this.get_unchecked(.. len) },
+			that: unsafe { that.get_unchecked(.. len) },
+			front: 0,
+		}
+	}
+}
+
+impl<O1, T1, O2, T2> Iterator for IterMismatches<'_, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let n = match self.this.first_mismatch(self.that) {
+			Some(n) => n,
+			None => {
+				self.this = Default::default();
+				self.that = Default::default();
+				return None;
+			},
+		};
+		self.this = unsafe { self.this.get_unchecked(n + 1 ..) };
+		self.that = unsafe { self.that.get_unchecked(n + 1 ..) };
+		let out = self.front + n;
+		self.front = out + 1;
+		Some(out)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.this.len()))
+	}
+}
+
+impl<O1, T1, O2, T2> FusedIterator for IterMismatches<'_, O1, T1, O2, T2>
+where
+	O1: BitOrder,
+	T1: BitStore,
+	O2: BitOrder,
+	T2: BitStore,
+{
+}
+
 /* This macro has some very obnoxious call syntax that is necessary to handle
 the different iteration protocols used above.
 
@@ -24,8 +24,21 @@ use core::ops::{
 	RangeInclusive,
 	RangeTo,
 	RangeToInclusive,
+	ShlAssign,
+	ShrAssign,
 };
 
+/** Clears each bit of `self` whose partner in `rhs` is `0`.
+
+The operand is consumed in order, one bit for each bit of `self`. If it runs
+out, the remaining bits of `self` are cleared, as if it were extended with `0`
+bits. Its excess bits are ignored. Use [`.bitand_assign_truncating()`] to leave
+the remaining bits unchanged instead, or [`.try_bitand_assign()`] to reject a
+bit-slice operand of a different length.
+
+[`.bitand_assign_truncating()`]: crate::slice::BitSlice::bitand_assign_truncating
+[`.try_bitand_assign()`]: crate::slice::BitSlice::try_bitand_assign
+**/
 impl<O, T, Rhs> BitAndAssign<Rhs> for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -38,6 +51,15 @@ where
 	}
 }
 
+/** Sets each bit of `self` whose partner in `rhs` is `1`.
+
+The operand is consumed in order, one bit for each bit of `self`. If it runs
+out, the remaining bits of `self` are unchanged, and its excess bits are
+ignored. Use [`.try_bitor_assign()`] to reject a bit-slice operand of a
+different length.
+
+[`.try_bitor_assign()`]: crate::slice::BitSlice::try_bitor_assign
+**/
 impl<O, T, Rhs> BitOrAssign<Rhs> for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -50,6 +72,15 @@ where
 	}
 }
 
+/** Inverts each bit of `self` whose partner in `rhs` is `1`.
+
+The operand is consumed in order, one bit for each bit of `self`. If it runs
+out, the remaining bits of `self` are unchanged, and its excess bits are
+ignored. Use [`.try_bitxor_assign()`] to reject a bit-slice operand of a
+different length.
+
+[`.try_bitxor_assign()`]: crate::slice::BitSlice::try_bitxor_assign
+**/
 impl<O, T, Rhs> BitXorAssign<Rhs> for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -163,3 +194,35 @@ where
 		self
 	}
 }
+
+/// Shifts the slice towards its most significant end, as an integer.
+///
+/// See [`BitSlice::wrapping_shl`] for the numeric interpretation of a slice.
+/// Shifting by at least the slice length clears it.
+///
+/// [`BitSlice::wrapping_shl`]: crate::slice::BitSlice::wrapping_shl
+impl<O, T> ShlAssign<usize> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn shl_assign(&mut self, by: usize) {
+		self.shift_numeric(by, true);
+	}
+}
+
+/// Shifts the slice towards its least significant end, as an integer.
+///
+/// See [`BitSlice::wrapping_shl`] for the numeric interpretation of a slice.
+/// Shifting by at least the slice length clears it.
+///
+/// [`BitSlice::wrapping_shl`]: crate::slice::BitSlice::wrapping_shl
+impl<O, T> ShrAssign<usize> for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn shr_assign(&mut self, by: usize) {
+		self.shift_numeric(by, false);
+	}
+}
@@ -10,9 +10,16 @@ and transmute generic slices into slices with concrete type arguments applied.
 
 use crate::{
 	devel as dvl,
+	domain::{
+		BitDomain,
+		BitDomainMut,
+		Domain,
+		DomainMut,
+	},
 	field::BitField,
 	mem::BitMemory,
 	order::{
+		BitOrder,
 		Lsb0,
 		Msb0,
 	},
@@ -20,7 +27,159 @@ use crate::{
 	store::BitStore,
 };
 
-use core::ops::RangeBounds;
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	mem,
+	ops::{
+		Range,
+		RangeBounds,
+	},
+	ptr,
+};
+
+/** Order-independent function implementations.
+
+When two slices of the same length start at the same index within their first
+elements, they cover their elements identically, whatever their ordering. The
+elements that they fully span can then be copied or compared whole, as `memcpy`
+and `memcmp` do, without any shifting or masking.
+**/
+impl<O, T> BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Copies the fully-spanned elements of one slice into another.
+	///
+	/// Both slices must start at the same index and have the same length.
+	fn sp_copy_elements(&mut self, src: &Self) {
+		if let (
+			DomainMut::Region { body: to, .. },
+			Domain::Region { body: from, .. },
+		) = (self.domain_mut(), src.domain())
+		{
+			for (to, from) in to.iter_mut().zip(from) {
+				to.store_value(from.load_value());
+			}
+		}
+	}
+
+	/// Moves a range of bits within the slice a whole element at a time.
+	///
+	/// This applies when the source and destination start at the same index
+	/// within their elements, and the source fully spans at least one element.
+	/// Those elements are moved with a single `memmove`. The bits in the
+	/// partially-spanned elements at either end of the source are moved by
+	/// `edge`, before or after the whole elements as overlapping ranges
+	/// require.
+	///
+	/// # Returns
+	///
+	/// Whether the move was performed. When this is `false`, the slice is
+	/// unchanged.
+	///
+	/// # Safety
+	///
+	/// As [`.copy_within_unchecked()`].
+	///
+	/// [`.copy_within_unchecked()`]: Self::copy_within_unchecked
+	pub(crate) unsafe fn sp_copy_within_elements<F>(
+		&mut self,
+		source: Range<usize>,
+		dest: usize,
+		mut edge: F,
+	) -> bool
+	where
+		F: FnMut(&mut Self, Range<usize>, usize),
+	{
+		let width = T::Mem::BITS as usize;
+		let head = self.bitptr().head().value() as usize;
+		let start = head + source.start;
+		if start % width != (head + dest) % width {
+			return false;
+		}
+		//  The element indices of the fully-spanned source elements.
+		let first = start / width + (start % width != 0) as usize;
+		let last = (head + source.end) / width;
+		if first >= last {
+			return false;
+		}
+		let to = (first * width + dest - source.start) / width;
+
+		let body_start = first * width - head;
+		let body_end = last * width - head;
+		let front = (source.start .. body_start, dest);
+		let back = (body_end .. source.end, dest + body_end - source.start);
+		//  Moving towards the back must read the back edge before the body
+		//  overwrites it, and moving towards the front, the front edge.
+		let (before, after) = if dest > source.start {
+			(back, front)
+		}
+		else {
+			(front, back)
+		};
+
+		edge(self, before.0, before.1);
+		let base = self.bitptr().pointer().to_mut() as *mut T::Mem;
+		ptr::copy(base.add(first), base.add(to), last - first);
+		edge(self, after.0, after.1);
+		true
+	}
+
+	/// Exchanges the contents of two slices.
+	///
+	/// Both slices must start at the same index and have the same length. Their
+	/// partial edge elements are swapped one bit at a time, and their
+	/// fully-spanned elements are swapped whole.
+	pub(crate) fn sp_swap_elements(&mut self, other: &mut Self) {
+		if let (
+			BitDomainMut::Region {
+				head: this_head,
+				body: this_body,
+				tail: this_tail,
+			},
+			BitDomainMut::Region {
+				head: that_head,
+				body: that_body,
+				tail: that_tail,
+			},
+		) = (self.bit_domain_mut(), other.bit_domain_mut())
+		{
+			this_head.swap_bits(that_head);
+			if let (
+				DomainMut::Region { body: this, .. },
+				DomainMut::Region { body: that, .. },
+			) = (this_body.domain_mut(), that_body.domain_mut())
+			{
+				for (this, that) in this.iter_mut().zip(that) {
+					mem::swap(this, that);
+				}
+			}
+			this_tail.swap_bits(that_tail);
+			return;
+		}
+		self.swap_bits(other);
+	}
+
+	/// Compares the fully-spanned elements of two slices.
+	///
+	/// Both slices must start at the same index and have the same length.
+	fn sp_eq_elements(&self, other: &Self) -> bool {
+		match (self.domain(), other.domain()) {
+			(
+				Domain::Region { body: this, .. },
+				Domain::Region { body: that, .. },
+			) => this
+				.iter()
+				.map(BitStore::load_value)
+				.eq(that.iter().map(BitStore::load_value)),
+			_ => self.iter().eq(other.iter()),
+		}
+	}
+}
 
 /** Order-specialized function implementations.
 
@@ -35,6 +194,10 @@ impl<T> BitSlice<Lsb0, T>
 where T: BitStore
 {
 	/// Accelerates copies between disjoint slices with batch loads.
+	///
+	/// If both slices start at the same index, only their partial edge
+	/// elements are copied by batch loads, and their fully-spanned elements
+	/// are copied whole.
 	pub(crate) fn sp_copy_from_bitslice(&mut self, src: &Self) {
 		assert_eq!(
 			self.len(),
@@ -42,6 +205,35 @@ where T: BitStore
 			"Copying between slices requires equal lengths"
 		);
 
+		if self.bitptr().head() == src.bitptr().head() {
+			if let (
+				BitDomainMut::Region {
+					head: to_head,
+					body: to_body,
+					tail: to_tail,
+				},
+				BitDomain::Region {
+					head: from_head,
+					body: from_body,
+					tail: from_tail,
+				},
+			) = (self.bit_domain_mut(), src.bit_domain())
+			{
+				to_head.sp_copy_chunks(from_head);
+				to_body.sp_copy_elements(from_body);
+				to_tail.sp_copy_chunks(from_tail);
+				return;
+			}
+		}
+		self.sp_copy_chunks(src);
+	}
+
+	/// Copies between equal-length slices with batch loads.
+	///
+	/// The storage types of the two slices need not match, as each batch is
+	/// moved through a register in which the bits are in slice order.
+	pub(crate) fn sp_copy_chunks<T2>(&mut self, src: &BitSlice<Lsb0, T2>)
+	where T2: BitStore {
 		let chunk_size = <usize as BitMemory>::BITS as usize;
 		for (to, from) in unsafe { self.chunks_mut(chunk_size).remove_alias() }
 			.zip(src.chunks(chunk_size))
@@ -104,16 +296,129 @@ where T: BitStore
 		}
 	}
 
+	/// Accelerates single-bit insertion with one funnel shift per element.
+	///
+	/// Each element’s live bits move towards its most significant bit, and
+	/// the bit pushed out of the top becomes the carry into the next element.
+	pub(crate) fn sp_shift_in_front(&mut self, mut carry: bool) -> bool {
+		for chunk in self.chunks_by_element_mut() {
+			let width = chunk.len();
+			let val = chunk.load_le::<u64>();
+			let out = (val >> (width - 1)) & 1 != 0;
+			chunk.store_le::<u64>(val << 1 | carry as u64);
+			carry = out;
+		}
+		carry
+	}
+
+	/// Accelerates single-bit removal with one funnel shift per element.
+	///
+	/// This walks the elements from the back, moving each element’s live bits
+	/// towards its least significant bit.
+	pub(crate) fn sp_shift_in_back(&mut self, mut carry: bool) -> bool {
+		for chunk in self.chunks_by_element_mut().rev() {
+			let width = chunk.len();
+			let val = chunk.load_le::<u64>();
+			let out = val & 1 != 0;
+			chunk.store_le::<u64>(val >> 1 | (carry as u64) << (width - 1));
+			carry = out;
+		}
+		carry
+	}
+
 	/// Accelerates equality checking with batch loads.
+	///
+	/// If both slices start at the same index, only their partial edge
+	/// elements are compared by batch loads, and their fully-spanned elements
+	/// are compared whole.
 	pub(crate) fn sp_eq(&self, other: &Self) -> bool {
 		if self.len() != other.len() {
 			return false;
 		}
+		if self.bitptr().head() == other.bitptr().head() {
+			if let (
+				BitDomain::Region {
+					head: this_head,
+					body: this_body,
+					tail: this_tail,
+				},
+				BitDomain::Region {
+					head: that_head,
+					body: that_body,
+					tail: that_tail,
+				},
+			) = (self.bit_domain(), other.bit_domain())
+			{
+				return this_head.sp_eq_chunks(that_head)
+					&& this_body.sp_eq_elements(that_body)
+					&& this_tail.sp_eq_chunks(that_tail);
+			}
+		}
+		self.sp_eq_chunks(other)
+	}
+
+	/// Compares equal-length slices with batch loads.
+	fn sp_eq_chunks(&self, other: &Self) -> bool {
 		let chunk_size = <usize as BitMemory>::BITS as usize;
 		self.chunks(chunk_size)
 			.zip(other.chunks(chunk_size))
 			.all(|(a, b)| a.load_le::<usize>() == b.load_le::<usize>())
 	}
+
+	/// Accelerates numeric comparison with batch loads.
+	///
+	/// The most significant bits are at the back of the slice, so the excess
+	/// of the longer slice is at its back, and the common region is compared
+	/// from back to front.
+	pub(crate) fn sp_cmp_numeric<T2>(
+		&self,
+		other: &BitSlice<Lsb0, T2>,
+	) -> Ordering
+	where
+		T2: BitStore,
+	{
+		let width = cmp::min(self.len(), other.len());
+		let (this, this_high) = unsafe { self.split_at_unchecked(width) };
+		let (that, that_high) = unsafe { other.split_at_unchecked(width) };
+		if this_high.any() {
+			return Ordering::Greater;
+		}
+		if that_high.any() {
+			return Ordering::Less;
+		}
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		this.rchunks(chunk_size)
+			.zip(that.rchunks(chunk_size))
+			.map(|(a, b)| a.load_le::<usize>().cmp(&b.load_le::<usize>()))
+			.find(|&ord| ord != Ordering::Equal)
+			.unwrap_or(Ordering::Equal)
+	}
+
+	/// Accelerates mismatch search with batch loads.
+	///
+	/// The front bit of each chunk is the least significant bit of its loaded
+	/// value, so the first difference is at the trailing zeros of the XOR.
+	pub(crate) fn sp_first_mismatch<T2>(
+		&self,
+		other: &BitSlice<Lsb0, T2>,
+	) -> Option<usize>
+	where
+		T2: BitStore,
+	{
+		let width = cmp::min(self.len(), other.len());
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		unsafe { self.get_unchecked(.. width) }
+			.chunks(chunk_size)
+			.zip(unsafe { other.get_unchecked(.. width) }.chunks(chunk_size))
+			.enumerate()
+			.find_map(|(n, (a, b))| {
+				let diff = a.load_le::<usize>() ^ b.load_le::<usize>();
+				if diff == 0 {
+					return None;
+				}
+				Some(n * chunk_size + diff.trailing_zeros() as usize)
+			})
+	}
 }
 
 /** Order-specialized function implementations.
@@ -129,6 +434,10 @@ impl<T> BitSlice<Msb0, T>
 where T: BitStore
 {
 	/// Accelerates copies between disjoint slices with batch loads.
+	///
+	/// If both slices start at the same index, only their partial edge
+	/// elements are copied by batch loads, and their fully-spanned elements
+	/// are copied whole.
 	pub(crate) fn sp_copy_from_bitslice(&mut self, src: &Self) {
 		assert_eq!(
 			self.len(),
@@ -136,6 +445,35 @@ where T: BitStore
 			"Copying between slices requires equal lengths"
 		);
 
+		if self.bitptr().head() == src.bitptr().head() {
+			if let (
+				BitDomainMut::Region {
+					head: to_head,
+					body: to_body,
+					tail: to_tail,
+				},
+				BitDomain::Region {
+					head: from_head,
+					body: from_body,
+					tail: from_tail,
+				},
+			) = (self.bit_domain_mut(), src.bit_domain())
+			{
+				to_head.sp_copy_chunks(from_head);
+				to_body.sp_copy_elements(from_body);
+				to_tail.sp_copy_chunks(from_tail);
+				return;
+			}
+		}
+		self.sp_copy_chunks(src);
+	}
+
+	/// Copies between equal-length slices with batch loads.
+	///
+	/// The storage types of the two slices need not match, as each batch is
+	/// moved through a register in which the bits are in slice order.
+	pub(crate) fn sp_copy_chunks<T2>(&mut self, src: &BitSlice<Msb0, T2>)
+	where T2: BitStore {
 		let chunk_size = <usize as BitMemory>::BITS as usize;
 		for (to, from) in unsafe { self.chunks_mut(chunk_size).remove_alias() }
 			.zip(src.chunks(chunk_size))
@@ -180,14 +518,132 @@ where T: BitStore
 		}
 	}
 
+	/// Accelerates single-bit insertion with one funnel shift per element.
+	///
+	/// Each element’s live bits move towards its least significant bit, and
+	/// the bit pushed out of the bottom becomes the carry into the next
+	/// element.
+	pub(crate) fn sp_shift_in_front(&mut self, mut carry: bool) -> bool {
+		for chunk in self.chunks_by_element_mut() {
+			let width = chunk.len();
+			let val = chunk.load_be::<u64>();
+			let out = val & 1 != 0;
+			chunk.store_be::<u64>(val >> 1 | (carry as u64) << (width - 1));
+			carry = out;
+		}
+		carry
+	}
+
+	/// Accelerates single-bit removal with one funnel shift per element.
+	///
+	/// This walks the elements from the back, moving each element’s live bits
+	/// towards its most significant bit.
+	pub(crate) fn sp_shift_in_back(&mut self, mut carry: bool) -> bool {
+		for chunk in self.chunks_by_element_mut().rev() {
+			let width = chunk.len();
+			let val = chunk.load_be::<u64>();
+			let out = (val >> (width - 1)) & 1 != 0;
+			chunk.store_be::<u64>(val << 1 | carry as u64);
+			carry = out;
+		}
+		carry
+	}
+
 	/// Accelerates equality checking with batch loads.
+	///
+	/// If both slices start at the same index, only their partial edge
+	/// elements are compared by batch loads, and their fully-spanned elements
+	/// are compared whole.
 	pub(crate) fn sp_eq(&self, other: &Self) -> bool {
 		if self.len() != other.len() {
 			return false;
 		}
+		if self.bitptr().head() == other.bitptr().head() {
+			if let (
+				BitDomain::Region {
+					head: this_head,
+					body: this_body,
+					tail: this_tail,
+				},
+				BitDomain::Region {
+					head: that_head,
+					body: that_body,
+					tail: that_tail,
+				},
+			) = (self.bit_domain(), other.bit_domain())
+			{
+				return this_head.sp_eq_chunks(that_head)
+					&& this_body.sp_eq_elements(that_body)
+					&& this_tail.sp_eq_chunks(that_tail);
+			}
+		}
+		self.sp_eq_chunks(other)
+	}
+
+	/// Compares equal-length slices with batch loads.
+	fn sp_eq_chunks(&self, other: &Self) -> bool {
 		let chunk_size = <usize as BitMemory>::BITS as usize;
 		self.chunks(chunk_size)
 			.zip(other.chunks(chunk_size))
 			.all(|(a, b)| a.load_be::<usize>() == b.load_be::<usize>())
 	}
+
+	/// Accelerates numeric comparison with batch loads.
+	///
+	/// The most significant bits are at the front of the slice, so the excess
+	/// of the longer slice is at its front, and the common region is compared
+	/// from front to back.
+	pub(crate) fn sp_cmp_numeric<T2>(
+		&self,
+		other: &BitSlice<Msb0, T2>,
+	) -> Ordering
+	where
+		T2: BitStore,
+	{
+		let width = cmp::min(self.len(), other.len());
+		let (this_high, this) =
+			unsafe { self.split_at_unchecked(self.len() - width) };
+		let (that_high, that) =
+			unsafe { other.split_at_unchecked(other.len() - width) };
+		if this_high.any() {
+			return Ordering::Greater;
+		}
+		if that_high.any() {
+			return Ordering::Less;
+		}
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		this.chunks(chunk_size)
+			.zip(that.chunks(chunk_size))
+			.map(|(a, b)| a.load_be::<usize>().cmp(&b.load_be::<usize>()))
+			.find(|&ord| ord != Ordering::Equal)
+			.unwrap_or(Ordering::Equal)
+	}
+
+	/// Accelerates mismatch search with batch loads.
+	///
+	/// The front bit of each chunk is the most significant bit of its loaded
+	/// value, which fills only the low `len` bits of the register, so the first
+	/// difference is at the leading zeros of the XOR less the unused high bits.
+	pub(crate) fn sp_first_mismatch<T2>(
+		&self,
+		other: &BitSlice<Msb0, T2>,
+	) -> Option<usize>
+	where
+		T2: BitStore,
+	{
+		let width = cmp::min(self.len(), other.len());
+		let chunk_size = <usize as BitMemory>::BITS as usize;
+		unsafe { self.get_unchecked(.. width) }
+			.chunks(chunk_size)
+			.zip(unsafe { other.get_unchecked(.. width) }.chunks(chunk_size))
+			.enumerate()
+			.find_map(|(n, (a, b))| {
+				let diff = a.load_be::<usize>() ^ b.load_be::<usize>();
+				if diff == 0 {
+					return None;
+				}
+				let unused = chunk_size - a.len();
+				Some(n * chunk_size + diff.leading_zeros() as usize - unused)
+			})
+	}
 }
@@ -3,6 +3,7 @@
 #![cfg(test)]
 
 use crate::{
+	devel::XorShift,
 	index::BitIdx,
 	prelude::*,
 	ptr::BitPtr,
@@ -27,7 +28,7 @@ fn construction() {
 				BitSlice::<LocalBits, u8>::MAX_ELTS,
 			)
 		})
-		.is_none()
+		.is_err()
 	);
 
 	#[cfg(not(miri))]
@@ -38,18 +39,18 @@ fn construction() {
 				BitSlice::<LocalBits, u8>::MAX_ELTS,
 			)
 		})
-		.is_none()
+		.is_err()
 	);
 
 	assert_eq!(
 		unsafe { crate::slice::bits_from_raw_parts(&data, 0, 8) },
-		Some(bits)
+		Ok(bits)
 	);
 	assert!(
 		unsafe {
 			crate::slice::bits_from_raw_parts::<LocalBits, _>(&data, 0, !0)
 		}
-		.is_none()
+		.is_err()
 	);
 
 	let mut data = 0u8;
@@ -57,7 +58,7 @@ fn construction() {
 		unsafe {
 			crate::slice::bits_from_raw_parts_mut(&mut data as *mut _, 0, 8)
 		},
-		Some(data.view_bits_mut::<LocalBits>())
+		Ok(data.view_bits_mut::<LocalBits>())
 	);
 
 	let mut data = [0u16; 2];
@@ -172,6 +173,70 @@ fn batch_copy() {
 	assert!(m.all());
 }
 
+#[test]
+fn aligned_copy_eq() {
+	let src = [0xA5u8, 0x3C, 0x0F, 0x96, 0x69];
+	let mut dst = [0u8; 5];
+
+	let from = &src.view_bits::<LocalBits>()[3 .. 37];
+	let to = &mut dst.view_bits_mut::<LocalBits>()[3 .. 37];
+	to.copy_from_bitslice(from);
+	assert_eq!(to, from);
+	assert_eq!(dst[1 .. 4], src[1 .. 4]);
+	assert!(dst.view_bits::<LocalBits>()[.. 3].not_any());
+
+	let (from, to) =
+		(&src.view_bits::<Msb0>()[5 ..], dst.view_bits_mut::<Msb0>());
+	to[5 ..].copy_from_bitslice(from);
+	assert_eq!(&to[5 ..], from);
+	assert_eq!(dst[1 ..], src[1 ..]);
+
+	let mut other = src;
+	other[2] ^= 0x10;
+	assert_ne!(
+		src.view_bits::<LocalBits>()[1 ..],
+		other.view_bits::<LocalBits>()[1 ..]
+	);
+	assert_eq!(
+		src.view_bits::<Msb0>()[.. 16],
+		other.view_bits::<Msb0>()[.. 16]
+	);
+	assert_ne!(
+		src.view_bits::<Msb0>()[4 .. 36],
+		other.view_bits::<Msb0>()[4 .. 36]
+	);
+}
+
+#[test]
+fn cross_type_copy() {
+	let src = [0xA5u8, 0x3C, 0x0F, 0x96, 0x69, 0x5A, 0xC3, 0x81, 0x7E];
+
+	let mut dst = [0u32; 3];
+	let from = src.view_bits::<Lsb0>();
+	dst.view_bits_mut::<Lsb0>().copy_bits(from, 3 .. 70, 11);
+	assert_eq!(dst.view_bits::<Lsb0>()[11 .. 78], from[3 .. 70]);
+	assert!(dst.view_bits::<Lsb0>()[.. 11].not_any());
+	assert!(dst.view_bits::<Lsb0>()[78 ..].not_any());
+
+	let mut dst = [0u16; 5];
+	let from = src.view_bits::<Msb0>();
+	dst.view_bits_mut::<Msb0>().copy_bits(from, 5 .., 9);
+	assert_eq!(dst.view_bits::<Msb0>()[9 .. 76], from[5 ..]);
+
+	let mut dst = [!0u64];
+	dst.view_bits_mut::<Lsb0>()
+		.copy_bits(src.view_bits::<Msb0>(), .. 4, 60);
+	assert_eq!(dst.view_bits::<Lsb0>()[.. 60], bits![1; 60]);
+	assert_eq!(dst.view_bits::<Lsb0>()[60 ..], bits![1, 0, 1, 0]);
+}
+
+#[test]
+#[should_panic]
+fn copy_bits_overrun() {
+	let src = [0u8; 2];
+	bits![mut 0; 12].copy_bits(src.view_bits::<Lsb0>(), 2 .., 0);
+}
+
 #[test]
 fn query() {
 	let data = [0x0Fu8, !0, 0xF0, 0, 0x0E];
@@ -190,6 +255,17 @@ fn query() {
 	assert_eq!(bits[.. 24].count_ones(), 16);
 	assert_eq!(bits[16 ..].count_zeros(), 17);
 
+	let data = [0x0F0Fu16, !0, 0x8001, 0];
+	let bits = data.view_bits::<Lsb0>();
+	for start in 0 .. bits.len() {
+		for end in start .. bits.len() {
+			let bits = &bits[start .. end];
+			let ones = (0 .. bits.len()).filter(|&idx| bits[idx]).count();
+			assert_eq!(bits.count_ones(), ones);
+			assert_eq!(bits.count_zeros(), bits.len() - ones);
+		}
+	}
+
 	assert!(!bits![0].contains(bits![0, 1]));
 	assert!(bits![0, 1, 0].contains(bits![1, 0]));
 	assert!(bits![0, 1, 0].starts_with(bits![0, 1]));
@@ -218,6 +294,121 @@ fn modify() {
 	assert_eq!(data, 0b0111_1111);
 }
 
+#[test]
+fn swap_with_bitslice() {
+	let orig_a = [0x0123_4567u32, 0x89AB_CDEF, 0x0F1E_2D3C];
+	let orig_b = [0xFEDC_BA98u32, 0x7654_3210, 0xC3D2_E1F0];
+
+	//  Aligned swaps exchange the interior elements whole.
+	for &(start, end) in &[(4, 90), (0, 96), (5, 31), (32, 64)] {
+		let (mut a, mut b) = (orig_a, orig_b);
+		a.view_bits_mut::<Msb0>()[start .. end]
+			.swap_with_bitslice(&mut b.view_bits_mut::<Msb0>()[start .. end]);
+		for idx in 0 .. 96 {
+			let (mut x, mut y) = (
+				orig_a.view_bits::<Msb0>()[idx],
+				orig_b.view_bits::<Msb0>()[idx],
+			);
+			if (start .. end).contains(&idx) {
+				core::mem::swap(&mut x, &mut y);
+			}
+			assert_eq!(a.view_bits::<Msb0>()[idx], x);
+			assert_eq!(b.view_bits::<Msb0>()[idx], y);
+		}
+	}
+
+	//  Misaligned swaps, and swaps between different types, go bit by bit.
+	let (mut a, mut b) = (orig_a, orig_b);
+	a.view_bits_mut::<Lsb0>()[3 .. 67]
+		.swap_with_bitslice(&mut b.view_bits_mut::<Lsb0>()[10 .. 74]);
+	assert_eq!(
+		a.view_bits::<Lsb0>()[3 .. 67],
+		orig_b.view_bits::<Lsb0>()[10 .. 74]
+	);
+	assert_eq!(
+		b.view_bits::<Lsb0>()[10 .. 74],
+		orig_a.view_bits::<Lsb0>()[3 .. 67]
+	);
+
+	let mut c = [0u8; 12];
+	a.view_bits_mut::<Lsb0>()
+		.swap_with_bitslice(c.view_bits_mut::<Msb0>());
+	assert!(a.view_bits::<Lsb0>().not_any());
+	assert_eq!(
+		c.view_bits::<Msb0>()[3 .. 67],
+		orig_b.view_bits::<Lsb0>()[10 .. 74]
+	);
+}
+
+#[test]
+fn fill() {
+	let mut data = [0u32; 3];
+	let bits = &mut data.view_bits_mut::<Msb0>()[3 .. 90];
+	bits.fill(true);
+	assert_eq!(data, [0x1FFF_FFFF, !0, 0xFFFF_FFC0]);
+	data.view_bits_mut::<Msb0>()[.. 40].fill(false);
+	assert_eq!(data, [0, 0x00FF_FFFF, 0xFFFF_FFC0]);
+
+	let mut data = [0u8; 4];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[5 .. 29];
+	let mut calls = 0;
+	bits.fill_with(|| {
+		calls += 1;
+		calls % 3 == 0
+	});
+	assert_eq!(calls, 24);
+	let ones = [7, 10, 13, 16, 19, 22, 25, 28].iter().copied();
+	assert!(data.view_bits::<Lsb0>().iter_ones().eq(ones));
+}
+
+#[test]
+fn copy_within_elements() {
+	fn check<O>()
+	where O: BitOrder {
+		let mut rng = XorShift::default();
+		let mut orig = [0u16; 12];
+		for elem in orig.iter_mut() {
+			*elem = rng.next_u32() as u16;
+		}
+		let src = &orig.view_bits::<O>()[2 ..];
+
+		//  Aligned moves in both directions, with and without partial edges,
+		//  and unaligned or element-free moves that take the fallback.
+		for &(start, end, dest) in &[
+			(5, 150, 37),
+			(37, 182, 5),
+			(3, 140, 51),
+			(51, 188, 3),
+			(14, 174, 30),
+			(30, 190, 14),
+			(20, 30, 4),
+			(1, 100, 50),
+			(50, 149, 1),
+		] {
+			let mut data = orig;
+			let bits = &mut data.view_bits_mut::<O>()[2 ..];
+			bits.copy_within(start .. end, dest);
+			for (idx, bit) in bits.iter().enumerate() {
+				let from = if (dest .. dest + end - start).contains(&idx) {
+					idx - dest + start
+				}
+				else {
+					idx
+				};
+				assert_eq!(*bit, src[from], "{:?} -> {}", start .. end, dest);
+			}
+		}
+	}
+
+	crate::order! {
+		struct HiLo = |idx, bits| idx ^ 4;
+	}
+
+	check::<Lsb0>();
+	check::<Msb0>();
+	check::<HiLo>();
+}
+
 #[test]
 fn split() {
 	assert!(
@@ -381,6 +572,23 @@ fn alignment() {
 	assert!(l2.is_empty());
 	assert!(r2.is_empty());
 	assert_eq!(c1.len(), c2.len());
+
+	//  Widening views cover every register fully inside the slice.
+	let words = [0x0123_4567_89AB_CDEFu64; 4];
+	let bytes =
+		unsafe { core::slice::from_raw_parts(words.as_ptr() as *const u8, 32) };
+	for start in (0 .. 80).step_by(3) {
+		for end in (start .. 256).step_by(5) {
+			let bits = &bytes.view_bits::<Lsb0>()[start .. end];
+			let (l, c, r) = unsafe { bits.align_to::<u64>() };
+			let lo = (start + 63) & !63;
+			let hi = end & !63;
+			assert_eq!(c.len(), hi.saturating_sub(lo));
+			assert_eq!(l.len() + c.len() + r.len(), bits.len());
+			#[cfg(target_endian = "little")]
+			assert!(l.iter().chain(c.iter()).chain(r.iter()).eq(bits.iter()));
+		}
+	}
 }
 
 #[test]
@@ -419,6 +627,730 @@ fn shift() {
 	assert_eq!(bits, bits![1, 1, 0, 0, 0, 0]);
 	bits.shift_right(2);
 	assert_eq!(bits, bits![0, 0, 1, 1, 0, 0]);
+	bits.shift_right(6);
+	assert!(bits.not_any());
+
+	//  Long shifts move whole elements, and saturate to zero.
+	let mut data = [0x1234_5678u32, 0x9ABC_DEF0, 0x0FED_CBA9];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 92];
+	bits.shift_left(32);
+	assert_eq!(data, [0x9ABC_DEF8, 0x0FED_CBA9, 0]);
+	let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 92];
+	bits.shift_right(36);
+	assert_eq!(data, [0x0000_0008, 0xABCD_EF00, 0x0EDC_BA99]);
+	let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 92];
+	bits.shift_left(usize::MAX);
+	assert_eq!(data, [0x0000_0008, 0, 0]);
+}
+
+#[test]
+fn overflowing_shift() {
+	let mut data = 0xA5u8;
+	let bits = data.view_bits_mut::<Msb0>();
+	let mut out = 0u16;
+	bits.shift_right_overflowing_into(3, &mut out.view_bits_mut::<Lsb0>()[.. 3]);
+	assert_eq!(out, 0b101);
+	assert_eq!(data, 0x14);
+
+	let bits = data.view_bits_mut::<Msb0>();
+	bits.shift_left_overflowing_into(8, &mut out.view_bits_mut::<Lsb0>()[8 ..]);
+	assert_eq!(out, 0x2805);
+	assert_eq!(data, 0);
+
+	#[cfg(feature = "alloc")]
+	{
+		let bits = bits![mut 0, 1, 1, 0];
+		assert_eq!(bits.shift_right_overflowing(1), bits![0]);
+		assert_eq!(bits.shift_left_overflowing(2), bits![0, 0]);
+		assert_eq!(bits, bits![1, 1, 0, 0]);
+	}
+}
+
+#[test]
+fn numeric_shift() {
+	let mut data = 0x0123_4567u32;
+	let bits = data.view_bits_mut::<Lsb0>();
+	*bits <<= 4;
+	*bits >>= 8;
+	assert_eq!(data, 0x0123_4567u32 << 4 >> 8);
+
+	let mut data = [0x12u8, 0x34, 0x56];
+	let bits = data.view_bits_mut::<Msb0>();
+	*bits >>= 12;
+	assert_eq!(data, [0x00, 0x01, 0x23]);
+	data.view_bits_mut::<Msb0>().wrapping_shl(24 + 8);
+	assert_eq!(data, [0x01, 0x23, 0x00]);
+
+	let bits = data.view_bits_mut::<Msb0>();
+	assert!(!bits.checked_shr(24));
+	assert!(bits.checked_shr(4));
+	assert_eq!(data, [0x00, 0x12, 0x30]);
+
+	let arr = bitarr![Lsb0, u8; 1, 0, 0, 0, 0, 0, 0, 1];
+	assert_eq!((arr << 1).value(), [0x02]);
+	assert_eq!((arr >> 7).value(), [0x01]);
+	assert_eq!((arr << 8).value(), [0x00]);
+
+	bits![mut Lsb0, u8;].wrapping_shr(3);
+}
+
+#[test]
+fn for_each_mut() {
+	let func = |idx: usize, bit: bool| bit ^ (idx % 5 < 2);
+	for start in 0 .. 10 {
+		for end in start .. 40 {
+			let mut a = [0x5Au8, 0xC3, 0x0F, 0xE1, 0x96, 0x3C];
+			let mut b = a;
+			a.view_bits_mut::<Lsb0>()[start .. end].for_each(func);
+			b.view_bits_mut::<Lsb0>()[start .. end].for_each_mut(func);
+			assert_eq!(a, b);
+
+			let mut c = [0x5A3Cu16, 0x0FE1, 0x96C3];
+			let mut d = c;
+			c.view_bits_mut::<Msb0>()[start .. end].for_each(func);
+			d.view_bits_mut::<Msb0>()[start .. end].for_each_mut(func);
+			assert_eq!(c, d);
+		}
+	}
+
+	let mut data = [0u16; 2];
+	let (left, right) = data.view_bits_mut::<Lsb0>().split_at_mut(9);
+	let mut seen = 0;
+	right.for_each_mut(|idx, _| {
+		assert_eq!(idx, seen);
+		seen += 1;
+		true
+	});
+	left.for_each_mut(|idx, _| idx == 8);
+	assert_eq!(seen, 23);
+	assert_eq!(data, [0xFF00, 0xFFFF]);
+}
+
+#[test]
+fn zip_with() {
+	let src = [0x5Au8, 0xC3, 0x0F, 0xE1, 0x96, 0x3C];
+	for start in 0 .. 10 {
+		for other in 0 .. 10 {
+			for len in 0 .. 30 {
+				let mut a = [0x33u8; 6];
+				let mut b = a;
+				let lhs = &mut a.view_bits_mut::<Msb0>()[start ..][.. len];
+				let rhs = &src.view_bits::<Msb0>()[other ..][.. len];
+				lhs.zip_with(rhs, |x, y| x ^ y);
+				b.view_bits_mut::<Msb0>()[start ..][.. len] ^=
+					rhs.iter().copied();
+				assert_eq!(a, b, "{} {} {}", start, other, len);
+			}
+		}
+	}
+
+	let mut data = [0u16; 3];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 40];
+	bits.zip_with(bits![Lsb0, u16; 1; 36], |x, y| {
+		assert_eq!(x, 0);
+		!y
+	});
+	assert!(bits.not_any());
+	bits.zip_with(bits![Lsb0, u16; 0; 36], |_, _| !0);
+	assert_eq!(data, [0xFFF0, 0xFFFF, 0x00FF]);
+}
+
+#[test]
+fn cmp_numeric() {
+	use core::cmp::Ordering;
+
+	let values = [0u128, 1, 2, 0xFF, 0x100, 0x1234_5678_9ABC_DEF0, !0 >> 1];
+	for &a in values.iter() {
+		for &b in values.iter() {
+			for &(la, lb) in [(120, 120), (120, 100), (80, 120)].iter() {
+				let a = a & ((1 << la) - 1);
+				let b = b & ((1 << lb) - 1);
+
+				let mut x = [0u8; 16];
+				let mut y = [0u32; 4];
+				x.view_bits_mut::<Lsb0>()[.. la].store_le(a);
+				y.view_bits_mut::<Lsb0>()[.. lb].store_le(b);
+				assert_eq!(
+					x.view_bits::<Lsb0>()[.. la]
+						.cmp_numeric(&y.view_bits::<Lsb0>()[.. lb]),
+					a.cmp(&b),
+				);
+
+				x.view_bits_mut::<Msb0>()[1 ..][.. la].store_be(a);
+				y.view_bits_mut::<Msb0>()[3 ..][.. lb].store_be(b);
+				assert_eq!(
+					x.view_bits::<Msb0>()[1 ..][.. la]
+						.cmp_numeric(&y.view_bits::<Msb0>()[3 ..][.. lb]),
+					a.cmp(&b),
+				);
+			}
+		}
+	}
+
+	assert_eq!(
+		BitSlice::<Lsb0, u8>::empty().cmp_numeric(bits![Lsb0, u8; 0, 0]),
+		Ordering::Equal
+	);
+}
+
+#[test]
+fn write_text() {
+	let data = [0x96u8, 0x3C, 0xF0];
+	let bits = data.view_bits::<Lsb0>();
+	let mut buf = [b'.'; 24];
+
+	assert_eq!(bits[.. 0].write_binary(&mut buf[.. 0]), Ok(""));
+	assert_eq!(bits[.. 10].write_binary(&mut buf), Ok("0110100100"));
+	assert_eq!(bits.write_binary(&mut buf[.. 23]), Err(24));
+	assert_eq!(buf[10], b'.');
+
+	assert_eq!(bits.write_hex(&mut buf), Ok("693c0f"));
+	assert_eq!(bits[1 ..].write_upper_hex(&mut buf), Ok("693C0F"));
+	assert_eq!(bits[.. 18].write_hex(&mut buf), Ok("1a4f0"));
+	assert_eq!(bits[.. 18].write_hex(&mut buf[.. 4]), Err(5));
+}
+
+#[test]
+fn display_numeral() {
+	let data = [0x1234u16, 0xABCD];
+	let bits = data.view_bits::<Lsb0>();
+	let bytes = [0x2Cu8, 0x48, 0xB3, 0xD5];
+	let other = bytes.view_bits::<Msb0>();
+	assert_eq!(bits, other);
+
+	assert_eq!(format!("{:x}", bits.display_numeral()), "2c48b3d5");
+	assert_eq!(format!("{:x}", other.display_numeral()), "2c48b3d5");
+	assert_eq!(format!("{:#o}", bits[.. 9].display_numeral()), "0o130");
+	assert_eq!(format!("{:#b}", other[3 .. 7].display_numeral()), "0b0110");
+	assert_eq!(format!("{:X}", bits[.. 0].display_numeral()), "0");
+	assert_eq!(format!("{:#X}", other[28 ..].display_numeral()), "0x5");
+
+	assert_eq!(format!("{:08x}", bits[.. 20].display_numeral()), "0002c48b");
+	assert_eq!(
+		format!("{:#08b}", other[3 .. 7].display_numeral()),
+		"0b000110"
+	);
+	assert_eq!(format!("{:>6o}", bits[.. 9].display_numeral()), "   130");
+	assert_eq!(format!("{:*<4X}", bits[.. 0].display_numeral()), "0***");
+}
+
+#[test]
+fn eq_ignore_trailing_zeros() {
+	let data = [0x5Au8, 0x00, 0x01];
+	let bits = data.view_bits::<Lsb0>();
+	let other = 0x005Au16;
+
+	assert!(bits[.. 16].eq_ignore_trailing_zeros(other.view_bits::<Lsb0>()));
+	assert!(bits[.. 7].eq_ignore_trailing_zeros(other.view_bits::<Lsb0>()));
+	assert!(!bits.eq_ignore_trailing_zeros(other.view_bits::<Lsb0>()));
+	assert!(!bits[1 ..].eq_ignore_trailing_zeros(&bits[.. 16]));
+	let seven = bits![Msb0, u8; 0, 1, 0, 1, 1, 0, 1];
+	assert!(bits[.. 8].eq_ignore_trailing_zeros(seven));
+	assert!(
+		BitSlice::<Lsb0, u8>::empty().eq_ignore_trailing_zeros(&bits[8 .. 16])
+	);
+}
+
+#[test]
+fn try_index() {
+	use crate::slice::IndexError;
+
+	let mut data = 0u8;
+	let bits = &mut data.view_bits_mut::<Lsb0>()[2 .. 6];
+
+	assert_eq!(bits.try_set(3, true), Ok(()));
+	assert_eq!(
+		bits.try_set(4, true),
+		Err(IndexError::OutOfBounds { index: 4, len: 4 })
+	);
+	assert_eq!(bits.try_swap(3, 0), Ok(()));
+	assert_eq!(
+		bits.try_swap(0, 9),
+		Err(IndexError::OutOfBounds { index: 9, len: 4 })
+	);
+	assert_eq!(bits, bits![1, 0, 0, 0]);
+
+	assert!(bits.try_split_at(4).is_ok());
+	assert_eq!(
+		bits.try_split_at(5),
+		Err(IndexError::PastEnd { index: 5, len: 4 })
+	);
+	let (left, right) = bits.try_split_at_mut(4).unwrap();
+	assert_eq!(left.len(), 4);
+	assert!(right.is_empty());
+	let (left, right) = bits.split_at_mut(4);
+	assert_eq!((left.len(), right.len()), (4, 0));
+
+	assert_eq!(bits.try_get_range(..).map(BitSlice::len), Ok(4));
+	assert_eq!(
+		bits.try_get_range(3 .. 2),
+		Err(IndexError::Inverted { start: 3, end: 2 })
+	);
+	assert_eq!(
+		bits.try_get_range(.. 5),
+		Err(IndexError::PastEnd { index: 5, len: 4 })
+	);
+	bits.try_get_range_mut(1 ..= 2).unwrap().set_all(true);
+	assert_eq!(data, 0b0001_1100);
+}
+
+/// Views six elements of `data`, less three bits at the front and five at the
+/// back, so that the view has partial elements at both ends.
+///
+/// The bits of each range in `ones` are set first. Ranges are counted from the
+/// start of `data`, and are clipped to its length.
+fn partial_view<'a, O, T>(
+	data: &'a mut [T; 6],
+	ones: &[core::ops::Range<usize>],
+) -> &'a mut BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let full = data.view_bits_mut::<O>();
+	let len = full.len();
+	for range in ones {
+		if range.start < len {
+			full[range.start .. core::cmp::min(range.end, len)].set_all(true);
+		}
+	}
+	&mut full[3 .. len - 5]
+}
+
+#[test]
+fn next_prev_one() {
+	fn check<O, T>()
+	where
+		O: BitOrder,
+		T: BitStore + Default,
+	{
+		let mut data: [T; 6] = Default::default();
+		let ones = [1, 2, 9, 40, 41, 100, 200, 300]
+			.iter()
+			.map(|&idx| idx .. idx + 1)
+			.collect::<Vec<_>>();
+		let bits = &*partial_view::<O, T>(&mut data, &ones);
+		let ones = bits.iter_ones().collect::<Vec<_>>();
+		assert!(!ones.is_empty());
+
+		for idx in 0 ..= bits.len() + 1 {
+			let next = ones.iter().copied().find(|&n| n > idx);
+			let prev = ones.iter().copied().rev().find(|&n| n < idx);
+			assert_eq!(bits.next_one_after(idx), next, "after {}", idx);
+			assert_eq!(bits.prev_one_before(idx), prev, "before {}", idx);
+		}
+		assert_eq!(bits.next_one_after(usize::MAX), None);
+		assert_eq!(bits.prev_one_before(usize::MAX), ones.last().copied());
+		assert_eq!(bits[.. 0].prev_one_before(9), None);
+	}
+
+	check::<Lsb0, u8>();
+	check::<Msb0, u8>();
+	check::<Lsb0, u16>();
+	check::<Msb0, u32>();
+	check::<Lsb0, u64>();
+	check::<Msb0, usize>();
+}
+
+#[test]
+fn one_ranges() {
+	use core::ops::Range;
+
+	fn check<O, T>()
+	where
+		O: BitOrder,
+		T: BitStore + Default,
+	{
+		let mut data: [T; 6] = Default::default();
+		let ones = [0 .. 5, 7 .. 8, 10 .. 70, 71 .. 72, 90 .. 300];
+		let bits = &*partial_view::<O, T>(&mut data, &ones);
+
+		let mut runs = Vec::<Range<usize>>::new();
+		for (idx, bit) in bits.iter().copied().enumerate() {
+			match runs.last_mut() {
+				Some(run) if bit && run.end == idx => run.end += 1,
+				_ if bit => runs.push(idx .. idx + 1),
+				_ => {},
+			}
+		}
+		assert_eq!(bits.iter_one_ranges().collect::<Vec<_>>(), runs);
+		let mut back = bits.iter_one_ranges().rev().collect::<Vec<_>>();
+		back.reverse();
+		assert_eq!(back, runs);
+
+		let mut iter = bits.iter_one_ranges();
+		assert_eq!(iter.next_back(), runs.last().cloned());
+		assert_eq!(iter.next(), Some(0 .. 2));
+		assert_eq!(iter.count(), runs.len() - 2);
+		assert!(bits[5 .. 7].iter_one_ranges().next().is_none());
+	}
+
+	check::<Lsb0, u8>();
+	check::<Msb0, u8>();
+	check::<Lsb0, u16>();
+	check::<Msb0, u32>();
+	check::<Lsb0, u64>();
+	check::<Msb0, usize>();
+}
+
+#[test]
+fn set_ranges() {
+	let mut bits = bitvec![Lsb0, u16; 0; 100];
+	let ranges = vec![3 .. 5, 10 .. 70, 60 .. 80, 99 .. 100, 40 .. 40];
+	bits.set_ranges(ranges.iter().cloned(), true);
+	let runs = bits.iter_one_ranges().collect::<Vec<_>>();
+	assert_eq!(runs, [3 .. 5, 10 .. 80, 99 .. 100]);
+
+	bits[1 ..].set_ranges(vec![15 .. 20, 0 .. 3], false);
+	let runs = bits.iter_one_ranges().collect::<Vec<_>>();
+	assert_eq!(runs, [4 .. 5, 10 .. 16, 21 .. 80, 99 .. 100]);
+
+	bits.set_ranges(Some(..), true);
+	assert!(bits.all());
+}
+
+#[test]
+#[should_panic(expected = "Range out of bounds")]
+fn set_ranges_past_end() {
+	bits![mut 0; 10].set_ranges(Some(5 ..= 10), true);
+}
+
+#[test]
+fn gather_scatter() {
+	let mut data = [0u16; 4];
+	let bits = data.view_bits_mut::<Lsb0>();
+	let indices = [63, 0, 17, 40, 17, 2];
+	bits.scatter(&indices, bits![Msb0, u8; 1, 1, 0, 1, 1, 1]);
+	assert_eq!(bits.iter_ones().collect::<Vec<_>>(), [0, 2, 17, 40, 63]);
+
+	let mut out = bitvec![Msb0, u8; 0; 8];
+	bits.gather_into(&indices, &mut out[1 .. 7]);
+	assert_eq!(out, bits![0, 1, 1, 1, 1, 1, 1, 0]);
+	assert_eq!(bits.gather(&[1, 2, 3]), bits![0, 1, 0]);
+
+	let empty: &[usize] = &[];
+	bits.scatter(empty, bits![]);
+	bits.gather_into(empty, BitSlice::<Lsb0, u8>::empty_mut());
+}
+
+#[test]
+#[should_panic(expected = "Index 64 out of bounds: 64")]
+fn scatter_out_of_bounds() {
+	let mut data = 0u64;
+	data.view_bits_mut::<Msb0>()
+		.scatter(&[3, 64, 5], bits![1, 1, 1]);
+}
+
+#[test]
+fn permute() {
+	let perm = [3, 0, 7, 5, 1, 6, 2, 4, 9, 8];
+	let data = [0b1001_0111u8, 0b01_000000];
+	let bits = &data.view_bits::<Msb0>()[.. 10];
+	let out = bits.permute(&perm);
+	for (n, &src) in perm.iter().enumerate() {
+		assert_eq!(out[n], bits[src]);
+	}
+
+	let mut inplace = bitvec![Lsb0, u32; 0; 12];
+	inplace[2 ..].clone_from_bitslice(bits);
+	inplace[2 ..].permute_in_place(&perm);
+	assert_eq!(inplace[2 ..], out);
+	assert!(inplace[.. 2].not_any());
+
+	let identity = (0 .. 10).collect::<Vec<_>>();
+	assert_eq!(bits.permute(&identity), bits);
+	bits![mut].permute_in_place(&[]);
+}
+
+#[test]
+#[should_panic(expected = "Index 2 is repeated in the permutation")]
+fn permute_repeated() {
+	bits![mut 0, 1, 1].permute_in_place(&[2, 1, 2]);
+}
+
+#[test]
+fn mismatches() {
+	use core::cmp;
+
+	fn check<O1, T1, O2, T2>()
+	where
+		O1: BitOrder,
+		T1: BitStore + Default,
+		O2: BitOrder,
+		T2: BitStore + Default,
+	{
+		let mut a: [T1; 6] = Default::default();
+		let mut b: [T2; 6] = Default::default();
+		let a = partial_view::<O1, T1>(&mut a, &[]);
+		let b = &mut partial_view::<O2, T2>(&mut b, &[])[2 ..];
+		let len = cmp::min(a.len(), b.len());
+		for idx in (0 .. len).filter(|n| n % 7 == 0 || n % 5 == 1) {
+			a.set(idx, true);
+			b.set(idx, true);
+		}
+		assert_eq!(a.first_mismatch(b), None);
+		assert_eq!(a.iter_mismatches(b).next(), None);
+
+		let flips = [0, 1, 13, 63, 64, 65, 100, 130, 200, 300];
+		let flips = flips.iter().copied().filter(|&n| n < len);
+		for idx in flips.clone() {
+			let bit = b[idx];
+			b.set(idx, !bit);
+		}
+		let expected = flips.collect::<Vec<_>>();
+		assert_eq!(a.first_mismatch(b), expected.first().copied());
+		assert_eq!(a.iter_mismatches(b).collect::<Vec<_>>(), expected);
+		assert_eq!(b[.. len].iter_mismatches(a).collect::<Vec<_>>(), expected);
+		assert_eq!(a[2 ..].first_mismatch(&b[2 ..]), Some(11));
+	}
+
+	check::<Lsb0, u8, Lsb0, u8>();
+	check::<Lsb0, u8, Lsb0, u32>();
+	check::<Msb0, u16, Msb0, u8>();
+	check::<Msb0, u64, Msb0, usize>();
+	check::<Lsb0, u16, Msb0, u32>();
+	check::<Msb0, u8, Lsb0, u64>();
+}
+
+#[test]
+fn length_policies() {
+	use crate::slice::LengthMismatch;
+
+	let short = bits![Msb0, u16; 0, 1, 1, 0];
+
+	let bits = bits![mut 1, 1, 0, 0, 1, 1];
+	bits.bitand_assign_truncating(short);
+	assert_eq!(bits, bits![0, 1, 0, 0, 1, 1]);
+	bits.bitand_assign_zero_extending(short);
+	assert_eq!(bits, bits![0, 1, 0, 0, 0, 0]);
+
+	bits.bitor_assign_truncating(short);
+	assert_eq!(bits, bits![0, 1, 1, 0, 0, 0]);
+	bits.bitxor_assign_zero_extending(bits![1; 8]);
+	assert_eq!(bits, bits![1, 0, 0, 1, 1, 1]);
+	bits.bitxor_assign_truncating(short);
+	assert_eq!(bits, bits![1, 1, 1, 1, 1, 1]);
+	bits.bitor_assign_zero_extending(short);
+	assert!(bits.all());
+
+	let err = bits.try_bitxor_assign(short).unwrap_err();
+	assert_eq!((err.left(), err.right()), (6, 4));
+	assert!(bits.all());
+	assert_eq!(
+		crate::error::Error::from(bits.try_bitor_assign(short).unwrap_err()),
+		crate::error::Error::LengthMismatch { left: 6, right: 4 },
+	);
+	let bits = &mut bits[.. 4];
+	bits.try_bitand_assign(short).unwrap();
+	assert_eq!(bits, short);
+	bits.try_bitor_assign(bits![1, 0, 0, 0]).unwrap();
+	bits.try_bitxor_assign(bits![0, 1, 0, 1]).unwrap();
+	assert_eq!(bits, bits![1, 0, 1, 1]);
+	let _: Result<(), LengthMismatch> = bits.try_bitand_assign(bits![]);
+}
+
+#[test]
+fn apply_mask() {
+	let pattern = 0b1001_0110u8;
+	let base = [0x0Fu8, 0x33, 0x55, 0xF0];
+	let expect = |op: fn(u8, u8) -> u8, range: core::ops::Range<usize>| {
+		let mut out = base;
+		for idx in range {
+			let (elt, bit) = (idx / 8, 7 - idx % 8);
+			let new = op(base[elt], pattern) >> bit & 1;
+			out[elt] = out[elt] & !(1 << bit) | new << bit;
+		}
+		out
+	};
+
+	for range in vec![0 .. 32, 3 .. 29, 2 .. 6, 8 .. 24, 9 .. 9] {
+		let mut data = base;
+		data.view_bits_mut::<Msb0>()[range.clone()].apply_mask_and(pattern);
+		assert_eq!(data, expect(|a, b| a & b, range.clone()));
+
+		let mut data = base;
+		data.view_bits_mut::<Msb0>()[range.clone()].apply_mask_or(pattern);
+		assert_eq!(data, expect(|a, b| a | b, range.clone()));
+
+		let mut data = base;
+		data.view_bits_mut::<Msb0>()[range.clone()].apply_mask_xor(pattern);
+		assert_eq!(data, expect(|a, b| a ^ b, range));
+	}
+
+	let mut data = [core::cell::Cell::new(0u16), core::cell::Cell::new(0)];
+	data.view_bits_mut::<Lsb0>()[4 .. 28].apply_mask_or(!0);
+	assert_eq!([data[0].get(), data[1].get()], [0xFFF0, 0x0FFF]);
+}
+
+radium::if_atomic! {
+	if atomic(32) {
+		#[test]
+		fn find_and_set_first_zero() {
+			use core::sync::atomic::AtomicU32;
+
+			static DATA: [AtomicU32; 7] = [
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+				AtomicU32::new(0),
+			];
+			let bits = &DATA.view_bits::<Msb0>()[5 .. 205];
+			bits.set_aliased(0, true);
+			bits.set_aliased(2, true);
+			assert_eq!(bits.find_and_set_first_zero(), Some(1));
+			assert_eq!(bits.find_and_set_first_zero(), Some(3));
+			assert!(!DATA.view_bits::<Msb0>()[.. 5].any());
+
+			#[cfg(feature = "std")]
+			{
+				let threads = (0 .. 4)
+					.map(|_| {
+						std::thread::spawn(move || {
+							let mut claimed = Vec::new();
+							while let Some(idx) = bits.find_and_set_first_zero() {
+								claimed.push(idx);
+							}
+							claimed
+						})
+					})
+					.collect::<Vec<_>>();
+				let mut claimed = threads
+					.into_iter()
+					.flat_map(|handle| handle.join().unwrap())
+					.collect::<Vec<_>>();
+				claimed.sort_unstable();
+				assert_eq!(claimed, (4 .. 200).collect::<Vec<_>>());
+			}
+			#[cfg(not(feature = "std"))]
+			while bits.find_and_set_first_zero().is_some() {}
+
+			assert!(bits.all());
+			assert_eq!(bits.find_and_set_first_zero(), None);
+			assert!(!DATA.view_bits::<Msb0>()[205 ..].any());
+		}
+
+		#[test]
+		#[cfg(feature = "alloc")]
+		fn atomic_storage() {
+			use core::sync::atomic::AtomicU32;
+
+			let mut bv = BitVec::<Lsb0, AtomicU32>::repeat(false, 40);
+			bv.push(true);
+			let bits = bv.as_bitslice();
+			bits[3 .. 35].fill_aliased(true);
+			assert_eq!(bits.count_ones(), 33);
+			assert!(bits.replace_aliased(3, false));
+			assert!(!bits.replace_aliased(3, false));
+			assert!(!bits.replace_aliased(0, true));
+			bits.fill_aliased(false);
+			assert!(bv.not_any());
+
+			#[cfg(feature = "std")]
+			{
+				let shared =
+					std::sync::Arc::new(BitVec::<Lsb0, AtomicU32>::repeat(false, 64));
+				let threads = (0 .. 4)
+					.map(|n| {
+						let shared = shared.clone();
+						std::thread::spawn(move || {
+							for idx in (n .. 64).step_by(4) {
+								assert!(!shared.replace_aliased(idx, true));
+							}
+						})
+					})
+					.collect::<Vec<_>>();
+				for handle in threads {
+					handle.join().unwrap();
+				}
+				assert!(shared.all());
+			}
+		}
+	}
+}
+
+#[test]
+fn element_transforms() {
+	let mut data = [0x0102_0304u32, 0x0506_0708];
+	let bits = data.view_bits_mut::<Msb0>();
+	bits.swap_element_bytes();
+	assert_eq!(data, [0x0403_0201, 0x0807_0605]);
+
+	let (left, right) = data.view_bits_mut::<Lsb0>().split_at_mut(40);
+	left.reverse_element_bits();
+	right.swap_element_bytes();
+	assert_eq!(data, [0x8040_C020, 0x1006_0710]);
+
+	let mut cells = [core::cell::Cell::new(0x00FFu16)];
+	cells.view_bits_mut::<Lsb0>()[4 .. 12].swap_element_bytes();
+	assert_eq!(cells[0].get(), 0x0F0F);
+}
+
+#[test]
+fn whole_elements() {
+	let mut data = [5u16, 6, 7];
+	let bits = data.view_bits_mut::<Msb0>();
+	assert_eq!(bits.try_as_elements(), Some(&[5, 6, 7][..]));
+	assert!(bits[1 ..].try_as_elements().is_none());
+	assert!(bits[.. 40].try_as_element().is_none());
+
+	let (left, right) = bits.split_at_mut(16);
+	assert_eq!(left.try_as_element().map(|elem| elem.load_value()), Some(5));
+	assert!(right.try_as_element().is_none());
+	assert_eq!(right[16 ..].try_as_elements().map(<[_]>::len), Some(1));
+	assert!(right[.. 17].try_as_elements().is_none());
+}
+
+#[test]
+fn packed() {
+	use crate::error::Error;
+
+	let (bits, rest) = BitSlice::from_packed(&[0; 8]).unwrap();
+	assert!(bits.is_empty());
+	assert!(rest.is_empty());
+
+	let wire = [17, 0, 0, 0, 0, 0, 0, 0, 0x12, 0x34, 0x80, 7];
+	let (bits, rest) = BitSlice::from_packed(&wire).unwrap();
+	assert_eq!(bits.len(), 17);
+	assert_eq!(bits[.. 16].load_be::<u16>(), 0x1234);
+	assert!(bits[16]);
+	assert_eq!(bits.as_slice().as_ptr(), wire[8 ..].as_ptr());
+	assert_eq!(rest, &[7]);
+
+	assert_eq!(
+		BitSlice::from_packed(&wire[.. 5]),
+		Err(Error::Truncated {
+			needed: 8,
+			available: 5
+		}),
+	);
+	assert_eq!(
+		BitSlice::from_packed(&wire[.. 10]),
+		Err(Error::Truncated {
+			needed: 11,
+			available: 10
+		}),
+	);
+	assert!(matches!(
+		BitSlice::from_packed(&[0xFF; 16]),
+		Err(Error::TooLong { .. })
+	));
+
+	#[cfg(feature = "alloc")]
+	{
+		let mut data = [0u32; 2];
+		let src = &mut data.view_bits_mut::<Lsb0>()[3 .. 40];
+		src.store_le(0x1F_2E3D_4C5Bu64);
+		let wire = src.to_packed();
+		assert_eq!(wire.len(), 8 + 5);
+		assert_eq!(wire[.. 8], [37, 0, 0, 0, 0, 0, 0, 0]);
+		assert_eq!(wire[12] & 0x07, 0);
+		let (back, rest) = BitSlice::from_packed(&wire).unwrap();
+		assert_eq!(back, src);
+		assert!(rest.is_empty());
+	}
 }
 
 #[test]
@@ -445,6 +1377,43 @@ fn rotate() {
 	bits.rotate_right(6);
 
 	assert_eq!(bits, bits![0, 1, 0, 0, 1, 0]);
+
+	//  Long, unaligned rotations match a per-bit rotation.
+	let mut rng = XorShift::default();
+	let mut data = [0u16; 40];
+	for elem in data.iter_mut() {
+		*elem = rng.next_u32() as u16;
+	}
+	let len = 617;
+	for &by in &[1, 63, 64, 65, 128, 200, 308, 309, 500, 553, 616, 617] {
+		let mut left = data;
+		let mut right = data;
+		let mut expected = [0u16; 40];
+		let src = &data.view_bits::<Msb0>()[3 .. 3 + len];
+		let bits = &mut expected.view_bits_mut::<Msb0>()[.. len];
+		for idx in 0 .. len {
+			bits.set(idx, src[(idx + by) % len]);
+		}
+
+		let bits = &mut left.view_bits_mut::<Msb0>()[3 .. 3 + len];
+		bits.rotate_left(by);
+		assert_eq!(bits, &expected.view_bits::<Msb0>()[.. len]);
+		bits.rotate_right(by);
+		assert_eq!(left, data);
+
+		let bits = &mut right.view_bits_mut::<Msb0>()[3 .. 3 + len];
+		bits.rotate_right(len - by);
+		assert_eq!(bits, &expected.view_bits::<Msb0>()[.. len]);
+	}
+
+	let mut data = [0x0Fu8, 0xA5, 0x33, 0x81, 0xC6];
+	let before = data;
+	let bits = &mut data.view_bits_mut::<Lsb0>()[1 .. 38];
+	bits.rotate_left(23);
+	let before = &before.view_bits::<Lsb0>()[1 .. 38];
+	for idx in 0 .. 37 {
+		assert_eq!(bits[idx], before[(idx + 23) % 37]);
+	}
 }
 
 #[test]
@@ -549,6 +1518,10 @@ fn iter() {
 	let iter2 = iter.clone();
 	let bits: &BitSlice<_, _> = iter2.as_ref();
 	assert!(bits.is_empty());
+
+	let bits = bits![Lsb0, u16; 1, 0, 0, 1, 1];
+	assert!(bits.iter().by_refs().eq(bits.iter()));
+	assert_eq!(bits.iter().by_vals().rev().position(|bit| !bit), Some(2));
 }
 
 #[test]
@@ -567,6 +1540,22 @@ fn iter_mut() {
 	let bitptr = bits.bitptr();
 	assert_eq!(bits.iter_mut().into_slice().bitptr(), bitptr);
 	assert_eq!(bits.iter_mut().as_bitslice().bitptr(), bitptr);
+
+	let mut iter = bits.iter_mut();
+	iter.next_back();
+	assert_eq!(iter.as_bitslice().bitptr(), bits[.. 7].bitptr());
+	assert_eq!(bits.iter().into_bitslice().bitptr(), bitptr);
+
+	for mut bit in bits.iter_mut().by_refs().step_by(2) {
+		*bit = !*bit;
+	}
+	let vals = bits.iter_mut().by_vals().collect::<Vec<_>>();
+	assert_eq!(vals, bits.iter().by_vals().collect::<Vec<_>>());
+	let mut iter = bits.iter_mut();
+	iter.next();
+	iter.next_back();
+	assert_eq!(iter.by_vals().collect::<Vec<_>>(), vals[1 .. 7]);
+	assert_eq!(bits, bits![0, 1, 0, 1, 0, 1, 0, 1]);
 }
 
 #[test]
@@ -593,10 +1582,12 @@ fn chunks() {
 	let mut chunks = bits.chunks(5);
 	assert_eq!(chunks.next().unwrap().bitptr(), bits[.. 5].bitptr());
 	assert_eq!(chunks.next_back().unwrap().bitptr(), bits[15 ..].bitptr());
+	assert_eq!(chunks.as_bitslice().bitptr(), bits[5 .. 15].bitptr());
 
 	let mut chunks = bits.chunks(3);
 	assert_eq!(chunks.nth(2).unwrap().bitptr(), bits[6 .. 9].bitptr());
 	assert_eq!(chunks.nth_back(2).unwrap().bitptr(), bits[9 .. 12].bitptr());
+	assert!(chunks.into_bitslice().is_empty());
 }
 
 #[test]
@@ -642,6 +1633,70 @@ fn chunks_exact() {
 	assert!(chunks.nth_back(1).is_none());
 }
 
+#[test]
+fn array_chunks() {
+	let data = [0x1234u16, 0x5678, 0x9ABC];
+	let bits = &data.view_bits::<Lsb0>()[4 ..];
+
+	let mut chunks = bits.array_chunks::<u8>();
+	assert_eq!(chunks.len(), 5);
+	assert_eq!(chunks.remainder(), &bits[40 ..]);
+	assert_eq!(chunks.next().unwrap().value(), 0x23);
+	assert_eq!(chunks.next_back().unwrap().value(), 0xAB);
+	assert_eq!(chunks.nth(1).unwrap().value(), 0x67);
+	assert_eq!(chunks.len(), 1);
+	assert_eq!(chunks.last().unwrap().value(), 0xC5);
+
+	let wide = bits.array_chunks::<[u8; 2]>().collect::<Vec<_>>();
+	assert_eq!(wide.len(), 2);
+	assert_eq!(wide[0].value(), [0x23, 0x81]);
+	assert_eq!(wide[1], bits[16 .. 32]);
+	assert!(bits[.. 15].array_chunks::<u16>().next().is_none());
+}
+
+#[test]
+fn chunk_by() {
+	let data = [0xFFF0_000Fu32, 0, 0x8000_0001, !0];
+	let bits = &data.view_bits::<Lsb0>()[2 .. 126];
+
+	let runs = bits.chunk_by(|a, b| a == b).map(BitSlice::len);
+	assert!(runs.eq([2, 16, 12, 32, 1, 30, 31].iter().copied()));
+	let runs = bits.chunk_by(|a, b| a == b).rev().map(BitSlice::len);
+	assert!(runs.eq([31, 30, 1, 32, 12, 16, 2].iter().copied()));
+
+	let mut iter = bits.chunk_by(|a, b| a <= b);
+	assert_eq!(iter.next().unwrap().len(), 2);
+	assert_eq!(iter.next_back().unwrap().len(), 61);
+	assert_eq!(iter.next().unwrap().len(), 28);
+	assert_eq!(iter.next().unwrap().len(), 33);
+	assert!(iter.next().is_none());
+	assert!(iter.next_back().is_none());
+
+	//  As in `slice::chunk_by`, the predicate sees every adjacent pair.
+	let mut calls = 0;
+	let lens = bits
+		.chunk_by(|_, _| {
+			calls += 1;
+			calls % 3 != 0
+		})
+		.map(BitSlice::len)
+		.collect::<Vec<_>>();
+	assert_eq!(lens.len(), 42);
+	assert!(lens[.. 41].iter().all(|&len| len == 3));
+	assert_eq!(lens[41], 1);
+	assert_eq!(calls, 123);
+
+	let mut iter = bits.chunk_by(|_, _| false);
+	assert_eq!(iter.size_hint(), (1, Some(124)));
+	assert_eq!(iter.next(), Some(&bits[.. 1]));
+	assert_eq!(iter.next_back(), Some(&bits[123 ..]));
+	assert_eq!(iter.count(), 122);
+
+	let empty = BitSlice::<Lsb0, u8>::empty();
+	assert!(empty.chunk_by(|_, _| true).next().is_none());
+	assert_eq!(bits.chunk_by(|_, _| true).collect::<Vec<_>>(), [bits]);
+}
+
 #[test]
 fn chunks_exact_mut() {
 	let bits = bits![mut Msb0, u32; 0; 32];
@@ -747,6 +1802,49 @@ fn rchunks_exact_mut() {
 	assert_eq!(rchunks.into_remainder().bitptr(), rest);
 }
 
+#[test]
+fn chunks_by_element() {
+	let data = [0u16; 4];
+	let bits = data.view_bits::<Lsb0>();
+	assert_eq!(bits[.. 0].chunks_by_element().count(), 0);
+
+	for start in 0 .. 20 {
+		for end in start .. 64 {
+			let bits = &bits[start .. end];
+			let iter = bits.chunks_by_element();
+			let len = iter.len();
+			assert_eq!(iter.clone().count(), len);
+			let mut idx = start;
+			for chunk in iter.clone() {
+				assert!(!chunk.is_empty());
+				assert_eq!(chunk.as_slice().len(), 1);
+				assert_eq!(chunk.bitptr().head().value() as usize, idx % 16);
+				idx += chunk.len();
+			}
+			assert_eq!(idx, end);
+			let chunks = iter.clone().collect::<Vec<_>>();
+			assert!(iter.clone().rev().eq(chunks.into_iter().rev()));
+			for n in 0 ..= len {
+				assert_eq!(iter.clone().nth(n), iter.clone().skip(n).next());
+				assert_eq!(
+					iter.clone().nth_back(n),
+					iter.clone().rev().skip(n).next()
+				);
+			}
+		}
+	}
+
+	let mut data = [0u16; 4];
+	let bits = &mut data.view_bits_mut::<Msb0>()[12 .. 52];
+	let mut iter = bits.chunks_by_element_mut();
+	assert_eq!(iter.len(), 4);
+	iter.nth(1).unwrap().set_all(true);
+	iter.next_back().unwrap().set_all(true);
+	assert_eq!(iter.nth_back(0).map(|c| c.len()), Some(16));
+	assert!(iter.next().is_none());
+	assert_eq!(data, [0, 0xFFFF, 0, 0xF000]);
+}
+
 #[test]
 fn iter_ones_zeros() {
 	//                          0  1  2  3  4  5  6  7
@@ -771,6 +1869,71 @@ fn iter_ones_zeros() {
 	assert!(zeros.nth(2).is_none());
 	assert!(ones.nth_back(0).is_none());
 	assert!(zeros.nth_back(0).is_none());
+
+	let mut data = [0u16; 6];
+	data[1] = 0x0104;
+	data[4] = 0xFFFE;
+	for &(start, end) in &[(0, 96), (5, 90), (17, 80), (31, 65), (20, 30)] {
+		let bits = &data.view_bits::<Lsb0>()[start .. end];
+		let naive = |value: bool| {
+			(0 .. bits.len()).filter(move |&idx| bits[idx] == value)
+		};
+		assert!(bits.iter_ones().eq(naive(true)));
+		assert!(bits.iter_zeros().eq(naive(false)));
+		assert!(bits.iter_ones().rev().eq(naive(true).rev()));
+		assert!(bits.iter_zeros().rev().eq(naive(false).rev()));
+	}
+}
+
+#[test]
+fn first_last() {
+	fn check<O, T>(bits: &BitSlice<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let naive = |value: bool| bits.iter().position(|&bit| bit == value);
+		let naive_rev = |value: bool| bits.iter().rposition(|&bit| bit == value);
+		assert_eq!(bits.first_one(), naive(true));
+		assert_eq!(bits.first_zero(), naive(false));
+		assert_eq!(bits.last_one(), naive_rev(true));
+		assert_eq!(bits.last_zero(), naive_rev(false));
+	}
+
+	let mut data = [0u16; 6];
+	check(&data.view_bits::<Msb0>()[5 .. 90]);
+	data[2] = 0x0100;
+	data[3] = 0x8000;
+	for &(start, end) in &[(5, 90), (40, 41), (39, 60), (0, 96), (41, 48)] {
+		check(&data.view_bits::<Msb0>()[start .. end]);
+		check(&data.view_bits::<Lsb0>()[start .. end]);
+	}
+	let data = [!0u8, !0, 0xFE, !0];
+	for &(start, end) in &[(0, 32), (3, 17), (17, 29), (16, 17)] {
+		check(&data.view_bits::<Lsb0>()[start .. end]);
+		check(&data.view_bits::<Msb0>()[start .. end]);
+	}
+	check(BitSlice::<Lsb0, usize>::empty());
+}
+
+#[test]
+fn trim() {
+	let data = [0u16, 0x0180, 0, 0];
+	let bits = &data.view_bits::<Lsb0>()[3 .. 60];
+	assert_eq!(bits.trim_start(false).len(), 57 - 20);
+	assert_eq!(bits.trim_end(false).len(), 22);
+	assert_eq!(bits.trim(false), bits![1, 1]);
+	assert_eq!(bits.trim(true), bits);
+
+	let bits = &bits[13 .. 15];
+	assert!(bits.trim(false).is_empty());
+	assert!(bits.trim_start(false).is_empty());
+	assert!(bits.trim_end(false).is_empty());
+	assert_eq!(bits.trim(true), bits);
+
+	let empty = BitSlice::<Lsb0, u8>::empty();
+	assert!(empty.trim(false).is_empty());
+	assert!(empty.trim(true).is_empty());
 }
 
 #[cfg(feature = "alloc")]
@@ -1010,4 +2173,24 @@ mod format {
 ]"
 		);
 	}
+
+	#[test]
+	fn decimal() {
+		let values = [0u128, 1, 9, 10, 999_999_999, 1_000_000_000, !0 >> 3, !0];
+		for &val in values.iter() {
+			let mut data = [0u16; 9];
+			data.view_bits_mut::<Lsb0>()[3 ..][.. 128].store_le(val);
+			let bits = &data.view_bits::<Lsb0>()[3 .. 140];
+			assert_eq!(format!("{}", bits.display_decimal()), val.to_string());
+
+			let mut data = [0u16; 9];
+			data.view_bits_mut::<Msb0>()[5 ..][.. 128].store_be(val);
+			let bits = &data.view_bits::<Msb0>()[.. 133];
+			assert_eq!(format!("{}", bits.display_decimal()), val.to_string());
+		}
+
+		let bits = BitSlice::<Lsb0, u8>::empty();
+		assert_eq!(format!("{}", bits.display_decimal()), "0");
+		assert_eq!(format!("{:+05}", bits![1, 1].display_decimal()), "+0003");
+	}
 }
@@ -25,6 +25,7 @@ use core::{
 		Formatter,
 		LowerHex,
 		Octal,
+		Pointer,
 		UpperHex,
 	},
 	hash::{
@@ -276,7 +277,7 @@ where
 	type Error = &'a [T];
 
 	fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
-		BitSlice::from_slice(slice).ok_or(slice)
+		BitSlice::from_slice(slice).map_err(|_| slice)
 	}
 }
 
@@ -289,7 +290,7 @@ where
 
 	fn try_from(slice: &'a mut [T]) -> Result<Self, Self::Error> {
 		let slice_ptr = slice as *mut [T];
-		BitSlice::from_slice_mut(slice).ok_or(unsafe { &mut *slice_ptr })
+		BitSlice::from_slice_mut(slice).map_err(|_| unsafe { &mut *slice_ptr })
 	}
 }
 
@@ -335,6 +336,42 @@ where
 	}
 }
 
+/** Renders the address of the slice’s first element and the index of its first
+bit within that element.
+
+The address is formatted as an ordinary pointer, and is followed by the head
+index in brackets. The owning types format this way with `{:p}`. A `&BitSlice`
+reference is itself a pointer, and `{:p}` on it prints the raw encoded pointer
+instead, so borrowed slices must call this implementation directly.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use core::fmt;
+
+struct Addr<'a>(&'a BitSlice<Lsb0, u8>);
+impl fmt::Display for Addr<'_> {
+  fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Pointer::fmt(self.0, fmt)
+  }
+}
+
+let data = [0u8; 2];
+let bits = &data.view_bits::<Lsb0>()[3 ..];
+assert_eq!(Addr(bits).to_string(), format!("{:p}[3]", &data[0]));
+```
+**/
+impl<O, T> Pointer for BitSlice<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.bitptr().render_pointer(fmt)
+	}
+}
+
 /// Constructs numeric formatting implementations.
 macro_rules! fmt {
 	($trait:ident, $base:expr, $pfx:expr, $blksz:expr) => {
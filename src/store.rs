@@ -49,6 +49,12 @@ references to a single location attempt to read from and write to it.
 The [atomic] and [`Cell`] types stay as themselves when [`BitSlice`] introduces
 aliasing conditions, as they are already alias-aware.
 
+This also makes them useful as storage parameters in their own right. A data
+structure whose bits are always shared, such as a `BitVec<O, AtomicUsize>` of
+flags that many threads update, can name an [atomic] integer directly, and then
+write its bits through shared references with [`.set_aliased()`],
+[`.replace_aliased()`], and [`.fill_aliased()`].
+
 Lastly, the `bitvec` memory description model as implemented in the [`domain`]
 module is able to perform the inverse transition: where it can demonstrate a
 static awareness that the `&`/`&mut` exclusion rules are satisfied for a
@@ -77,6 +83,9 @@ coërced to interfere with each other.
 [`domain`]: crate::domain
 [`::Alias`]: self::BitStore::Alias
 [`::Unalias`]: self::BitStore::Unalias
+[`.fill_aliased()`]: crate::slice::BitSlice::fill_aliased
+[`.replace_aliased()`]: crate::slice::BitSlice::replace_aliased
+[`.set_aliased()`]: crate::slice::BitSlice::set_aliased
 [`.split_at_mut()`]: crate::slice::BitSlice::split_at_mut
 !*/
 
@@ -23,8 +23,13 @@ dynamic resizing, and provide some specializations that cannot safely be done on
 
 use crate::{
 	boxed::BitBox,
+	devel as dvl,
 	domain::Domain,
-	index::BitIdx,
+	error::Error,
+	index::{
+		BitIdx,
+		BitMask,
+	},
 	mem::{
 		BitMemory,
 		BitRegister,
@@ -34,15 +39,32 @@ use crate::{
 		Lsb0,
 	},
 	ptr::BitPtr,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		IndexError,
+	},
 	store::BitStore,
 };
 
-use alloc::vec::Vec;
+use alloc::{
+	alloc::Layout,
+	vec::Vec,
+};
 
 use core::{
-	mem::ManuallyDrop,
-	ptr::NonNull,
+	cmp,
+	mem::{
+		self,
+		ManuallyDrop,
+	},
+	ops::{
+		Range,
+		RangeBounds,
+	},
+	ptr::{
+		self,
+		NonNull,
+	},
 };
 
 use funty::IsInteger;
@@ -247,8 +269,18 @@ where
 {
 	/// Region pointer describing the live portion of the owned buffer.
 	pointer: NonNull<BitSlice<O, T>>,
-	/// Allocated capacity, in elements `T`, of the owned buffer.
+	/// Allocated capacity, in elements `T`, of the owned buffer, counted from
+	/// the start of the live portion.
 	capacity: usize,
+	/// Number of allocated elements before the start of the live portion.
+	///
+	/// This is kept by [`.pop_front()`] and [`.push_front()`] so that they do
+	/// not move the buffer contents, and is returned to the capacity before
+	/// the allocation is handed to a `Vec`.
+	///
+	/// [`.pop_front()`]: Self::pop_front
+	/// [`.push_front()`]: Self::push_front
+	front: usize,
 }
 
 /// General-purpose functions not present on `Vec<T>`.
@@ -290,6 +322,148 @@ where
 		out
 	}
 
+	/// Constructs a `BitVec` by calling a function for each index.
+	///
+	/// The bits produced by `func` are gathered into a whole memory element
+	/// before that element is written, so this is considerably faster than
+	/// collecting `(0 .. len).map(func)`, which writes each bit separately.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of live bits in the constructed `BitVec`.
+	/// - `func`: A function which receives each index in `0 .. len`, in order,
+	///   and returns the bit to store at that index.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` with `len` live bits, where `bv[n]` is `func(n)`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<Lsb0, u8>::from_fn(10, |n| n % 3 == 0);
+	/// assert_eq!(bv, bits![1, 0, 0, 1, 0, 0, 1, 0, 0, 1]);
+	/// assert_eq!(bv.as_slice(), &[0b0100_1001, 0b10]);
+	/// ```
+	pub fn from_fn<F>(len: usize, mut func: F) -> Self
+	where F: FnMut(usize) -> bool {
+		let mut out = Self::with_capacity(len);
+		unsafe {
+			out.set_len(len);
+		}
+		let mut idx = 0;
+		for elem in out.as_mut_slice() {
+			let live = cmp::min(T::Mem::BITS as usize, len - idx);
+			let value = (0 .. live)
+				.filter(|&n| func(idx + n))
+				.map(|n| unsafe { BitIdx::<T::Mem>::new_unchecked(n as u8) })
+				.map(BitIdx::select::<O>)
+				.sum::<BitMask<T::Mem>>();
+			elem.store_value(value.value());
+			idx += live;
+		}
+		out
+	}
+
+	/// Constructs a `BitVec` with the bits at a sequence of indices set.
+	///
+	/// This accepts the set-bit lists that databases and graph edge lists
+	/// produce. The indices may arrive in any order, and may repeat.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of live bits in the constructed `BitVec`.
+	/// - `indices`: The indices of the bits to set. Every index must be less
+	///   than `len`.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` with `len` live bits, where `bv[n]` is set exactly when `n`
+	/// appears in `indices`.
+	///
+	/// # Errors
+	///
+	/// This returns [`IndexError::OutOfBounds`] for the first index that is not
+	/// less than `len`. The rest of `indices` is not consumed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::IndexError;
+	///
+	/// let bv = BitVec::<Lsb0, u8>::from_indices(6, vec![4, 1, 4]).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 0, 0, 1, 0]);
+	///
+	/// assert_eq!(
+	///   BitVec::<Lsb0, u8>::from_indices(6, vec![1, 6]),
+	///   Err(IndexError::OutOfBounds { index: 6, len: 6 }),
+	/// );
+	/// ```
+	///
+	/// [`IndexError::OutOfBounds`]: crate::slice::IndexError::OutOfBounds
+	pub fn from_indices<I>(len: usize, indices: I) -> Result<Self, IndexError>
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::repeat(false, len);
+		for idx in indices {
+			out.try_set(idx, true)?;
+		}
+		Ok(out)
+	}
+
+	/// Constructs a `BitVec` just long enough to set the bit at each of a
+	/// sequence of indices.
+	///
+	/// This is the inferred-length variant of [`::from_indices()`]. It is not
+	/// provided as a `FromIterator<usize>` implementation, because that would
+	/// conflict with collecting `usize` memory elements into a
+	/// `BitVec<_, usize>`.
+	///
+	/// # Parameters
+	///
+	/// - `indices`: The indices of the bits to set, in any order.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` whose length is one more than the greatest index in
+	/// `indices`, or zero if `indices` is empty, where `bv[n]` is set exactly
+	/// when `n` appears in `indices`.
+	///
+	/// # Panics
+	///
+	/// This panics if an index is `usize::MAX`, or if the vector would exceed
+	/// its maximum capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<Msb0, u16>::from_indices_growing(vec![9, 2]);
+	/// assert_eq!(bv.len(), 10);
+	/// assert_eq!(bv.iter_ones().collect::<Vec<_>>(), [2, 9]);
+	///
+	/// assert!(BitVec::<Msb0, u16>::from_indices_growing(None).is_empty());
+	/// ```
+	///
+	/// [`::from_indices()`]: Self::from_indices
+	pub fn from_indices_growing<I>(indices: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		for idx in indices {
+			if idx >= out.len() {
+				let len = idx.checked_add(1).expect("Bit index overflowed");
+				out.resize(len, false);
+			}
+			unsafe {
+				out.set_unchecked(idx, true);
+			}
+		}
+		out
+	}
+
 	/// Copies the contents of a [`BitSlice`] into a new allocation.
 	///
 	/// This is an exact copy: the newly-created vector is initialized with a
@@ -342,9 +516,45 @@ where
 		Self {
 			pointer: bitptr.to_nonnull(),
 			capacity,
+			front: 0,
 		}
 	}
 
+	/// Copies a slice of memory elements into a new bit-vector.
+	///
+	/// This checks the length of `slice` before allocating, so buffers of
+	/// untrusted size can be rejected without a panic.
+	///
+	/// # Parameters
+	///
+	/// - `slice`: Some memory elements to copy into a bit-vector.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` over a copy of `slice`, beginning at the zeroth bit of its
+	/// first element.
+	///
+	/// # Errors
+	///
+	/// This returns [`Error::TooLong`] if `slice` has too many elements to be
+	/// viewed as bits. See [`BitSlice::MAX_ELTS`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<Msb0, u8>::try_from_slice(&[0x0F, 0xA5]).unwrap();
+	/// assert_eq!(bv.len(), 16);
+	/// assert_eq!(bv[.. 8], bits![0, 0, 0, 0, 1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`BitSlice::MAX_ELTS`]: crate::slice::BitSlice::MAX_ELTS
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	pub fn try_from_slice(slice: &[T]) -> Result<Self, Error> {
+		BitSlice::from_slice(slice).map(Self::from_bitslice)
+	}
+
 	/// Converts a [`Vec<T>`] into a `BitVec<O, T>` without copying its buffer.
 	///
 	/// # Parameters
@@ -390,9 +600,13 @@ where
 	///
 	/// # Returns
 	///
-	/// If `vec` is short enough to be viewed as a `BitVec`, then this returns
-	/// a `BitVec` over the `vec` buffer. If `vec` is too long, then this
-	/// returns `vec` unmodified.
+	/// A `BitVec` over the `vec` buffer.
+	///
+	/// # Errors
+	///
+	/// If `vec` is too long to be viewed as bits, this returns
+	/// [`Error::TooLong`] alongside `vec`, unmodified. See
+	/// [`BitSlice::MAX_ELTS`].
 	///
 	/// # Examples
 	///
@@ -407,12 +621,14 @@ where
 	/// An example showing this function failing would require an allocation
 	/// exceeding `!0usize >> 3` bytes in size, which is infeasible to produce.
 	///
+	/// [`BitSlice::MAX_ELTS`]: crate::slice::BitSlice::MAX_ELTS
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
 	/// [`Vec`]: alloc::vec::Vec
 	/// [`Vec<T>`]: alloc::vec::Vec
-	pub fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+	pub fn try_from_vec(vec: Vec<T>) -> Result<Self, (Error, Vec<T>)> {
 		let len = vec.len();
-		if len > BitSlice::<O, T>::MAX_ELTS {
-			return Err(vec);
+		if let Err(err) = BitSlice::<O, T>::check_slice_len(len) {
+			return Err((err, vec));
 		}
 
 		let vec = ManuallyDrop::new(vec);
@@ -427,6 +643,7 @@ where
 			}
 			.to_nonnull(),
 			capacity,
+			front: 0,
 		})
 	}
 
@@ -477,6 +694,293 @@ where
 		unsafe { self.get_unchecked_mut(len ..) }.clone_from_bitslice(other);
 	}
 
+	/// Copies all bits in a [`BitSlice`] into the `BitVec`, if they can be
+	/// allocated.
+	///
+	/// This behaves as [`.extend_from_bitslice()`], except that it reserves
+	/// the memory for `other` with [`.try_reserve()`] first, and returns its
+	/// error rather than panicking or aborting when the vector cannot grow.
+	///
+	/// # Errors
+	///
+	/// - [`Error::TooLong`] if the new length exceeds the maximum vector
+	///   length.
+	/// - [`Error::Alloc`] if the allocator cannot provide the memory.
+	///
+	/// In both cases, the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1];
+	/// bv.try_extend_from_bitslice(bits![1, 1, 0, 1]).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 1, 1, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	/// [`Error::Alloc`]: crate::error::Error::Alloc
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	/// [`.extend_from_bitslice()`]: Self::extend_from_bitslice
+	/// [`.try_reserve()`]: Self::try_reserve
+	pub fn try_extend_from_bitslice<O2, T2>(
+		&mut self,
+		other: &BitSlice<O2, T2>,
+	) -> Result<(), Error>
+	where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		self.try_reserve(other.len())?;
+		self.extend_from_bitslice(other);
+		Ok(())
+	}
+
+	/// Appends all the bits of a slice of memory elements to the end of the
+	/// vector.
+	///
+	/// While the live bits of the vector end on an element boundary, the
+	/// elements are copied directly onto the end of the underlying buffer.
+	/// Otherwise, their bits are copied into the vector in one bulk transfer
+	/// behind the partial last element.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector exceeds the maximum vector
+	/// capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1, 0, 1];
+	/// bv.extend_from_raw_slice(&[0x12, 0x34]);
+	/// assert_eq!(bv.len(), 19);
+	/// assert_eq!(bv[3 ..].load_be::<u16>(), 0x1234);
+	///
+	/// bv.truncate(8);
+	/// bv.extend_from_raw_slice(&[0x56]);
+	/// assert_eq!(bv.as_slice()[1], 0x56);
+	/// ```
+	pub fn extend_from_raw_slice(&mut self, slice: &[T]) {
+		let len = self.len();
+		let width = T::Mem::BITS as usize;
+		let bits = BitSlice::<O, T>::from_slice(slice)
+			.expect("Vector capacity exceeded");
+		if self.bitptr().head().value() == 0 && len & T::Mem::MASK as usize == 0
+		{
+			self.with_vec(|v| v.extend(slice.iter().map(BitStore::load_value)));
+			unsafe {
+				self.set_len(len + slice.len() * width);
+			}
+		}
+		else {
+			self.extend_from_bitslice(bits);
+		}
+	}
+
+	/// Appends all the bits of a memory element to the end of the vector.
+	///
+	/// This is equivalent to [`.extend_from_raw_slice()`] with a one-element
+	/// slice.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector exceeds the maximum vector
+	/// capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Lsb0, u16; 1];
+	/// bv.push_element(0x8001);
+	/// assert_eq!(bv.len(), 17);
+	/// assert_eq!(bv[1 ..].load_le::<u16>(), 0x8001);
+	/// ```
+	///
+	/// [`.extend_from_raw_slice()`]: Self::extend_from_raw_slice
+	pub fn push_element(&mut self, element: T) {
+		self.extend_from_raw_slice(core::slice::from_ref(&element));
+	}
+
+	/// Prepends a bit to the front of the vector.
+	///
+	/// A `BitVec` may begin partway through its first memory element. When it
+	/// does, this method grows the vector backwards into the dead bits of that
+	/// element, and does not move any memory. When the first element is full,
+	/// the vector claims an unused element allocated in front of it, which it
+	/// then fills backwards on later calls.
+	///
+	/// Only when there are no unused elements in front of the vector does it
+	/// move the buffer contents, and it then opens as many unused elements as
+	/// the vector already occupies. Like the growth of a deque, this makes
+	/// repeated prepending take amortized constant time, rather than moving
+	/// the whole buffer for every bit as `.insert(0, value)` does.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector exceeds the maximum vector
+	/// capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 0, 1];
+	/// bv.push_front(true);
+	/// bv.push_front(false);
+	/// assert_eq!(bv, bits![0, 1, 0, 1]);
+	/// ```
+	pub fn push_front(&mut self, value: bool) {
+		let len = self.len();
+		assert!(
+			len < BitSlice::<O, T>::MAX_BITS,
+			"Exceeded capacity: {} >= {}",
+			len,
+			BitSlice::<O, T>::MAX_BITS,
+		);
+		let (head, wrapped) = self.bitptr().head().prev();
+		//  There is no dead bit before the live region; claim a new element.
+		if wrapped {
+			if self.front == 0 {
+				self.grow_front();
+			}
+			unsafe {
+				self.offset_base(-1);
+				self.as_mut_ptr().cast::<T::Mem>().write(T::Mem::ZERO);
+			}
+		}
+		unsafe {
+			let mut bitptr = self.bitptr();
+			bitptr.set_head(head);
+			bitptr.set_len(len + 1);
+			self.pointer = bitptr.to_nonnull();
+			self.set_unchecked(0, value);
+		}
+	}
+
+	/// Removes the first bit from a vector and returns it, or [`None`] if it is
+	/// empty.
+	///
+	/// This advances the start of the vector, and does not move any memory.
+	/// When the first element has been fully consumed, it is kept as an unused
+	/// element in front of the vector, which [`.push_front()`] can reclaim.
+	/// This takes constant time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![1, 0, 0];
+	/// assert_eq!(bv.pop_front(), Some(true));
+	/// assert_eq!(bv, bits![0, 0]);
+	/// ```
+	///
+	/// [`None`]: core::option::Option::None
+	/// [`.push_front()`]: Self::push_front
+	pub fn pop_front(&mut self) -> Option<bool> {
+		let len = self.len();
+		if len == 0 {
+			return None;
+		}
+		let out = unsafe { *self.get_unchecked(0) };
+		let (head, wrapped) = self.bitptr().head().next();
+		//  The first element has no live bits left; step over it.
+		if wrapped {
+			unsafe {
+				self.offset_base(1);
+			}
+		}
+		unsafe {
+			let mut bitptr = self.bitptr();
+			bitptr.set_head(head);
+			bitptr.set_len(len - 1);
+			self.pointer = bitptr.to_nonnull();
+		}
+		Some(out)
+	}
+
+	/// Inserts the contents of a bit-slice at position `index` within the
+	/// vector, shifting all bits after it to the right.
+	///
+	/// This is the batch form of [`.insert()`]. The bits after `index` are
+	/// moved once, by the full length of `other`, rather than once per
+	/// inserted bit.
+	///
+	/// # Panics
+	///
+	/// Panics if `index > len`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0, 0, 0];
+	/// bv.insert_bitslice(1, bits![1, 0, 1]);
+	/// assert_eq!(bv, bits![0, 1, 0, 1, 0, 0, 0]);
+	/// ```
+	///
+	/// [`.insert()`]: Self::insert
+	pub fn insert_bitslice<O2, T2>(
+		&mut self,
+		index: usize,
+		other: &BitSlice<O2, T2>,
+	) where
+		O2: BitOrder,
+		T2: BitStore,
+	{
+		let len = self.len();
+		assert!(index <= len, "Index {} out of bounds: {}", index, len);
+		let olen = other.len();
+		self.resize(len + olen, false);
+		unsafe {
+			self.copy_within_unchecked(index .. len, index + olen);
+			self.get_unchecked_mut(index .. index + olen)
+				.clone_from_bitslice(other);
+		}
+	}
+
+	/// Removes a range of bits from the vector, shifting all bits after it to
+	/// the left.
+	///
+	/// This is the batch form of [`.remove()`]. The bits after the range are
+	/// moved once, by the full length of the range, rather than once per
+	/// removed bit.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is out of bounds.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 0];
+	/// bv.remove_range(1 .. 4);
+	/// assert_eq!(bv, bits![0, 1, 0]);
+	/// ```
+	///
+	/// [`.remove()`]: Self::remove
+	pub fn remove_range<R>(&mut self, range: R)
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let range = dvl::normalize_range(range, len);
+		dvl::assert_range(range.clone(), len);
+		let Range { start, end } = range;
+		unsafe {
+			self.copy_within_unchecked(end .., start);
+			self.set_len(len - (end - start));
+		}
+	}
+
 	/// Produces the allocation capacity of the underlying vector, measured in
 	/// `T` units.
 	///
@@ -491,9 +995,12 @@ where
 	/// # Returns
 	///
 	/// The capacity, in `T` elements, of the allocated buffer underlying
-	/// `self`.
+	/// `self`, counted from the start of the vector. Elements that
+	/// [`.pop_front()`] has stepped over are not counted until the vector next
+	/// reällocates or is deconstructed.
 	///
 	/// [`::from_raw_parts`]: Self::from_raw_parts
+	/// [`.pop_front()`]: Self::pop_front
 	pub fn alloc_capacity(&self) -> usize {
 		self.capacity
 	}
@@ -543,7 +1050,8 @@ where
 	/// Removes the bit-precision view, returning the underlying [`Vec`].
 	///
 	/// [`Vec`]: alloc::vec::Vec
-	pub fn into_vec(self) -> Vec<T> {
+	pub fn into_vec(mut self) -> Vec<T> {
+		self.reclaim_front();
 		let mut this = ManuallyDrop::new(self);
 		let buf = this.as_mut_slice();
 		unsafe {
@@ -604,15 +1112,15 @@ where
 	/// assert_eq!(bv.count_ones(), 2);
 	/// assert_eq!(bv.as_slice(), &[220u8]);
 	///
-	/// bv.set_uninitialized(false);
+	/// bv.fill_uninitialized(false);
 	/// assert_eq!(bv.as_slice(), &[12u8]);
 	///
-	/// bv.set_uninitialized(true);
+	/// bv.fill_uninitialized(true);
 	/// assert_eq!(bv.as_slice(), &[!3u8]);
 	/// ```
 	///
 	/// [`.as_bitslice()`]: Self::as_bitslice
-	pub fn set_uninitialized(&mut self, value: bool) {
+	pub fn fill_uninitialized(&mut self, value: bool) {
 		let head = self.bitptr().head().value() as usize;
 		let tail = head + self.len();
 		let capa = self.capacity();
@@ -626,6 +1134,12 @@ where
 		}
 	}
 
+	/// Sets the uninitialized bits of the vector to a fixed value.
+	#[deprecated = "Use `.fill_uninitialized()` to set the dead bits"]
+	pub fn set_uninitialized(&mut self, value: bool) {
+		self.fill_uninitialized(value);
+	}
+
 	/// Ensures that the live region of the vector’s contents begins at the
 	/// leading edge of the buffer.
 	///
@@ -782,8 +1296,13 @@ where
 		self.pointer.as_ptr().pipe(BitPtr::from_bitslice_ptr_mut)
 	}
 
+	/// Views the owned buffer as a `Vec` for the duration of `func`.
+	///
+	/// The `Vec` begins at the start of the live region, so any unused elements
+	/// in front of it are first returned to the capacity.
 	fn with_vec<F, R>(&mut self, func: F) -> R
 	where F: FnOnce(&mut ManuallyDrop<Vec<T::Mem>>) -> R {
+		self.reclaim_front();
 		let cap = self.capacity;
 		let mut bitptr = self.bitptr();
 		let (base, elts) = (
@@ -802,6 +1321,166 @@ where
 		self.capacity = vec.capacity();
 		out
 	}
+
+	/// Moves the start of the live region by `by` elements within the
+	/// allocation, trading them between the unused elements in front of the
+	/// vector and its capacity. The buffer contents are not moved.
+	///
+	/// # Safety
+	///
+	/// `by` must not be less than `-self.front`, nor greater than
+	/// `self.capacity`.
+	unsafe fn offset_base(&mut self, by: isize) {
+		let mut bitptr = self.bitptr();
+		bitptr.set_pointer(bitptr.pointer().to_const().offset(by));
+		self.pointer = bitptr.to_nonnull();
+		self.front = (self.front as isize + by) as usize;
+		self.capacity = (self.capacity as isize - by) as usize;
+	}
+
+	/// Opens unused elements in front of the vector, as many as it occupies
+	/// and at least one, by moving its contents towards the back.
+	fn grow_front(&mut self) {
+		let elts = self.bitptr().elements();
+		let extra = cmp::max(elts, 1);
+		self.with_vec(|v| {
+			v.reserve(extra);
+			unsafe {
+				let base = v.as_mut_ptr();
+				ptr::copy(base, base.add(extra), elts);
+			}
+		});
+		unsafe {
+			self.offset_base(extra as isize);
+		}
+	}
+
+	/// Moves the contents of the vector to the start of its allocation,
+	/// returning any unused elements in front of it to the capacity.
+	fn reclaim_front(&mut self) {
+		let front = self.front;
+		if front == 0 {
+			return;
+		}
+		let elts = self.bitptr().elements();
+		unsafe {
+			let base = self.as_mut_ptr().cast::<T::Mem>();
+			ptr::copy(base, base.sub(front), elts);
+			self.offset_base(-(front as isize));
+		}
+	}
+
+	/// Rebases the vector onto the start of its allocation without moving its
+	/// contents, which leaves the bits in the vector unspecified. This is
+	/// only suitable when the vector is about to be cleared or destroyed.
+	pub(crate) fn release_front(&mut self) {
+		unsafe {
+			self.offset_base(-(self.front as isize));
+		}
+	}
+
+	/// Counts the memory elements that the vector needs to hold `additional`
+	/// more bits.
+	///
+	/// # Errors
+	///
+	/// This returns [`Error::TooLong`] if the new length exceeds the maximum
+	/// vector length.
+	///
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	fn elts_for(&self, additional: usize) -> Result<usize, Error> {
+		let len = self.len();
+		let max = BitSlice::<O, T>::MAX_BITS;
+		let new_len = len
+			.checked_add(additional)
+			.filter(|&new_len| new_len <= max)
+			.ok_or(Error::TooLong {
+				len: len.saturating_add(additional),
+				max,
+			})?;
+		Ok(self.bitptr().head().span(new_len).0)
+	}
+
+	/// Grows the allocation to hold at least `elts` memory elements, without
+	/// aborting the program if the allocator fails.
+	///
+	/// This allocates through the global allocator with the layout of a
+	/// `Vec<T::Mem>`, so that the buffer can still be released by [`Drop`].
+	///
+	/// # Errors
+	///
+	/// This returns [`Error::Alloc`] if the allocator fails, and leaves the
+	/// vector unchanged.
+	///
+	/// [`Drop`]: core::ops::Drop
+	/// [`Error::Alloc`]: crate::error::Error::Alloc
+	fn try_grow(&mut self, elts: usize) -> Result<(), Error> {
+		if elts <= self.capacity {
+			return Ok(());
+		}
+		self.reclaim_front();
+		let cap = self.capacity;
+		if elts <= cap {
+			return Ok(());
+		}
+		let size = mem::size_of::<T::Mem>();
+		let align = mem::align_of::<T::Mem>();
+		let err = Error::Alloc {
+			size: elts.saturating_mul(size),
+			align,
+		};
+		let layout = Layout::array::<T::Mem>(elts).map_err(|_| err)?;
+
+		let mut bitptr = self.bitptr();
+		let ptr = unsafe {
+			if cap == 0 {
+				alloc::alloc::alloc(layout)
+			}
+			else {
+				alloc::alloc::realloc(
+					bitptr.pointer().to_mut().cast::<u8>(),
+					Layout::from_size_align_unchecked(cap * size, align),
+					layout.size(),
+				)
+			}
+		};
+		if ptr.is_null() {
+			return Err(err);
+		}
+
+		unsafe {
+			bitptr.set_pointer(ptr as *const T);
+		}
+		self.pointer = bitptr.to_nonnull();
+		self.capacity = elts;
+		Ok(())
+	}
+
+	/// Appends whole memory elements to the end of the vector.
+	///
+	/// While the live bits of the vector end on an element boundary, each
+	/// element is pushed directly onto the underlying buffer. Otherwise, its
+	/// bits are copied into the vector behind the partial last element.
+	fn extend_elements<I>(&mut self, iter: I)
+	where I: Iterator<Item = T::Mem> {
+		let width = T::Mem::BITS as usize;
+		self.reserve(iter.size_hint().0.saturating_mul(width));
+		for elem in iter {
+			let len = self.len();
+			let bitptr = self.bitptr();
+			if bitptr.head().value() == 0 && len & T::Mem::MASK as usize == 0 {
+				self.with_vec(|v| v.push(elem));
+				unsafe {
+					self.set_len(len + width);
+				}
+			}
+			else {
+				self.extend_from_bitslice(BitSlice::<O, T::Mem>::from_element(
+					&elem,
+				));
+			}
+		}
+	}
 }
 
 mod api;
@@ -815,5 +1494,14 @@ pub use self::iter::{
 	Splice,
 };
 
+//  `Arc` requires pointer-width atomic instructions.
+radium::if_atomic! {
+	if atomic(ptr) {
+		mod shared;
+
+		pub use self::shared::SharedBitVec;
+	}
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,8 @@
 //! Port of the `Vec<T>` inherent API.
 
 use crate::{
+	devel as dvl,
+	error::Error,
 	mem::BitMemory,
 	order::BitOrder,
 	ptr::BitPtr,
@@ -21,6 +23,7 @@ use alloc::{
 };
 
 use core::{
+	cmp,
 	mem::{
 		self,
 		ManuallyDrop,
@@ -57,6 +60,7 @@ where
 		Self {
 			pointer: BitPtr::<O, T>::EMPTY.to_nonnull(),
 			capacity: 0,
+			front: 0,
 		}
 	}
 
@@ -119,7 +123,11 @@ where
 			.pipe(ManuallyDrop::new);
 		let (ptr, capacity) = (vec.as_ptr(), vec.capacity());
 		let pointer = ptr.pipe(BitPtr::uninhabited).pipe(BitPtr::to_nonnull);
-		Self { pointer, capacity }
+		Self {
+			pointer,
+			capacity,
+			front: 0,
+		}
 	}
 
 	/// Decomposes a `BitVec<O, T>` into its raw components.
@@ -161,7 +169,8 @@ where
 	///
 	/// [`::from_raw_parts()`]: Self::from_raw_parts
 	/// [`.alloc_capacity()`]: Self::alloc_capacity
-	pub fn into_raw_parts(self) -> (*mut BitSlice<O, T>, usize) {
+	pub fn into_raw_parts(mut self) -> (*mut BitSlice<O, T>, usize) {
+		self.reclaim_front();
 		let mut this = ManuallyDrop::new(self);
 		(this.as_mut_bitptr(), this.alloc_capacity())
 	}
@@ -270,7 +279,11 @@ where
 			panic!("Attempted to reconstruct a `BitVec` from a null pointer");
 		}
 		let pointer = pointer.pipe(BitPtr::from_bitslice_ptr_mut).to_nonnull();
-		Self { pointer, capacity }
+		Self {
+			pointer,
+			capacity,
+			front: 0,
+		}
 	}
 
 	/// Returns the number of bits the vector can hold without reällocating.
@@ -404,6 +417,97 @@ where
 		}
 	}
 
+	/// Tries to reserve capacity for at least `additional` more bits to be
+	/// inserted in the given `BitVec<O, T>`.
+	///
+	/// This behaves as [`.reserve()`], except that it reports failure as a
+	/// value instead of panicking when the new length is too long, or aborting
+	/// the program when the allocator fails.
+	///
+	/// # Original
+	///
+	/// [`Vec::try_reserve`](alloc::vec::Vec::try_reserve)
+	///
+	/// # Errors
+	///
+	/// - [`Error::TooLong`] if the new length exceeds the maximum vector
+	///   length.
+	/// - [`Error::Alloc`] if the allocator cannot provide the memory.
+	///
+	/// In both cases, the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   error::Error,
+	///   prelude::*,
+	/// };
+	///
+	/// let mut bv = bitvec![1];
+	/// bv.try_reserve(100).unwrap();
+	/// assert!(bv.capacity() >= 101);
+	///
+	/// assert!(matches!(bv.try_reserve(!0), Err(Error::TooLong { .. })));
+	/// ```
+	///
+	/// [`Error::Alloc`]: crate::error::Error::Alloc
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	/// [`.reserve()`]: Self::reserve
+	pub fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+		let elts = self.elts_for(additional)?;
+		//  Only reserve if the request needs more elements than are allocated.
+		if elts > self.capacity {
+			let grown = cmp::min(
+				self.capacity.saturating_mul(2),
+				BitSlice::<O, T>::MAX_ELTS,
+			);
+			let old = self.capacity();
+			self.try_grow(cmp::max(elts, grown))?;
+			let capa = self.capacity();
+			//  Zero the newly-allocated buffer.
+			unsafe { self.get_unchecked_mut(old .. capa) }.set_all(false);
+		}
+		Ok(())
+	}
+
+	/// Tries to reserve the minimum capacity for exactly `additional` more
+	/// bits to be inserted in the given `BitVec<O, T>`.
+	///
+	/// This behaves as [`.reserve_exact()`], except that it reports failure
+	/// as a value instead of panicking when the new length is too long, or
+	/// aborting the program when the allocator fails.
+	///
+	/// # Original
+	///
+	/// [`Vec::try_reserve_exact`](alloc::vec::Vec::try_reserve_exact)
+	///
+	/// # Errors
+	///
+	/// - [`Error::TooLong`] if the new length exceeds the maximum vector
+	///   length.
+	/// - [`Error::Alloc`] if the allocator cannot provide the memory.
+	///
+	/// In both cases, the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<Lsb0, u8>::new();
+	/// bv.try_reserve_exact(20).unwrap();
+	/// assert_eq!(bv.capacity(), 24);
+	/// ```
+	///
+	/// [`Error::Alloc`]: crate::error::Error::Alloc
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	/// [`.reserve_exact()`]: Self::reserve_exact
+	pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), Error> {
+		let elts = self.elts_for(additional)?;
+		self.try_grow(elts)
+	}
+
 	/// Shrinks the capacity of the vector as much as possible.
 	///
 	/// It will drop down as close as possible to the length but the allocator
@@ -804,8 +908,8 @@ where
 	pub fn insert(&mut self, index: usize, value: bool) {
 		let len = self.len();
 		assert!(index <= len, "Index {} out of bounds: {}", index, len);
-		self.push(value);
-		unsafe { self.get_unchecked_mut(index ..) }.rotate_right(1);
+		self.push(false);
+		unsafe { self.get_unchecked_mut(index ..) }.shift_in_front(value);
 	}
 
 	/// Removes and returns the bit at position `index` within the vector,
@@ -832,9 +936,9 @@ where
 		self.assert_in_bounds(index);
 		let last = self.len() - 1;
 		unsafe {
-			self.get_unchecked_mut(index ..).rotate_left(1);
+			let out = self.get_unchecked_mut(index ..).shift_in_back(false);
 			self.set_len(last);
-			*self.get_unchecked(last)
+			out
 		}
 	}
 
@@ -909,6 +1013,76 @@ where
 		}
 	}
 
+	/// Appends a bit to the back of a collection, if it fits in the current
+	/// allocation.
+	///
+	/// This never reällocates the vector, so its cost is bounded.
+	///
+	/// # Original
+	///
+	/// [`Vec::push_within_capacity`](alloc::vec::Vec::push_within_capacity)
+	///
+	/// # Errors
+	///
+	/// If the vector has no spare capacity, this returns `value` and leaves the
+	/// vector unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<Lsb0, u8>::with_capacity(8);
+	/// for _ in 0 .. bv.capacity() {
+	///   bv.push_within_capacity(true).unwrap();
+	/// }
+	/// assert_eq!(bv.push_within_capacity(false), Err(false));
+	/// assert!(bv.all());
+	/// ```
+	pub fn push_within_capacity(&mut self, value: bool) -> Result<(), bool> {
+		if self.len() < self.capacity() {
+			self.push(value);
+			Ok(())
+		}
+		else {
+			Err(value)
+		}
+	}
+
+	/// Appends a bit to the back of a collection, if it can be allocated.
+	///
+	/// This behaves as [`.push()`], except that it reserves the memory for the
+	/// bit with [`.try_reserve()`] first, and returns its error rather than
+	/// panicking or aborting when the vector cannot grow.
+	///
+	/// # Errors
+	///
+	/// - [`Error::TooLong`] if the vector is already at its maximum length.
+	/// - [`Error::Alloc`] if the allocator cannot provide the memory.
+	///
+	/// In both cases, the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<Msb0, u16>::new();
+	/// bv.try_push(true).unwrap();
+	/// bv.try_push(false).unwrap();
+	/// assert_eq!(bv, bits![1, 0]);
+	/// ```
+	///
+	/// [`Error::Alloc`]: crate::error::Error::Alloc
+	/// [`Error::TooLong`]: crate::error::Error::TooLong
+	/// [`.push()`]: Self::push
+	/// [`.try_reserve()`]: Self::try_reserve
+	pub fn try_push(&mut self, value: bool) -> Result<(), Error> {
+		self.try_reserve(1)?;
+		self.push(value);
+		Ok(())
+	}
+
 	/// Removes the last bit from a vector and returns it, or [`None`] if it is
 	/// empty.
 	///
@@ -938,6 +1112,51 @@ where
 		}
 	}
 
+	/// Removes the last bit from a vector and returns it, if the predicate
+	/// accepts it.
+	///
+	/// The predicate receives a mutable reference to the last bit. If it
+	/// returns `false`, any change it made to the bit is kept in the vector.
+	///
+	/// # Original
+	///
+	/// [`Vec::pop_if`](alloc::vec::Vec::pop_if)
+	///
+	/// # Returns
+	///
+	/// [`None`] if the vector is empty or the predicate returns `false`;
+	/// otherwise, the removed bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1];
+	/// assert_eq!(bv.pop_if(|bit| *bit), Some(true));
+	/// assert_eq!(bv.pop_if(|bit| !*bit), None);
+	/// assert_eq!(bv, bits![0, 1]);
+	/// ```
+	///
+	/// [`None`]: core::option::Option::None
+	pub fn pop_if<F>(&mut self, predicate: F) -> Option<bool>
+	where F: FnOnce(&mut bool) -> bool {
+		let last = self.len().checked_sub(1)?;
+		let mut bit = unsafe { *self.get_unchecked(last) };
+		if predicate(&mut bit) {
+			unsafe {
+				self.set_len_unchecked(last);
+			}
+			Some(bit)
+		}
+		else {
+			unsafe {
+				self.set_unchecked(last, bit);
+			}
+			None
+		}
+	}
+
 	/// Moves all the bits of `other` into `self`, leaving `other` empty.
 	///
 	/// # Original
@@ -979,8 +1198,10 @@ where
 	///
 	/// When the iterator **is** dropped, all bits in the range are removed from
 	/// the vector, even if the iterator was not fully consumed. If the iterator
-	/// **is not** dropped (with [`mem::forget`] for example), it is unspecified
-	/// how many bits are removed.
+	/// **is not** dropped (with [`mem::forget`] for example), the vector is
+	/// truncated to the start of the range, and the drained bits and the bits
+	/// after the range are lost. The iterator’s [`.keep_rest()`] method keeps
+	/// the bits it has not yet yielded instead.
 	///
 	/// # Original
 	///
@@ -1007,6 +1228,7 @@ where
 	/// ```
 	///
 	/// [`mem::forget`]: core::mem::forget
+	/// [`.keep_rest()`]: crate::vec::Drain::keep_rest
 	pub fn drain<R>(&mut self, range: R) -> Drain<O, T>
 	where R: RangeBounds<usize> {
 		Drain::new(self, range)
@@ -1033,6 +1255,7 @@ where
 	/// assert!(bv.is_empty());
 	/// ```
 	pub fn clear(&mut self) {
+		self.release_front();
 		self.pointer = BitPtr::uninhabited(self.as_mut_ptr()).to_nonnull();
 	}
 
@@ -1254,6 +1477,43 @@ where
 		self.extend(other.iter().copied());
 	}
 
+	/// Copies bits from `src` range to the end of the vector.
+	///
+	/// The vector reserves its new capacity once, and then copies the whole
+	/// range in a single bulk transfer rather than bit by bit.
+	///
+	/// # Original
+	///
+	/// [`Vec::extend_from_within`](alloc::vec::Vec::extend_from_within)
+	///
+	/// # Panics
+	///
+	/// Panics if the starting point is greater than the end point or if the end
+	/// point is greater than the length of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0];
+	/// bv.extend_from_within(1 ..);
+	/// assert_eq!(bv, bits![0, 1, 1, 0, 1, 1, 0]);
+	/// bv.extend_from_within(.. 2);
+	/// assert_eq!(bv, bits![0, 1, 1, 0, 1, 1, 0, 0, 1]);
+	/// ```
+	pub fn extend_from_within<R>(&mut self, src: R)
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let src = dvl::normalize_range(src, len);
+		dvl::assert_range(src.clone(), len);
+		self.reserve(src.len());
+		unsafe {
+			self.set_len(len + src.len());
+			self.copy_within_unchecked(src, len);
+		}
+	}
+
 	/// Creates a splicing iterator that replaces the specified range in the
 	/// vector with the given `replace_with` iterator and yields the removed
 	/// items. `replace_with` does not need to be the same length as `range`.
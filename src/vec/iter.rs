@@ -3,6 +3,7 @@
 use crate::{
 	devel as dvl,
 	order::BitOrder,
+	ptr::Address,
 	slice::{
 		BitSlice,
 		Iter,
@@ -84,6 +85,47 @@ where
 	}
 }
 
+/** Appends a sequence of bit-slices to a bit-vector.
+
+Each bit-slice is copied with [`.extend_from_bitslice()`], so runs of whole
+elements are moved in bulk rather than one bit at a time. The bit-slices may
+have any ordering and storage type, which lets a message be assembled from
+fields held in different buffers. Raw bytes can be appended by viewing them as
+bits first.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let header = bits![Msb0, u8; 1, 0, 1];
+let payload = [0xA5u8, 0x0F];
+let mut msg = BitVec::<Msb0, u16>::new();
+msg.extend(vec![header, payload.view_bits::<Msb0>()]);
+msg.extend(payload.iter().map(BitView::view_bits::<Lsb0>));
+
+assert_eq!(msg.len(), 35);
+assert_eq!(msg[.. 11], bits![1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1]);
+assert_eq!(msg[27 ..], bits![1, 1, 1, 1, 0, 0, 0, 0]);
+```
+
+[`.extend_from_bitslice()`]: crate::vec::BitVec::extend_from_bitslice
+**/
+impl<'a, O, T, O2, T2> Extend<&'a BitSlice<O2, T2>> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+	T2: 'a + BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a BitSlice<O2, T2>> {
+		for bits in iter {
+			self.extend_from_bitslice(bits);
+		}
+	}
+}
+
 impl<O, T> FromIterator<bool> for BitVec<O, T>
 where
 	O: BitOrder,
@@ -106,13 +148,70 @@ where
 	}
 }
 
+/** Append a sequence of memory elements to a bit-vector.
+
+Each element contributes all of its bits, in the order `O`, exactly as
+[`BitVec::from_vec`] would interpret it. When the vector ends on an element
+boundary, the elements are pushed directly onto its buffer.
+
+This is not a standard-library API.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let mut bv = bitvec![Msb0, u8; 1, 0];
+bv.extend(vec![0xA5u8, 0x0F]);
+assert_eq!(bv.len(), 18);
+assert_eq!(bv[2 ..].load_be::<u16>(), 0xA50F);
+```
+
+[`BitVec::from_vec`]: crate::vec::BitVec::from_vec
+**/
+impl<O, T> Extend<T> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = T> {
+		self.extend_elements(iter.into_iter().map(|elem| elem.load_value()));
+	}
+}
+
+impl<'a, O, T> Extend<&'a T> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a T> {
+		self.extend_elements(iter.into_iter().map(BitStore::load_value));
+	}
+}
+
 /** Collect a sequence of memory elements into a bit-vector.
 
-This is a short-hand for, and implemented as, `iter.collect::<Vec<_>>().into()`.
+The elements are written directly into the bit-vector’s buffer, with no
+intermediate [`Vec`]. The result is the same as collecting them into a [`Vec`]
+and passing it to [`BitVec::from_vec`].
 
 This is not a standard-library API, and was added for [Issue #83].
 
+# Examples
+
+```rust
+use bitvec::prelude::*;
+
+let bv: BitVec<Msb0, u8> = b"hi".iter().copied().collect();
+assert_eq!(bv.as_slice(), b"hi");
+assert_eq!(bv.len(), 16);
+```
+
 [Issue #83]: https://github.com/myrrlyn/bitvec/issues/83
+[`BitVec::from_vec`]: crate::vec::BitVec::from_vec
+[`Vec`]: alloc::vec::Vec
 **/
 impl<O, T> FromIterator<T> for BitVec<O, T>
 where
@@ -121,7 +220,7 @@ where
 {
 	fn from_iter<I>(iter: I) -> Self
 	where I: IntoIterator<Item = T> {
-		iter.into_iter().collect::<Vec<_>>().pipe(Self::from_vec)
+		Self::new().tap_mut(|bv| bv.extend(iter))
 	}
 }
 
@@ -132,14 +231,7 @@ where
 {
 	fn from_iter<I>(iter: I) -> Self
 	where I: IntoIterator<Item = &'a T> {
-		let mut vec = iter
-			.into_iter()
-			.map(BitStore::load_value)
-			.collect::<Vec<T::Mem>>()
-			.pipe(ManuallyDrop::new);
-		let (ptr, len, capa) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
-		unsafe { Vec::from_raw_parts(ptr as *mut T, len, capa) }
-			.pipe(Self::from_vec)
+		Self::new().tap_mut(|bv| bv.extend(iter))
 	}
 }
 
@@ -225,8 +317,11 @@ where
 		//  lifetime.
 		let iter = bv.as_bitslice().bitptr().to_bitslice_ref().iter();
 		//  Only the allocation’s base and capacity need to be kept for `Drop`.
-		let base = bv.bitptr().pointer().to_nonnull();
-		let capa = bv.alloc_capacity();
+		//  These include any unused elements in front of the vector.
+		let base = unsafe { bv.bitptr().pointer().to_mut().sub(bv.front) }
+			.pipe(Address::from)
+			.to_nonnull();
+		let capa = bv.capacity + bv.front;
 		Self { base, capa, iter }
 	}
 
@@ -380,11 +475,21 @@ where
 
 This `struct` is created by the [`.drain()`] method on [`BitVec`].
 
+# Leaking
+
+The source vector is truncated to the start of the drained range as soon as the
+iterator is created, and is only restored to include the tail when the
+iterator is dropped. If the iterator is leaked, with [`mem::forget`] for
+example, the vector is left with exactly the bits that preceded the drained
+range. Its length never describes drained, moved, or unwritten bits, so it can
+still be used, and unsafe code can rely on its contents.
+
 # Original
 
 [`vec::Drain`](alloc::vec::Drain)
 
 [`BitVec`]: crate::vec::BitVec
+[`mem::forget`]: core::mem::forget
 [`.drain()`]: crate::vec::BitVec::drain
 **/
 pub struct Drain<'a, O, T>
@@ -459,6 +564,47 @@ where
 		self.as_bitslice()
 	}
 
+	/// Keeps the bits that the iterator has not yet yielded in the source
+	/// vector.
+	///
+	/// The bits already yielded from either end are removed, as they would be
+	/// by dropping the iterator, but the bits that remain between them are
+	/// kept, and are followed by the tail of the vector.
+	///
+	/// # Original
+	///
+	/// [`Drain::keep_rest`](alloc::vec::Drain::keep_rest)
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 0, 0];
+	/// let mut drain = bv.drain(1 .. 6);
+	/// assert_eq!(drain.next(), Some(true));
+	/// assert_eq!(drain.next_back(), Some(false));
+	/// drain.keep_rest();
+	/// assert_eq!(bv, bits![0, 1, 0, 1, 0]);
+	/// ```
+	pub fn keep_rest(self) {
+		let this = ManuallyDrop::new(self);
+		let (rest, tail) = (this.drain.as_bitslice(), this.tail.clone());
+		let bitvec = unsafe { &mut *this.source.as_ptr() };
+		let start = bitvec.len();
+		let front = bitvec.as_bitslice().offset_from(rest) as usize;
+		let rest_len = rest.len();
+		let tail_len = tail.end - tail.start;
+		unsafe {
+			//  Restore the original length, as in `Drop`, so that both copies
+			//  are within the `len` boundary.
+			bitvec.set_len(tail.end);
+			bitvec.copy_within_unchecked(front .. front + rest_len, start);
+			bitvec.copy_within_unchecked(tail, start + rest_len);
+			bitvec.set_len(start + rest_len + tail_len);
+		}
+	}
+
 	/// Attempts to overwrite the drained region with another iterator.
 	///
 	/// # Type Parameters
@@ -734,6 +880,43 @@ where
 		let splice = splice.into_iter();
 		Self { drain, splice }
 	}
+
+	/// Returns the removed bits that this iterator has not yet yielded as a
+	/// [`BitSlice`].
+	///
+	/// Each bit that the iterator yields from the front is replaced by a bit
+	/// from the replacement stream, so the view borrows the iterator, and must
+	/// be released before the iteration continues.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0, 0];
+	/// let mut splice = bv.splice(1 .. 4, vec![false; 2]);
+	/// assert_eq!(splice.as_bitslice(), bits![1, 1, 0]);
+	/// assert_eq!(splice.next(), Some(true));
+	/// assert_eq!(splice.as_bitslice(), bits![1, 0]);
+	/// drop(splice);
+	/// assert_eq!(bv, bits![0; 4]);
+	/// ```
+	///
+	/// [`BitSlice`]: crate::slice::BitSlice
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.drain.as_bitslice()
+	}
+}
+
+impl<O, T, I> AsRef<BitSlice<O, T>> for Splice<'_, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+	fn as_ref(&self) -> &BitSlice<O, T> {
+		self.as_bitslice()
+	}
 }
 
 impl<O, T, I> Iterator for Splice<'_, O, T, I>
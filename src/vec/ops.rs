@@ -21,6 +21,10 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
@@ -127,6 +131,10 @@ where
 	T: BitStore,
 {
 	fn drop(&mut self) {
+		#[cfg(feature = "zeroize")]
+		zeroize::Zeroize::zeroize(self);
+		//  The contents are discarded, so they need not be moved.
+		self.release_front();
 		//  Run the `Vec` destructor to deällocate the buffer.
 		self.with_vec(|slot| unsafe { ManuallyDrop::drop(slot) });
 	}
@@ -174,3 +182,49 @@ where
 		self
 	}
 }
+
+impl<O, T> Shl<usize> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Output = Self;
+
+	fn shl(mut self, by: usize) -> Self::Output {
+		self.as_mut_bitslice().shift_numeric(by, true);
+		self
+	}
+}
+
+impl<O, T> ShlAssign<usize> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn shl_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_numeric(by, true);
+	}
+}
+
+impl<O, T> Shr<usize> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Output = Self;
+
+	fn shr(mut self, by: usize) -> Self::Output {
+		self.as_mut_bitslice().shift_numeric(by, false);
+		self
+	}
+}
+
+impl<O, T> ShrAssign<usize> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn shr_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_numeric(by, false);
+	}
+}
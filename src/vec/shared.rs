@@ -0,0 +1,268 @@
+//! A reference-counted, copy-on-write bit-vector.
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::sync::Arc;
+
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	ops::Deref,
+};
+
+/** A bit-vector whose clones share one buffer until they are modified.
+
+Cloning a `SharedBitVec` only increments a reference count, so a large bit-set
+can be handed to many threads or tasks cheaply. A handle that needs to change
+its bits calls [`.make_mut()`], which copies the buffer only if it is still
+shared with another handle, and then gives out an ordinary `&mut BitVec`.
+Modifications made through one handle are never visible through another.
+
+This is the bit-vector equivalent of using an `Arc<Vec<T>>` with
+[`Arc::make_mut`]. Like `Arc`, it is only available on targets that have atomic
+instructions for pointer-sized integers.
+
+# Examples
+
+```rust
+use bitvec::{
+  prelude::*,
+  vec::SharedBitVec,
+};
+
+let mask = SharedBitVec::from(bitvec![0; 1024]);
+let mut worker = mask.clone();
+assert!(SharedBitVec::ptr_eq(&mask, &worker));
+
+worker.make_mut().set(7, true);
+assert!(!SharedBitVec::ptr_eq(&mask, &worker));
+assert!(mask.not_any());
+assert_eq!(worker.count_ones(), 1);
+```
+
+[`Arc::make_mut`]: alloc::sync::Arc::make_mut
+[`.make_mut()`]: Self::make_mut
+**/
+pub struct SharedBitVec<O = Lsb0, T = usize>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The shared buffer.
+	inner: Arc<BitVec<O, T>>,
+}
+
+impl<O, T> SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Moves a bit-vector into a new shared handle.
+	///
+	/// This does not copy the bit-vector’s buffer.
+	pub fn from_bitvec(bv: BitVec<O, T>) -> Self {
+		Self {
+			inner: Arc::new(bv),
+		}
+	}
+
+	/// Views the shared bits.
+	pub fn as_bitslice(&self) -> &BitSlice<O, T> {
+		self.inner.as_bitslice()
+	}
+
+	/// Views the shared bit-vector.
+	pub fn as_bitvec(&self) -> &BitVec<O, T> {
+		&self.inner
+	}
+
+	/// Gets exclusive access to the bit-vector, copying it first if any other
+	/// handle shares it.
+	///
+	/// Once this has been called, `self` no longer shares its buffer with any
+	/// other handle, so later calls do not copy until `self` is cloned again.
+	///
+	/// # Original
+	///
+	/// [`Arc::make_mut`](alloc::sync::Arc::make_mut)
+	pub fn make_mut(&mut self) -> &mut BitVec<O, T> {
+		Arc::make_mut(&mut self.inner)
+	}
+
+	/// Gets exclusive access to the bit-vector if no other handle shares it.
+	///
+	/// # Original
+	///
+	/// [`Arc::get_mut`](alloc::sync::Arc::get_mut)
+	pub fn get_mut(&mut self) -> Option<&mut BitVec<O, T>> {
+		Arc::get_mut(&mut self.inner)
+	}
+
+	/// Unwraps the bit-vector, copying it if any other handle shares it.
+	pub fn into_bitvec(self) -> BitVec<O, T> {
+		Arc::try_unwrap(self.inner).unwrap_or_else(|arc| (*arc).clone())
+	}
+
+	/// Tests whether two handles share the same buffer.
+	///
+	/// # Original
+	///
+	/// [`Arc::ptr_eq`](alloc::sync::Arc::ptr_eq)
+	pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+		Arc::ptr_eq(&this.inner, &other.inner)
+	}
+}
+
+impl<O, T> AsRef<BitSlice<O, T>> for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn as_ref(&self) -> &BitSlice<O, T> {
+		self.as_bitslice()
+	}
+}
+
+impl<O, T> Borrow<BitSlice<O, T>> for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn borrow(&self) -> &BitSlice<O, T> {
+		self.as_bitslice()
+	}
+}
+
+impl<O, T> Clone for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O, T> Debug for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().bitptr().render(fmt, "SharedVec", None)?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+impl<O, T> Default for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn default() -> Self {
+		Self::from_bitvec(BitVec::new())
+	}
+}
+
+impl<O, T> Deref for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Target = BitSlice<O, T>;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O, T> Display for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Display::fmt(self.as_bitslice(), fmt)
+	}
+}
+
+impl<O, T> Eq for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> From<BitVec<O, T>> for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bv: BitVec<O, T>) -> Self {
+		Self::from_bitvec(bv)
+	}
+}
+
+impl<O, T> From<&BitSlice<O, T>> for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: &BitSlice<O, T>) -> Self {
+		Self::from_bitvec(BitVec::from_bitslice(bits))
+	}
+}
+
+impl<O, T> From<SharedBitVec<O, T>> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(shared: SharedBitVec<O, T>) -> Self {
+		shared.into_bitvec()
+	}
+}
+
+impl<O, T> Hash for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn hash<H>(&self, state: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(state)
+	}
+}
+
+impl<O, T, Rhs> PartialEq<Rhs> for SharedBitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	Rhs: ?Sized + PartialEq<BitSlice<O, T>>,
+{
+	fn eq(&self, other: &Rhs) -> bool {
+		other == self.as_bitslice()
+	}
+}
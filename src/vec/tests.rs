@@ -1,15 +1,22 @@
 #![cfg(test)]
 
-use crate::prelude::*;
+use crate::{
+	error::Error,
+	prelude::*,
+};
 
 use core::{
 	borrow::{
 		Borrow,
 		BorrowMut,
 	},
-	cmp::Ordering,
+	cmp::{
+		self,
+		Ordering,
+	},
 	convert::TryInto,
 	iter,
+	mem,
 	ptr,
 };
 
@@ -36,6 +43,21 @@ fn from_vec() {
 	assert_eq!(bits as *mut _, bp_mut);
 	let bv = unsafe { BitVec::from_raw_parts(bits, capa) };
 	assert_eq!(bv.as_slice(), &[0, 1, 2, 3]);
+
+	let bv = BitVec::<Lsb0, u16>::try_from_slice(&[5, 0]).unwrap();
+	assert_eq!(bv.len(), 32);
+	assert_eq!(bv.iter_ones().collect::<Vec<_>>(), [0, 2]);
+
+	#[cfg(not(miri))]
+	assert!(matches!(
+		BitVec::<LocalBits, u8>::try_from_slice(unsafe {
+			core::slice::from_raw_parts(
+				1usize as *const _,
+				BitSlice::<LocalBits, u8>::MAX_ELTS,
+			)
+		}),
+		Err(crate::error::Error::TooLong { .. })
+	));
 }
 
 #[test]
@@ -54,6 +76,273 @@ fn push() {
 	bvm08.extend(&[true; 3]);
 	bvm08.extend(&[false; 3]);
 	assert_eq!(bvm08, bits![0, 1, 1, 1, 1, 0, 0, 0]);
+
+	let elts = bvm08.alloc_capacity();
+	while bvm08.push_within_capacity(true).is_ok() {}
+	assert_eq!(bvm08.len(), bvm08.capacity());
+	assert_eq!(bvm08.alloc_capacity(), elts);
+	assert_eq!(bvm08.push_within_capacity(true), Err(true));
+
+	assert_eq!(bvm08.pop_if(|bit| !*bit), None);
+	assert_eq!(bvm08.pop_if(|bit| mem::replace(bit, false)), Some(false));
+	assert_eq!(bvm08.pop_if(|bit| mem::replace(bit, false)), Some(false));
+	assert_eq!(
+		bvm08.pop_if(|bit| {
+			*bit = false;
+			false
+		}),
+		None
+	);
+	assert!(!bvm08[bvm08.len() - 1]);
+	assert_eq!(BitVec::<Msb0, u8>::new().pop_if(|_| true), None);
+}
+
+#[test]
+fn fallible_growth() {
+	use crate::error::Error;
+
+	let mut bv = BitVec::<Lsb0, u16>::new();
+	for idx in 0 .. 100 {
+		bv.try_push(idx % 3 == 0).unwrap();
+	}
+	assert_eq!(bv.len(), 100);
+	assert_eq!(bv.count_ones(), 34);
+	bv.try_extend_from_bitslice(bits![Msb0, u8; 1; 40]).unwrap();
+	assert_eq!(bv.count_ones(), 74);
+	assert!(bv[100 ..].all());
+
+	let mut bv = bitvec![Msb0, u8; 1; 3];
+	bv.try_reserve_exact(13).unwrap();
+	assert_eq!(bv.alloc_capacity(), 2);
+	bv.try_reserve(14).unwrap();
+	assert!(bv.capacity() >= 17);
+	assert_eq!(bv, bits![1; 3]);
+
+	let max = BitSlice::<Msb0, u8>::MAX_BITS;
+	assert_eq!(
+		bv.try_reserve(max),
+		Err(Error::TooLong { len: max + 3, max })
+	);
+	assert!(matches!(bv.try_push(false), Ok(())));
+	assert_eq!(bv, bits![1, 1, 1, 0]);
+
+	//  Growth is geometric in the allocation, not in the live elements.
+	let mut bv = BitVec::<Lsb0, u8>::with_capacity(80);
+	for idx in 0 .. 5000 {
+		bv.try_push(idx % 5 == 0).unwrap();
+		assert!(bv.capacity() <= cmp::max(80, bv.len() * 2 + 8));
+	}
+	assert_eq!(bv.count_ones(), 1000);
+
+	//  No allocator can provide a quarter of the 64-bit address space.
+	#[cfg(all(target_pointer_width = "64", not(miri)))]
+	assert!(matches!(
+		BitVec::<Lsb0, u8>::new().try_reserve_exact(max),
+		Err(Error::Alloc { .. })
+	));
+}
+
+#[test]
+fn from_fn() {
+	let bv = BitVec::<Msb0, u16>::from_fn(40, |n| n % 5 == 1);
+	assert_eq!(bv.len(), 40);
+	assert_eq!(bv.as_slice(), &[0x4210, 0x8421, 0x0800]);
+	for (idx, bit) in bv.iter().enumerate() {
+		assert_eq!(*bit, idx % 5 == 1);
+	}
+
+	let mut calls = 0;
+	let bv = BitVec::<Lsb0, u8>::from_fn(3, |n| {
+		calls += 1;
+		n == 2
+	});
+	assert_eq!(calls, 3);
+	assert_eq!(bv.as_slice(), &[0b100]);
+
+	assert!(BitVec::<Lsb0, u8>::from_fn(0, |_| true).is_empty());
+}
+
+#[test]
+fn from_indices() {
+	let edges = [17, 3, 40, 3, 0];
+	let bv =
+		BitVec::<Msb0, u32>::from_indices(41, edges.iter().copied()).unwrap();
+	assert_eq!(bv.len(), 41);
+	assert_eq!(bv.iter_ones().collect::<Vec<_>>(), [0, 3, 17, 40]);
+
+	let mut rest = edges.iter().copied();
+	assert_eq!(
+		BitVec::<Msb0, u32>::from_indices(20, rest.by_ref()),
+		Err(crate::slice::IndexError::OutOfBounds { index: 40, len: 20 }),
+	);
+	assert_eq!(rest.next(), Some(3));
+
+	let grown = BitVec::<Msb0, u32>::from_indices_growing(edges.iter().copied());
+	assert_eq!(grown, bv);
+	let one = BitVec::<Lsb0, u8>::from_indices_growing(vec![5]);
+	assert_eq!(one, bits![0, 0, 0, 0, 0, 1]);
+}
+
+#[test]
+fn push_pop_front() {
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	assert!(bv.pop_front().is_none());
+
+	for n in 0 .. 20 {
+		bv.push_front(n % 3 == 0);
+	}
+	assert_eq!(bv.len(), 20);
+	assert_eq!(bv.as_slice().len(), 3);
+	for (idx, bit) in bv.iter().rev().enumerate() {
+		assert_eq!(*bit, idx % 3 == 0);
+	}
+
+	bv.push(true);
+	for n in 0 .. 20 {
+		assert_eq!(bv.pop_front(), Some((19 - n) % 3 == 0));
+	}
+	assert_eq!(bv, bits![1]);
+	assert_eq!(bv.as_slice().len(), 1);
+
+	assert_eq!(bv.pop_front(), Some(true));
+	assert!(bv.is_empty());
+	bv.push_front(true);
+	bv.push(false);
+	assert_eq!(bv, bits![1, 0]);
+
+	//  Prepending moves the buffer only when it runs out of unused elements in
+	//  front of the vector, and popping never moves it.
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	let (mut wraps, mut moves) = (0, 0);
+	for n in 0 .. 8000 {
+		let (base, elts) = (bv.as_ptr(), bv.as_slice().len());
+		bv.push_front(n % 5 == 0);
+		if bv.as_slice().len() != elts {
+			wraps += 1;
+			if bv.as_ptr() != base.wrapping_sub(1) {
+				moves += 1;
+			}
+		}
+	}
+	assert_eq!(wraps, 1000);
+	assert!(moves <= 12, "{}", moves);
+	for n in (0 .. 8000).rev() {
+		let base = bv.as_ptr();
+		assert_eq!(bv.pop_front(), Some(n % 5 == 0));
+		assert!(bv.as_ptr() == base || bv.as_ptr() == base.wrapping_add(1));
+	}
+
+	//  The unused front is returned before the buffer leaves the vector.
+	let mut bv = BitVec::<Msb0, u8>::from_vec(vec![0x0F, 0xA5, 0x3C]);
+	for _ in 0 .. 12 {
+		bv.pop_front();
+	}
+	let rest = bv.clone();
+	bv.reserve(100);
+	assert_eq!(bv, rest);
+	assert_eq!(bv.as_slice(), &[0xA5, 0x3C]);
+	for _ in 0 .. 8 {
+		bv.pop_front();
+	}
+	assert_eq!(bv.clone().into_iter().count(), 4);
+	assert_eq!(bv.into_vec(), vec![0x3C]);
+}
+
+#[test]
+fn insert_remove() {
+	fn check<O, T>()
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut bv = BitVec::<O, T>::from_fn(70, |n| n % 3 == 0);
+		//  Start the vector partway through its first element.
+		bv.pop_front();
+		let mut model = bv.iter().copied().collect::<Vec<bool>>();
+
+		for &(idx, bit) in &[(0, true), (5, false), (30, true), (70, true)] {
+			bv.insert(idx, bit);
+			model.insert(idx, bit);
+			assert!(bv.iter().copied().eq(model.iter().copied()));
+		}
+		for &idx in &[0, 9, 40, 69] {
+			assert_eq!(bv.remove(idx), model.remove(idx));
+			assert!(bv.iter().copied().eq(model.iter().copied()));
+		}
+
+		bv.insert_bitslice(3, bits![1, 1, 0, 1]);
+		for (n, &bit) in [true, true, false, true].iter().enumerate() {
+			model.insert(3 + n, bit);
+		}
+		assert!(bv.iter().copied().eq(model.iter().copied()));
+
+		bv.remove_range(10 .. 25);
+		model.drain(10 .. 25);
+		assert!(bv.iter().copied().eq(model.iter().copied()));
+	}
+
+	check::<Lsb0, u8>();
+	check::<Msb0, u8>();
+	check::<Lsb0, u16>();
+	check::<Msb0, u32>();
+	check::<Lsb0, usize>();
+	check::<Msb0, usize>();
+}
+
+#[test]
+fn extend_from_within() {
+	let mut bv = bitvec![Msb0, u8; 1, 0, 1];
+	bv.extend_from_within(..);
+	bv.extend_from_within(2 .. 5);
+	assert_eq!(bv, bits![1, 0, 1, 1, 0, 1, 1, 1, 0]);
+
+	bv.extend_from_within(4 .. 4);
+	assert_eq!(bv.len(), 9);
+}
+
+#[test]
+fn extend_elements() {
+	let bv: BitVec<Lsb0, u16> = (0x1234u16 ..= 0x1236).collect();
+	assert_eq!(bv.as_slice(), &[0x1234, 0x1235, 0x1236]);
+	assert_eq!(bv.len(), 48);
+
+	let mut bv = bitvec![Msb0, u8; 1, 1, 0];
+	bv.extend([0x96u8, 0xC3].iter());
+	assert_eq!(bv.len(), 19);
+	assert_eq!(bv[.. 3], bits![1, 1, 0]);
+	assert_eq!(bv[3 ..].load_be::<u16>(), 0x96C3);
+
+	//  A vector whose first element is partially dead does not end on an
+	//  element boundary even when its length is a multiple of the width.
+	let mut bv = bitvec![Lsb0, u8; 0; 10];
+	bv.drain(.. 2);
+	bv.extend(Some(0xA5u8));
+	assert_eq!(bv.len(), 16);
+	assert_eq!(bv[8 ..].load_le::<u8>(), 0xA5);
+
+	let mut bv = bitvec![Lsb0, u32; 1; 5];
+	let data = [0x3Cu8, 0xFF, 0x01];
+	bv.extend(data.view_bits::<Msb0>().chunks(6));
+	bv.extend(Some(bits![Lsb0, u64; 0, 1]));
+	assert_eq!(bv.len(), 31);
+	assert_eq!(bv[5 .. 29], data.view_bits::<Msb0>());
+	assert_eq!(bv[29 ..], bits![0, 1]);
+
+	let mut bv = BitVec::<Msb0, u16>::new();
+	bv.extend_from_raw_slice(&[0x0102, 0x0304]);
+	bv.push_element(0x0506);
+	assert_eq!(bv.as_slice(), &[0x0102, 0x0304, 0x0506]);
+	assert_eq!(bv.len(), 48);
+
+	bv.drain(.. 4);
+	bv.extend_from_raw_slice(&[0xFFFF, 0x8000]);
+	bv.push_element(1);
+	assert_eq!(bv.len(), 92);
+	assert_eq!(bv[44 .. 60], bits![1; 16]);
+	assert_eq!(bv[60 .. 76].load_be::<u16>(), 0x8000);
+	assert_eq!(bv[76 ..].load_be::<u16>(), 1);
+	bv.extend_from_raw_slice(&[]);
+	assert_eq!(bv.len(), 92);
 }
 
 #[test]
@@ -78,9 +367,9 @@ fn buffer_control() {
 	assert_eq!(bv.as_slice(), &[0b1001_0101]);
 
 	bv.truncate(6);
-	bv.set_uninitialized(false);
+	bv.fill_uninitialized(false);
 	assert_eq!(bv.as_slice(), &[0b1001_0100]);
-	bv.set_uninitialized(true);
+	bv.fill_uninitialized(true);
 	assert_eq!(bv.as_slice(), &[0b1001_0111]);
 	assert_eq!(bv, bits![1, 0, 0, 1, 0, 1]);
 }
@@ -220,9 +509,34 @@ fn iterators() {
 	assert!(drain.last().unwrap());
 	assert_eq!(bitvec![0, 0, 1, 1, 0, 0,].drain(2 .. 4).count(), 2);
 
+	let mut bv = bitvec![Msb0, u8; 1; 20];
+	bv[8 .. 12].set_all(false);
+	mem::forget(bv.drain(5 .. 15));
+	assert_eq!(bv, bits![1; 5]);
+
+	let mut bv = (0 .. 40)
+		.map(|idx| idx % 3 == 0)
+		.collect::<BitVec<Lsb0, u16>>();
+	let model = bv.clone();
+	let mut drain = bv.drain(6 .. 30);
+	drain.nth(3);
+	drain.nth_back(5);
+	drain.keep_rest();
+	let mut expected = model[.. 6].to_bitvec();
+	expected.extend_from_bitslice(&model[10 .. 24]);
+	expected.extend_from_bitslice(&model[30 ..]);
+	assert_eq!(bv, expected);
+
+	let mut bv = bitvec![0, 1, 0];
+	bv.drain(..).keep_rest();
+	assert_eq!(bv, bits![0, 1, 0]);
+
 	let mut bv = bitvec![0, 0, 1, 0, 1, 1, 0, 1, 0, 0];
 	let mut splice = bv.splice(2 .. 8, iter::repeat(false).take(4));
+	assert_eq!(splice.as_bitslice(), bits![1, 0, 1, 1, 0, 1]);
 	assert!(splice.next().unwrap());
+	let removed: &BitSlice = splice.as_ref();
+	assert_eq!(removed, bits![0, 1, 1, 0, 1]);
 	assert!(splice.next_back().unwrap());
 	assert!(splice.nth(1).unwrap());
 	assert!(splice.nth_back(1).unwrap());
@@ -309,6 +623,61 @@ fn cloning() {
 	assert_ne!(a, b);
 	a.clone_from(&b);
 	assert_eq!(a, b);
+
+	let src = BitVec::from_bitslice(&0x5Au8.view_bits::<Msb0>()[3 ..]);
+	let mut dst = BitVec::<Msb0, u8>::with_capacity(64);
+	dst.extend_from_bitslice(bits![1; 40]);
+	let base = dst.as_slice().as_ptr();
+	dst.clone_from(&src);
+	assert_eq!(dst, src);
+	assert_eq!(dst.as_slice(), &[0x5A]);
+	assert_eq!(dst.as_slice().as_ptr(), base);
+
+	dst.clone_from(&BitVec::new());
+	assert!(dst.is_empty());
+}
+
+radium::if_atomic! {
+	if atomic(ptr) {
+		#[test]
+		fn shared() {
+			use crate::vec::SharedBitVec;
+
+			let base = SharedBitVec::from(bitvec![Msb0, u8; 0, 1, 1, 0]);
+			let mut copy = base.clone();
+			assert!(SharedBitVec::ptr_eq(&base, &copy));
+			assert!(copy.get_mut().is_none());
+			assert_eq!(copy, base);
+
+			copy.make_mut().push(true);
+			assert!(!SharedBitVec::ptr_eq(&base, &copy));
+			assert_eq!(base, bits![0, 1, 1, 0]);
+			assert_eq!(copy, bits![0, 1, 1, 0, 1]);
+
+			let ptr = copy.as_bitslice().as_ptr();
+			copy.make_mut().set(0, true);
+			assert_eq!(copy.as_bitslice().as_ptr(), ptr);
+			assert!(copy.get_mut().is_some());
+			assert_eq!(copy.clone().into_bitvec(), bits![1, 1, 1, 0, 1]);
+			assert_eq!(copy.into_bitvec().as_bitslice().as_ptr(), ptr);
+
+			#[cfg(feature = "std")]
+			{
+				let counts = (0 .. 4)
+					.map(|n| {
+						let mut mask = base.clone();
+						std::thread::spawn(move || {
+							mask.make_mut().set(n, true);
+							mask.count_ones()
+						})
+					})
+					.map(|handle| handle.join().unwrap())
+					.collect::<Vec<_>>();
+				assert_eq!(counts, [3, 2, 2, 3]);
+				assert_eq!(base.count_ones(), 2);
+			}
+		}
+	}
 }
 
 #[test]
@@ -355,6 +724,24 @@ fn traits() {
 	assert!(!bv.eq(&bv2));
 	assert_eq!((&bv.as_bitslice()).partial_cmp(&bv2), Some(Ordering::Less));
 
+	let bb = bitbox![Msb0, u8; 0, 0, 1, 1];
+	let ba = bitarr![Lsb0, u16; 0, 0, 1, 1];
+	let ba = &ba[.. 4];
+	assert_eq!(bv, bb);
+	assert_eq!(bb, bv);
+	assert_eq!(bv, *ba);
+	assert_eq!(bv, ba);
+	assert_eq!(bb, ba);
+	assert_eq!(*ba, bv);
+	assert_eq!(ba, bb);
+	assert!(bv < bv2);
+	assert!(bb < bv2);
+	assert!(bv2 > bb);
+	assert!(bv2 > *ba);
+	assert_eq!(bitvec![0].partial_cmp(bits![1]), Some(Ordering::Less));
+	assert_eq!(bitbox![1].partial_cmp(bits![0]), Some(Ordering::Greater));
+	assert_eq!(bitvec![0].partial_cmp(&bitarr![1]), Some(Ordering::Less));
+
 	let _: &BitSlice = bv.as_ref();
 	let _: &mut BitSlice = bv.as_mut();
 
@@ -364,7 +751,7 @@ fn traits() {
 	assert_eq!(bv, bits![0, 1, 0, 1]);
 	let vec: Vec<usize> = bv.into();
 	assert_eq!(vec.len(), 1);
-	let bv: Result<BitVec, Vec<usize>> = vec.try_into();
+	let bv: Result<BitVec, (Error, Vec<usize>)> = vec.try_into();
 	assert!(bv.is_ok());
 }
 
@@ -389,4 +776,11 @@ fn format() {
 		text
 	);
 	assert!(text.ends_with(" } [0100]"), "{}", text);
+
+	let mut bv = bv;
+	bv.pop_front();
+	assert_eq!(
+		format!("{:p}", bv),
+		format!("{:p}[1]", bv.as_slice().as_ptr())
+	);
 }
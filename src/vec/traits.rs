@@ -2,6 +2,7 @@
 
 use crate::{
 	boxed::BitBox,
+	error::Error,
 	order::BitOrder,
 	slice::BitSlice,
 	store::BitStore,
@@ -25,6 +26,7 @@ use core::{
 		Formatter,
 		LowerHex,
 		Octal,
+		Pointer,
 		UpperHex,
 	},
 	hash::{
@@ -64,10 +66,20 @@ where
 		Self::new().tap_mut(|bv| bv.clone_from(self))
 	}
 
+	/// Copies `source` into `self`, reusing `self`’s allocation.
+	///
+	/// The memory elements under `source` are copied in bulk, and `self`
+	/// takes on `source`’s head index, so no bit-by-bit work is done. `self`
+	/// only reallocates if its capacity is too small to hold them.
 	fn clone_from(&mut self, source: &Self) {
 		self.clear();
-		self.resize(source.len(), false);
-		self.copy_from_bitslice(source.as_bitslice());
+		self.with_vec(|vec| vec.extend(source.as_bitslice().domain()));
+		let mut bitptr = self.bitptr();
+		unsafe {
+			bitptr.set_head(source.bitptr().head());
+			bitptr.set_len(source.len());
+		}
+		self.pointer = bitptr.to_nonnull();
 	}
 }
 
@@ -128,10 +140,11 @@ impl<O, T, Rhs> PartialEq<Rhs> for BitVec<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
-	Rhs: ?Sized + PartialEq<BitSlice<O, T>>,
+	Rhs: ?Sized,
+	BitSlice<O, T>: PartialEq<Rhs>,
 {
 	fn eq(&self, other: &Rhs) -> bool {
-		other == self.as_bitslice()
+		self.as_bitslice() == other
 	}
 }
 
@@ -175,10 +188,11 @@ impl<O, T, Rhs> PartialOrd<Rhs> for BitVec<O, T>
 where
 	O: BitOrder,
 	T: BitStore,
-	Rhs: ?Sized + PartialOrd<BitSlice<O, T>>,
+	Rhs: ?Sized,
+	BitSlice<O, T>: PartialOrd<Rhs>,
 {
 	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
-		other.partial_cmp(self.as_bitslice())
+		self.as_bitslice().partial_cmp(other)
 	}
 }
 
@@ -247,7 +261,7 @@ where
 	O: BitOrder,
 	T: BitStore,
 {
-	type Error = Vec<T>;
+	type Error = (Error, Vec<T>);
 
 	fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
 		Self::try_from_vec(vec)
@@ -329,6 +343,16 @@ where
 	}
 }
 
+impl<O, T> Pointer for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Pointer::fmt(self.as_bitslice(), fmt)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<O, T> Hash for BitVec<O, T>
 where
@@ -0,0 +1,377 @@
+/*! A word-aligned hybrid compressed bitmap.
+
+Columns of analytical data are often sparse, or made of long runs of a single
+value, and are combined with each other far more often than they are indexed.
+This module provides [`WahBitmap`], which stores a bit-sequence in the
+Word-Aligned Hybrid (WAH) encoding, and computes `&`, `|`, and `^` by walking
+the compressed words of both operands together, without decompressing either
+of them.
+
+[`WahBitmap`]: self::WahBitmap
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::{
+	cmp,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+	},
+};
+
+/// The number of bits held in the payload of a literal word.
+const GROUP: usize = 31;
+
+/// Marks a word as a fill of repeated groups, rather than a literal.
+const FILL: u32 = 1 << 31;
+
+/// Marks a fill word as a run of `1` groups, rather than `0` groups.
+const FILL_ONES: u32 = 1 << 30;
+
+/// The run-length field of a fill word, and its largest value.
+const RUN: u32 = FILL_ONES - 1;
+
+/// The payload of a literal word, which is also a group of all `1` bits.
+const LITERAL: u32 = !FILL;
+
+/** A bit-sequence compressed in the Word-Aligned Hybrid encoding.
+
+The bits are cut into groups of 31, and each group is stored in one `u32` word.
+A group that mixes `0` and `1` bits is stored as a *literal* word, with the
+high bit clear and the group in the low 31 bits. A run of groups that are all
+`0` or all `1` is stored as one *fill* word, with the high bit set, the next bit
+holding the fill value, and the low 30 bits counting the groups in the run.
+
+The encoding is kept canonical: no literal word is uniform, adjacent fills of
+the same value are merged, and the bits after the end of the sequence in its
+last group are cleared. Two bitmaps are therefore equal exactly when they hold
+the same bits.
+
+The bitwise operators take their operands by reference, and produce a new
+bitmap as long as the longer operand. As with the operators on [`BitSlice`],
+the shorter operand is treated as if it were extended with `0` bits. Each
+operator walks the words of both operands once, and a pair of fills is
+combined in one step however many groups it covers, so the cost is
+proportional to the compressed sizes of the operands.
+
+# Examples
+
+```rust
+use bitvec::{
+  prelude::*,
+  wah::WahBitmap,
+};
+
+let mut a = bitvec![0; 1000];
+a[10 .. 600].set_all(true);
+let mut b = bitvec![0; 1000];
+b.set(20, true);
+b[500 .. 900].set_all(true);
+
+let wa = WahBitmap::from_bitslice(&a);
+let wb = WahBitmap::from_bitslice(&b);
+assert!(wa.as_words().len() < 10);
+
+let both = &wa & &wb;
+assert_eq!(both.count_ones(), 101);
+assert_eq!(both.to_bitvec::<Lsb0, usize>(), a.clone() & b.clone());
+assert_eq!((&wa | &wb).to_bitvec::<Lsb0, usize>(), a.clone() | b.clone());
+assert_eq!((&wa ^ &wb).to_bitvec::<Lsb0, usize>(), a ^ b);
+```
+
+[`BitSlice`]: crate::slice::BitSlice
+**/
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct WahBitmap {
+	/// The literal and fill words.
+	words: Vec<u32>,
+	/// The number of bits in the sequence.
+	len: usize,
+}
+
+impl WahBitmap {
+	/// Constructs an empty bitmap.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Compresses a bit-slice.
+	///
+	/// The first bit of `bits` is the first bit of the bitmap, whatever the
+	/// ordering of `bits` in its memory.
+	pub fn from_bitslice<O, T>(bits: &BitSlice<O, T>) -> Self
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut out = Self::new();
+		for chunk in bits.chunks(GROUP) {
+			let group = chunk.iter_ones().fold(0, |acc, idx| acc | 1 << idx);
+			out.push_groups(group, 1);
+		}
+		out.len = bits.len();
+		out
+	}
+
+	/// Counts the bits in the bitmap.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests if the bitmap has no bits.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Views the compressed words.
+	///
+	/// The length of this slice is the size of the bitmap in memory, and the
+	/// cost of combining it with another.
+	pub fn as_words(&self) -> &[u32] {
+		&self.words
+	}
+
+	/// Counts the `1` bits in the bitmap, without decompressing it.
+	pub fn count_ones(&self) -> usize {
+		self.words
+			.iter()
+			.map(|&word| match word {
+				w if w & FILL == 0 => w.count_ones() as usize,
+				w if w & FILL_ONES != 0 => (w & RUN) as usize * GROUP,
+				_ => 0,
+			})
+			.sum()
+	}
+
+	/// Counts the `0` bits in the bitmap, without decompressing it.
+	pub fn count_zeros(&self) -> usize {
+		self.len - self.count_ones()
+	}
+
+	/// Decompresses the bitmap into a bit-vector.
+	pub fn to_bitvec<O, T>(&self) -> BitVec<O, T::Unalias>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut out = BitVec::repeat(false, self.len);
+		let mut groups = Groups::new(&self.words);
+		let mut start = 0;
+		while let Some((group, count)) = groups.current() {
+			let end = cmp::min(start + count * GROUP, self.len);
+			if group == LITERAL {
+				out[start .. end].set_all(true);
+			}
+			else if count == 1 {
+				let mut rest = group;
+				while rest != 0 {
+					out.set(start + rest.trailing_zeros() as usize, true);
+					rest &= rest - 1;
+				}
+			}
+			groups.advance(count);
+			start = end;
+		}
+		out
+	}
+
+	/// Applies a bitwise operator to two bitmaps, a run at a time.
+	fn combine(&self, other: &Self, op: fn(u32, u32) -> u32) -> Self {
+		let mut out = Self::new();
+		let (mut this, mut that) =
+			(Groups::new(&self.words), Groups::new(&other.words));
+		loop {
+			let (a, b) = match (this.current(), that.current()) {
+				(None, None) => break,
+				(a, b) => {
+					(a.unwrap_or((0, usize::MAX)), b.unwrap_or((0, usize::MAX)))
+				},
+			};
+			let count = cmp::min(a.1, b.1);
+			out.push_groups(op(a.0, b.0) & LITERAL, count);
+			this.advance(count);
+			that.advance(count);
+		}
+		out.len = cmp::max(self.len, other.len);
+		out
+	}
+
+	/// Appends `count` copies of a group.
+	///
+	/// A uniform group is appended as, or merged into, a fill. Any other group
+	/// is appended as a literal, and may only be appended once.
+	fn push_groups(&mut self, group: u32, mut count: usize) {
+		let fill = match group {
+			0 => FILL,
+			LITERAL => FILL | FILL_ONES,
+			_ => {
+				debug_assert_eq!(count, 1, "Literal groups cannot repeat");
+				self.words.push(group);
+				return;
+			},
+		};
+		if let Some(last) = self.words.last_mut() {
+			if *last & !RUN == fill {
+				let add = cmp::min(count, (RUN - (*last & RUN)) as usize);
+				*last += add as u32;
+				count -= add;
+			}
+		}
+		while count > 0 {
+			let add = cmp::min(count, RUN as usize);
+			self.words.push(fill | add as u32);
+			count -= add;
+		}
+	}
+}
+
+impl<O, T> From<&BitSlice<O, T>> for WahBitmap
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(bits: &BitSlice<O, T>) -> Self {
+		Self::from_bitslice(bits)
+	}
+}
+
+impl BitAnd for &WahBitmap {
+	type Output = WahBitmap;
+
+	fn bitand(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |a, b| a & b)
+	}
+}
+
+impl BitOr for &WahBitmap {
+	type Output = WahBitmap;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |a, b| a | b)
+	}
+}
+
+impl BitXor for &WahBitmap {
+	type Output = WahBitmap;
+
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |a, b| a ^ b)
+	}
+}
+
+/// A cursor over the groups of a compressed word sequence.
+struct Groups<'a> {
+	/// The words not yet fully consumed.
+	words: &'a [u32],
+	/// The number of groups already consumed from the front word.
+	used: usize,
+}
+
+impl<'a> Groups<'a> {
+	fn new(words: &'a [u32]) -> Self {
+		Self { words, used: 0 }
+	}
+
+	/// Gets the group under the cursor, and the number of times it repeats
+	/// from there.
+	fn current(&self) -> Option<(u32, usize)> {
+		let word = *self.words.first()?;
+		if word & FILL == 0 {
+			return Some((word, 1));
+		}
+		let group = if word & FILL_ONES != 0 { LITERAL } else { 0 };
+		Some((group, (word & RUN) as usize - self.used))
+	}
+
+	/// Moves the cursor forward by `count` groups, which must not run past
+	/// the end of the current word.
+	fn advance(&mut self, count: usize) {
+		if let Some((_, left)) = self.current() {
+			if count < left {
+				self.used += count;
+			}
+			else {
+				self.words = &self.words[1 ..];
+				self.used = 0;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		devel::XorShift,
+		prelude::*,
+	};
+
+	#[test]
+	fn encoding() {
+		let mut bits = bitvec![Msb0, u8; 0; 200];
+		bits.set(3, true);
+		bits[62 .. 157].set_all(true);
+
+		let wah = WahBitmap::from_bitslice(&bits);
+		assert_eq!(wah.as_words(), &[
+			0b1000,
+			FILL | 1,
+			FILL | FILL_ONES | 3,
+			0b11,
+			FILL | 1,
+		]);
+		assert_eq!(wah.len(), 200);
+		assert_eq!(wah.count_ones(), 96);
+		assert_eq!(wah.count_zeros(), 104);
+		assert_eq!(wah.to_bitvec::<Msb0, u8>(), bits);
+		assert_eq!(WahBitmap::from(bits![]), WahBitmap::new());
+	}
+
+	#[test]
+	fn operators() {
+		let mut rng = XorShift::default();
+		let mut next = || rng.next_u32() as usize;
+
+		let mut runs = |len: usize| {
+			let mut out = BitVec::<Lsb0, u16>::with_capacity(len);
+			while out.len() < len {
+				let run = cmp::min(next() % 150 + 1, len - out.len());
+				let value = next() % 3 == 0;
+				out.extend((0 .. run).map(|idx| value || idx % 7 == 3));
+			}
+			out
+		};
+		let a = runs(5000);
+		let b = runs(3700);
+		let (wa, wb) = (WahBitmap::from(&a[..]), WahBitmap::from(&b[..]));
+
+		let mut wide = b.clone();
+		wide.resize(a.len(), false);
+		assert_eq!(
+			(&wa & &wb).to_bitvec::<Lsb0, u16>(),
+			a.clone() & wide.clone()
+		);
+		assert_eq!(
+			(&wa | &wb).to_bitvec::<Lsb0, u16>(),
+			a.clone() | wide.clone()
+		);
+		assert_eq!(
+			(&wb ^ &wa).to_bitvec::<Lsb0, u16>(),
+			a.clone() ^ wide.clone()
+		);
+		assert_eq!(&wa ^ &wb, WahBitmap::from(&(a.clone() ^ wide)[..]));
+		assert_eq!((&wa ^ &wa).count_ones(), 0);
+		assert_eq!((&wa ^ &wa).as_words().len(), 1);
+	}
+}
@@ -0,0 +1,213 @@
+/*! WebAssembly interoperability through [`wasm-bindgen`].
+
+This module is available when the `wasm` feature is enabled. It connects the
+byte-storage `bitvec` types to the [`Uint8Array`] typed array of [`js-sys`], so
+that a WASM module can exchange bit-exact payloads with JavaScript.
+
+- A [`BitSlice`] or [`BitVec`] with `u8` storage converts into a [`Uint8Array`]
+  through [`From`]. Bits that begin at the start of a byte are copied straight
+  from their bytes; others are first realigned to begin at the first byte. Any
+  bits in the last byte that are past the end of the bit-slice are cleared.
+- A [`Uint8Array`] converts into a [`BitVec`] through [`From`]. The bytes are
+  copied straight from the JavaScript heap into the bit-vector’s buffer, and
+  every bit of every byte is live.
+- [`BitBuffer`] pairs the bytes with a bit length. It is exported to JavaScript
+  as a class, so that a payload whose length is not a multiple of eight can
+  cross the boundary without losing its tail length.
+
+A typed array only carries bytes, so a conversion from [`Uint8Array`] alone
+always produces a multiple of eight bits. Use [`BitBuffer`] when the exact
+length matters.
+
+The functions of [`js-sys`] are only implemented on `wasm32` targets, and panic
+when called elsewhere.
+
+[`BitBuffer`]: self::BitBuffer
+[`BitSlice`]: crate::slice::BitSlice
+[`BitVec`]: crate::vec::BitVec
+[`From`]: core::convert::From
+[`Uint8Array`]: js_sys::Uint8Array
+[`js-sys`]: js_sys
+[`wasm-bindgen`]: wasm_bindgen
+!*/
+
+#![cfg(all(feature = "wasm", feature = "alloc"))]
+
+use crate::{
+	error::Error,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	vec::BitVec,
+};
+
+use alloc::string::ToString;
+
+use js_sys::{
+	RangeError,
+	Uint8Array,
+};
+
+use wasm_bindgen::{
+	prelude::wasm_bindgen,
+	JsValue,
+};
+
+impl<O> From<&BitSlice<O, u8>> for Uint8Array
+where O: BitOrder
+{
+	fn from(bits: &BitSlice<O, u8>) -> Self {
+		let bitptr = bits.bitptr();
+		//  Only a bit-slice that begins partway into a byte must be realigned.
+		if bitptr.head().value() != 0 {
+			return bits.to_bitvec().into();
+		}
+		let bytes = bits.as_slice();
+		let array = Self::new_with_length(bytes.len() as u32);
+		array.copy_from(bytes);
+		if let Some(&last) = bytes.last() {
+			let live = O::mask(None, bitptr.tail());
+			array.set_index(bytes.len() as u32 - 1, (live & last).value());
+		}
+		array
+	}
+}
+
+impl<O> From<BitVec<O, u8>> for Uint8Array
+where O: BitOrder
+{
+	fn from(mut bv: BitVec<O, u8>) -> Self {
+		bv.force_align();
+		bv.fill_uninitialized(false);
+		Self::from(bv.as_slice())
+	}
+}
+
+impl<O> From<&Uint8Array> for BitVec<O, u8>
+where O: BitOrder
+{
+	/// Copies the bytes of a typed array into a new bit-vector.
+	///
+	/// # Panics
+	///
+	/// This panics if the array has more bytes than a [`BitVec`] can hold.
+	///
+	/// [`BitVec`]: crate::vec::BitVec
+	fn from(array: &Uint8Array) -> Self {
+		let mut bv = Self::repeat(false, array.length() as usize * 8);
+		array.copy_to(bv.as_mut_slice());
+		bv
+	}
+}
+
+/** A bit-vector that carries its exact length across the JavaScript boundary.
+
+This is exported to JavaScript as the `BitBuffer` class. Its bits are stored in
+`Lsb0` order, so bit `n` of the buffer is `bytes[n >> 3] & (1 << (n & 7))` on the
+JavaScript side.
+
+# Examples
+
+```rust
+use bitvec::{
+  prelude::*,
+  wasm::BitBuffer,
+};
+
+let buf = BitBuffer::from(bitvec![Lsb0, u8; 1, 0, 1]);
+assert_eq!(buf.len(), 3);
+assert_eq!(buf.as_bitslice(), bits![1, 0, 1]);
+
+let bv: BitVec<Lsb0, u8> = buf.into();
+assert_eq!(bv, bits![1, 0, 1]);
+```
+**/
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitBuffer {
+	/// The live bits of the buffer.
+	bits: BitVec<Lsb0, u8>,
+}
+
+#[wasm_bindgen]
+impl BitBuffer {
+	/// Builds a buffer from the leading bits of a typed array.
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: The storage of the buffer.
+	/// - `len`: The number of live bits in `bytes`, counted from the start of
+	///   the first byte.
+	///
+	/// # Errors
+	///
+	/// This throws a JavaScript `RangeError` if `len` exceeds the number of
+	/// bits in `bytes`.
+	#[wasm_bindgen(constructor)]
+	pub fn new(bytes: &Uint8Array, len: usize) -> Result<BitBuffer, JsValue> {
+		let mut bits = BitVec::from(bytes);
+		let capacity = bits.len();
+		if len > capacity {
+			let err = Error::Capacity {
+				needed: len,
+				capacity,
+			};
+			return Err(RangeError::new(&err.to_string()).into());
+		}
+		bits.truncate(len);
+		Ok(Self { bits })
+	}
+
+	/// Counts the live bits in the buffer.
+	#[wasm_bindgen(getter)]
+	pub fn len(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Tests if the buffer has no live bits.
+	#[wasm_bindgen(getter, js_name = isEmpty)]
+	pub fn is_empty(&self) -> bool {
+		self.bits.is_empty()
+	}
+
+	/// Copies the buffer’s storage into a new typed array.
+	///
+	/// Bits in the last byte that are past [`.len()`] are cleared.
+	///
+	/// [`.len()`]: Self::len
+	#[wasm_bindgen(getter)]
+	pub fn bytes(&self) -> Uint8Array {
+		self.bits.as_bitslice().into()
+	}
+
+	/// Reads a bit, or `undefined` if `index` is out of bounds.
+	pub fn get(&self, index: usize) -> Option<bool> {
+		self.bits.get(index).copied()
+	}
+}
+
+impl BitBuffer {
+	/// Views the live bits of the buffer.
+	pub fn as_bitslice(&self) -> &BitSlice<Lsb0, u8> {
+		self.bits.as_bitslice()
+	}
+
+	/// Views the live bits of the buffer mutably.
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<Lsb0, u8> {
+		self.bits.as_mut_bitslice()
+	}
+}
+
+impl From<BitVec<Lsb0, u8>> for BitBuffer {
+	fn from(bits: BitVec<Lsb0, u8>) -> Self {
+		Self { bits }
+	}
+}
+
+impl From<BitBuffer> for BitVec<Lsb0, u8> {
+	fn from(buf: BitBuffer) -> Self {
+		buf.bits
+	}
+}
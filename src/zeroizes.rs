@@ -0,0 +1,175 @@
+/*! [`zeroize`] support for secret bit buffers.
+
+This module implements [`Zeroize`] for [`BitArray`], [`BitBox`], and
+[`BitVec`], so that key material and other secrets held in bit collections can
+be wiped with the same volatile writes that `zeroize` uses for its own types.
+Each implementation wipes the entire memory buffer of the collection, not just
+its live bits: the dead bits in the partial edge elements are cleared along
+with the rest, and a bit-vector also wipes the spare capacity past its end.
+
+[`BitBox`] and [`BitVec`] also implement [`ZeroizeOnDrop`]: while this feature
+is enabled, their destructors wipe their buffers before releasing them.
+[`BitArray`] is `Copy`, and so cannot have a destructor; call
+[`.zeroize()`] on it, or hold it in [`zeroize::Zeroizing`], once it is no longer
+needed.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use zeroize::Zeroize;
+
+let mut key = bitvec![Msb0, u8; 1; 20];
+key.zeroize();
+assert!(key.is_empty());
+
+let mut block = bitarr![Lsb0, u32; 1; 40];
+block.zeroize();
+assert!(block.not_any());
+```
+
+[`BitArray`]: crate::array::BitArray
+[`BitBox`]: crate::boxed::BitBox
+[`BitVec`]: crate::vec::BitVec
+[`Zeroize`]: zeroize::Zeroize
+[`ZeroizeOnDrop`]: zeroize::ZeroizeOnDrop
+[`zeroize`]: zeroize
+[`zeroize::Zeroizing`]: zeroize::Zeroizing
+[`.zeroize()`]: zeroize::Zeroize::zeroize
+!*/
+
+#![cfg(feature = "zeroize")]
+
+use crate::{
+	array::BitArray,
+	order::BitOrder,
+	view::BitView,
+};
+
+use core::{
+	mem::MaybeUninit,
+	slice,
+};
+
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use crate::{
+	boxed::BitBox,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[cfg(feature = "alloc")]
+use zeroize::ZeroizeOnDrop;
+
+/// Wipes every element of the array, including its dead bits.
+impl<O, V> Zeroize for BitArray<O, V>
+where
+	O: BitOrder,
+	V: BitView,
+{
+	fn zeroize(&mut self) {
+		unsafe {
+			wipe(self.as_mut_buffer(), 1);
+		}
+	}
+}
+
+/// Wipes every element of the box, including its dead bits.
+///
+/// The box keeps its length, and all of its bits are `0` afterwards.
+#[cfg(feature = "alloc")]
+impl<O, T> Zeroize for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn zeroize(&mut self) {
+		let elts = self.as_mut_slice();
+		unsafe {
+			wipe(elts.as_mut_ptr(), elts.len());
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> ZeroizeOnDrop for BitBox<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/// Wipes the whole allocation of the vector, including its dead bits and its
+/// spare capacity, and then clears it.
+///
+/// As with the implementation for `Vec`, the vector is empty afterwards, and
+/// keeps its allocation.
+#[cfg(feature = "alloc")]
+impl<O, T> Zeroize for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn zeroize(&mut self) {
+		//  Include the unused elements in front of the vector.
+		self.release_front();
+		let capacity = self.alloc_capacity();
+		unsafe {
+			wipe(self.as_mut_ptr(), capacity);
+		}
+		self.clear();
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<O, T> ZeroizeOnDrop for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/// Overwrites a region of memory with zeros, using volatile writes.
+///
+/// # Safety
+///
+/// `base` must be valid for writes of `count` elements.
+unsafe fn wipe<T>(base: *mut T, count: usize) {
+	slice::from_raw_parts_mut(base as *mut MaybeUninit<T>, count).zeroize();
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn wipes_buffers() {
+		let mut bv = bitvec![Lsb0, u16; 1; 40];
+		bv.drain(.. 3);
+		bv.truncate(30);
+		let (base, capacity) = (bv.as_ptr(), bv.alloc_capacity());
+		bv.zeroize();
+		assert!(bv.is_empty());
+		assert_eq!(bv.as_ptr(), base);
+		//  The buffer is still owned by the vector, so it can be read back.
+		let elts = unsafe { slice::from_raw_parts(base, capacity) };
+		assert!(elts.iter().all(|&elem| elem == 0));
+
+		let mut bb = bitvec![Msb0, u8; 1; 20].into_boxed_bitslice();
+		bb.zeroize();
+		assert_eq!(bb.len(), 20);
+		assert_eq!(bb.as_slice(), &[0; 3]);
+	}
+
+	#[test]
+	fn wipes_arrays() {
+		let mut ba = bitarr![Msb0, u8; 1; 12];
+		ba.as_mut_buffer()[1] = !0;
+		ba.zeroize();
+		assert_eq!(ba.as_buffer(), &[0; 2]);
+	}
+}
@@ -36,3 +36,21 @@ unsafe impl BitOrder for Swizzle {
 fn check_impl() {
 	bitvec::order::verify::<Swizzle>(cfg!(feature = "testing"));
 }
+
+#[test]
+fn navigation() {
+	let mut data = [0u16; 3];
+	let bits = data.view_bits_mut::<Swizzle>();
+	for &idx in &[2, 7, 12, 30, 47] {
+		bits.set(idx, true);
+	}
+	let bits = &bits[1 ..];
+
+	assert_eq!(bits.next_one_after(0), Some(1));
+	assert_eq!(bits.next_one_after(1), Some(6));
+	assert_eq!(bits.next_one_after(11), Some(29));
+	assert_eq!(bits.next_one_after(46), None);
+	assert_eq!(bits.prev_one_before(47), Some(46));
+	assert_eq!(bits.prev_one_before(29), Some(11));
+	assert_eq!(bits.prev_one_before(1), None);
+}